@@ -0,0 +1,82 @@
+// AIDEV-NOTE: Background USDC balance poller - emits balance_update only when the value changes
+
+use std::sync::Arc;
+use std::time::Duration;
+use parking_lot::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+use crate::error::ApiError;
+use crate::ws::EventEmitter;
+use super::ClobClient;
+
+/// Polls `ClobClient::get_balance` on an interval and pushes changes through an `EventEmitter`
+#[derive(Default)]
+pub struct BalanceWatcher {
+    handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl BalanceWatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start polling every `interval`. The first successful poll always emits.
+    /// Panics if `interval` is zero.
+    /// AIDEV-NOTE: Replaces any running poller. The client is captured by value, so
+    /// restart after login/logout to pick up the new credentials.
+    pub fn start<E: EventEmitter>(&self, client: ClobClient, emitter: Arc<E>, interval: Duration) {
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            let mut last = None;
+            loop {
+                ticker.tick().await;
+                // Errors (offline, expired credentials) keep the last value and retry next tick
+                if let Err(e) = poll_balance(&client, emitter.as_ref(), &mut last).await {
+                    warn!(error = %e, "Balance poll failed");
+                }
+            }
+        });
+
+        if let Some(old) = self.handle.lock().replace(handle) {
+            old.abort();
+        }
+    }
+
+    /// Stop polling, if running
+    pub fn stop(&self) {
+        if let Some(handle) = self.handle.lock().take() {
+            handle.abort();
+        }
+    }
+
+    /// Whether the poller is running
+    pub fn is_running(&self) -> bool {
+        self.handle.lock().as_ref().is_some_and(|h| !h.is_finished())
+    }
+}
+
+impl Drop for BalanceWatcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Fetch the balance once and emit it if it differs from `last` (which is then updated)
+/// Returns whether an event was emitted
+pub(crate) async fn poll_balance<E: EventEmitter + ?Sized>(
+    client: &ClobClient,
+    emitter: &E,
+    last: &mut Option<String>,
+) -> Result<bool, ApiError> {
+    let balance = client.get_balance().await?;
+    if last.as_deref() == Some(balance.balance.as_str()) {
+        return Ok(false);
+    }
+
+    debug!(balance = %balance.balance, "Balance changed");
+    emitter.emit_balance_update(&balance);
+    *last = Some(balance.balance);
+    Ok(true)
+}
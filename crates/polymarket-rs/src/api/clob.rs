@@ -1,24 +1,98 @@
 // AIDEV-NOTE: Authenticated CLOB REST API client for positions, orders, and balances
 
-use reqwest::Client;
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use futures_util::stream::{self, StreamExt};
+use parking_lot::RwLock;
+use reqwest::{Client, RequestBuilder, Response};
 use serde::Deserialize;
-use tracing::{debug, error, instrument};
+use tracing::{debug, error, field, info_span, instrument, warn, Instrument};
 
 use crate::auth::{ApiCredentials, HmacAuth, PolymarketSigner};
 use crate::error::ApiError;
-use crate::types::{Balance, Order, Position, PriceHistoryResponse, PricePoint};
+use crate::position_list::{filter_positions, PositionSortKey};
+use crate::types::{
+    Balance, BookSpread, ClobTrade, Holder, LeaderboardEntry, Market, Order, OrderBook, OrderBookLevel, OrderFill, PnlSummary, PortfolioSummary, Position, PriceHistoryResponse, PricePoint, TokenQuote,
+};
 
-use super::order::{CancelResponse, OrderType, PlaceOrderRequest, PlaceOrderResponse, SignedOrder};
+use super::gamma::{body_preview, GammaClient};
+use super::order::{
+    CancelResponse, FeeRates, OrderType, PlaceOrderRequest, PlaceOrderResponse, ReplacePolicy, ReplaceResult,
+    SignatureType, SignedOrder,
+};
 
 const CLOB_API_BASE: &str = "https://clob.polymarket.com";
 const DATA_API_BASE: &str = "https://data-api.polymarket.com";
+/// CLOB limit on orders per POST /orders request
+pub const MAX_BATCH_ORDERS: usize = 15;
+/// Data API max page size for /positions (its default is 100)
+const POSITIONS_PAGE_SIZE: u32 = 500;
+/// Safety stop for `get_all_positions` - 50 full pages is far beyond any real account
+const MAX_POSITION_PAGES: u32 = 50;
+/// Default per-request timeout for `ClobClientConfig`
+const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Everything needed to build a `ClobClient`, in one place
+/// AIDEV-NOTE: New client knobs (retries, rate limits) belong here rather than as
+/// more constructor arguments. The `with_*` builders still work on a built client.
+#[derive(Debug, Clone)]
+pub struct ClobClientConfig {
+    /// CLOB REST base URL
+    pub base_url: String,
+    /// Data API base URL (positions, leaderboard, public trades)
+    pub data_api_url: String,
+    /// Per-request timeout (None = wait indefinitely)
+    pub timeout: Option<Duration>,
+    /// Credentials for authenticated endpoints (validated by `with_config`)
+    pub credentials: Option<ApiCredentials>,
+    pub signature_type: SignatureType,
+    pub replace_policy: ReplacePolicy,
+    /// Correct auth timestamps by the server clock offset
+    pub server_time_sync: bool,
+}
+
+impl Default for ClobClientConfig {
+    fn default() -> Self {
+        Self {
+            base_url: CLOB_API_BASE.to_string(),
+            data_api_url: DATA_API_BASE.to_string(),
+            timeout: Some(DEFAULT_TIMEOUT),
+            credentials: None,
+            signature_type: SignatureType::default(),
+            replace_policy: ReplacePolicy::default(),
+            server_time_sync: false,
+        }
+    }
+}
 
 /// Client for the Polymarket CLOB REST API (authenticated)
+/// AIDEV-NOTE: Clones share one Arc'd config, so cloning per command is a pointer copy.
+/// Builders and set_credentials are copy-on-write - changing one handle never
+/// affects clones already handed out.
 #[derive(Clone)]
 pub struct ClobClient {
+    inner: Arc<ClobClientInner>,
+}
+
+#[derive(Clone)]
+struct ClobClientInner {
     client: Client,
     base_url: String,
+    /// Data API base (positions, leaderboard, public trades)
+    data_api_url: String,
     hmac_auth: Option<HmacAuth>,
+    /// Gamma client for cross-referencing condition IDs (owns the gamma ID cache)
+    gamma: GammaClient,
+    /// Leg ordering for `replace_order`
+    replace_policy: ReplacePolicy,
+    /// Account type - sent as signature_type on balance queries and used for order signing
+    signature_type: SignatureType,
+    /// Opt-in: correct auth timestamps by the server clock offset
+    server_time_sync: bool,
+    /// Cached server - local clock offset in seconds (shared across clones)
+    time_offset: Arc<RwLock<Option<i64>>>,
 }
 
 /// AIDEV-NOTE: Orders response is wrapped: {"data": [], "next_cursor": ..., "limit": ..., "count": ...}
@@ -34,6 +108,132 @@ pub struct OrdersResponse {
     pub count: Option<u32>,
 }
 
+/// Trade from GET /data/trades
+/// AIDEV-NOTE: The user's order is either the taker order or one of maker_orders;
+/// numeric fields arrive as strings
+#[derive(Debug, Clone, Deserialize)]
+struct RawTrade {
+    id: String,
+    #[serde(default)]
+    taker_order_id: String,
+    #[serde(default)]
+    size: String,
+    #[serde(default)]
+    price: String,
+    #[serde(default)]
+    fee_rate_bps: String,
+    #[serde(default)]
+    match_time: String,
+    #[serde(default)]
+    maker_orders: Vec<RawMakerOrder>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+struct RawMakerOrder {
+    order_id: String,
+    #[serde(default)]
+    matched_amount: String,
+    #[serde(default)]
+    price: String,
+    #[serde(default)]
+    fee_rate_bps: String,
+}
+
+/// Public trade from Data API GET /trades?market=
+/// AIDEV-NOTE: Numbers arrive as JSON numbers and timestamp is in seconds
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct RawActivityTrade {
+    asset: String,
+    #[serde(default)]
+    condition_id: Option<String>,
+    price: f64,
+    size: f64,
+    #[serde(default)]
+    side: String,
+    #[serde(default)]
+    timestamp: Option<i64>,
+    #[serde(default)]
+    transaction_hash: Option<String>,
+}
+
+impl From<RawActivityTrade> for ClobTrade {
+    fn from(raw: RawActivityTrade) -> Self {
+        ClobTrade {
+            event_type: Some("trade".to_string()),
+            asset_id: raw.asset,
+            market: raw.condition_id,
+            price: raw.price.to_string(),
+            size: raw.size.to_string(),
+            side: raw.side,
+            // AIDEV-NOTE: Milliseconds, to line up with CLOB WebSocket trades
+            timestamp: raw.timestamp.map(|t| t * 1000),
+            trade_id: raw.transaction_hash,
+        }
+    }
+}
+
+/// Data API /holders groups holders per outcome token of the market
+#[derive(Debug, Clone, Deserialize)]
+struct TokenHolders {
+    token: String,
+    #[serde(default)]
+    holders: Vec<Holder>,
+}
+
+/// Trades endpoint may return a bare array or the paginated {"data": [...]} wrapper
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+enum TradesResponse {
+    Paged { data: Vec<RawTrade> },
+    List(Vec<RawTrade>),
+}
+
+impl RawTrade {
+    /// This trade's fill of `order_id`, if the order took part in it
+    fn fill_for(&self, order_id: &str) -> Option<OrderFill> {
+        let parse = |s: &str| s.parse::<f64>().unwrap_or(0.0);
+        let (size, price, fee_bps) = if self.taker_order_id == order_id {
+            (parse(&self.size), parse(&self.price), parse(&self.fee_rate_bps))
+        } else {
+            let maker = self.maker_orders.iter().find(|m| m.order_id == order_id)?;
+            (parse(&maker.matched_amount), parse(&maker.price), parse(&maker.fee_rate_bps))
+        };
+
+        Some(OrderFill {
+            trade_id: self.id.clone(),
+            order_id: order_id.to_string(),
+            size,
+            price,
+            timestamp: self.match_time.clone(),
+            fee: size * price * fee_bps / 10_000.0,
+        })
+    }
+}
+
+/// GET /fee-rate-bps response - split maker/taker rates or a single base fee
+#[derive(Debug, Deserialize)]
+struct RawFeeRates {
+    #[serde(default)]
+    maker_fee_rate_bps: Option<u32>,
+    #[serde(default)]
+    taker_fee_rate_bps: Option<u32>,
+    #[serde(default)]
+    base_fee: Option<u32>,
+}
+
+/// GET /book response (REST order book snapshot)
+#[derive(Debug, Clone, Deserialize)]
+struct BookResponse {
+    #[serde(default)]
+    market: Option<String>,
+    asset_id: String,
+    #[serde(default)]
+    bids: Vec<OrderBookLevel>,
+    #[serde(default)]
+    asks: Vec<OrderBookLevel>,
+}
+
 /// API key derivation response
 #[derive(Debug, Clone, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -44,41 +244,115 @@ pub struct ApiKeyResponse {
 }
 
 impl ClobClient {
-    /// Create a new unauthenticated client
+    /// Create a new unauthenticated client with the default config
     pub fn new() -> Self {
-        Self {
-            client: Client::new(),
-            base_url: CLOB_API_BASE.to_string(),
-            hmac_auth: None,
-        }
+        Self::with_config(ClobClientConfig::default()).expect("default ClobClientConfig is valid")
     }
 
     /// Create an authenticated client with credentials
-    pub fn with_credentials(credentials: &ApiCredentials) -> Self {
-        Self {
-            client: Client::new(),
-            base_url: CLOB_API_BASE.to_string(),
-            hmac_auth: Some(HmacAuth::new(credentials)),
+    /// Fails with `ApiError::Auth` if the credentials are malformed (see `ApiCredentials::validate`)
+    pub fn with_credentials(credentials: &ApiCredentials) -> Result<Self, ApiError> {
+        Self::with_config(ClobClientConfig { credentials: Some(credentials.clone()), ..Default::default() })
+    }
+
+    /// Create a client from a full config
+    /// Fails if the credentials are malformed or the HTTP client can't be built
+    pub fn with_config(config: ClobClientConfig) -> Result<Self, ApiError> {
+        let hmac_auth = match &config.credentials {
+            Some(credentials) => {
+                credentials.validate()?;
+                Some(HmacAuth::new(credentials))
+            }
+            None => None,
+        };
+
+        let mut builder = Client::builder();
+        if let Some(timeout) = config.timeout {
+            builder = builder.timeout(timeout);
         }
+
+        Ok(Self::from_inner(ClobClientInner {
+            client: builder.build()?,
+            base_url: config.base_url.trim_end_matches('/').to_string(),
+            data_api_url: config.data_api_url.trim_end_matches('/').to_string(),
+            hmac_auth,
+            gamma: GammaClient::new(),
+            replace_policy: config.replace_policy,
+            signature_type: config.signature_type,
+            server_time_sync: config.server_time_sync,
+            time_offset: Arc::new(RwLock::new(None)),
+        }))
+    }
+
+    fn from_inner(inner: ClobClientInner) -> Self {
+        Self { inner: Arc::new(inner) }
+    }
+
+    /// Override the CLOB base URL (e.g. for a local mock server)
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        Arc::make_mut(&mut self.inner).base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Override the Data API base URL (e.g. for a local mock server)
+    pub fn with_data_api_url(mut self, data_api_url: &str) -> Self {
+        Arc::make_mut(&mut self.inner).data_api_url = data_api_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Choose which leg of `replace_order` runs first
+    pub fn with_replace_policy(mut self, policy: ReplacePolicy) -> Self {
+        Arc::make_mut(&mut self.inner).replace_policy = policy;
+        self
+    }
+
+    /// Set the account type (EOA, proxy or Safe) used for balances and orders
+    pub fn with_signature_type(mut self, signature_type: SignatureType) -> Self {
+        Arc::make_mut(&mut self.inner).signature_type = signature_type;
+        self
+    }
+
+    /// Account type this client was configured with
+    pub fn signature_type(&self) -> SignatureType {
+        self.inner.signature_type
+    }
+
+    /// Opt in to correcting HMAC/L1 timestamps by the CLOB server clock
+    /// AIDEV-NOTE: The offset is fetched once from /time on first authenticated call
+    pub fn with_server_time_sync(mut self, enabled: bool) -> Self {
+        Arc::make_mut(&mut self.inner).server_time_sync = enabled;
+        self
+    }
+
+    /// Whether credentials are set (authenticated endpoints are usable)
+    pub fn is_authenticated(&self) -> bool {
+        self.inner.hmac_auth.is_some()
     }
 
     /// Set credentials for authentication
-    pub fn set_credentials(&mut self, credentials: &ApiCredentials) {
-        self.hmac_auth = Some(HmacAuth::new(credentials));
+    /// Fails (leaving the client unchanged) if the credentials are malformed
+    pub fn set_credentials(&mut self, credentials: &ApiCredentials) -> Result<(), ApiError> {
+        credentials.validate()?;
+        Arc::make_mut(&mut self.inner).hmac_auth = Some(HmacAuth::new(credentials));
+        Ok(())
     }
 
     /// Derive API keys from wallet signature using L1 headers
-    #[instrument(skip(self, signer))]
+    #[instrument(skip(self, signer), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
     pub async fn derive_api_key(&self, signer: &PolymarketSigner) -> Result<ApiCredentials, ApiError> {
+        if self.inner.server_time_sync {
+            signer.set_time_offset(self.server_time_offset().await?);
+        }
+
         // Generate L1 authentication headers
         let l1_headers = signer.create_l1_headers(0).await?;
 
-        let url = format!("{}/auth/derive-api-key", self.base_url);
-        debug!("Deriving API key at: {} with address {}", url, l1_headers.address);
+        let url = format!("{}/auth/derive-api-key", self.inner.base_url);
+        debug!(%url, address = %l1_headers.address, "Deriving API key");
 
         // Send GET request with L1 headers
-        let response = l1_headers.apply_to_request(self.client.get(&url))
-            .send()
+        let response = self
+            .logged_send(l1_headers.apply_to_request(self.inner.client.get(&url)), "derive_api_key")
             .await?;
 
         if !response.status().is_success() {
@@ -100,24 +374,88 @@ impl ClobClient {
         })
     }
 
+    /// Create a new API key for `nonce` using L1 headers
+    /// AIDEV-NOTE: derive_api_key returns the key that already exists for the nonce; this
+    /// issues fresh credentials, so it is what rotation uses after delete_api_key
+    #[instrument(skip(self, signer), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn create_api_key(&self, signer: &PolymarketSigner, nonce: u64) -> Result<ApiCredentials, ApiError> {
+        if self.inner.server_time_sync {
+            signer.set_time_offset(self.server_time_offset().await?);
+        }
+
+        let l1_headers = signer.create_l1_headers(nonce).await?;
+
+        let url = format!("{}/auth/api-key", self.inner.base_url);
+        debug!(%url, address = %l1_headers.address, nonce, "Creating API key");
+
+        let response = self
+            .logged_send(l1_headers.apply_to_request(self.inner.client.post(&url)), "create_api_key")
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(ApiError::Api(format!("API key creation failed ({}): {}", status, text)));
+        }
+
+        let api_response: ApiKeyResponse = response.json().await?;
+
+        Ok(ApiCredentials {
+            api_key: api_response.api_key,
+            api_secret: api_response.secret,
+            api_passphrase: api_response.passphrase,
+            address: signer.address_string(),
+        })
+    }
+
+    /// Revoke the API key this client is authenticated with (L2 headers)
+    /// AIDEV-NOTE: The client's credentials are dead afterwards - create new ones before reuse
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn delete_api_key(&self) -> Result<(), ApiError> {
+        let hmac = self.hmac().await?;
+
+        let path = "/auth/api-key";
+        let url = format!("{}{}", self.inner.base_url, path);
+        let headers = hmac.generate_headers("DELETE", path, None)?;
+
+        debug!("Deleting API key");
+
+        let response = self
+            .logged_send(headers.apply_to_request(self.inner.client.delete(&url)), "delete_api_key")
+            .await?;
+
+        if !response.status().is_success() {
+            let status = response.status();
+            let text = response.text().await.unwrap_or_default();
+            return Err(ApiError::Api(format!("API key deletion failed ({}): {}", status, text)));
+        }
+
+        Ok(())
+    }
+
     /// Get authenticated user's balance and allowance
-    #[instrument(skip(self))]
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
     pub async fn get_balance(&self) -> Result<Balance, ApiError> {
-        let hmac = self.hmac_auth.as_ref()
-            .ok_or_else(|| ApiError::Auth("Not authenticated".to_string()))?;
+        let hmac = self.hmac().await?;
 
         // AIDEV-NOTE: Correct endpoint is /balance-allowance, not /balance
         // AIDEV-NOTE: asset_type=COLLATERAL for USDC balance
-        // AIDEV-NOTE: signature_type=2 for Polymarket proxy wallet balance (0=EOA, 1=?, 2=proxy)
+        // AIDEV-NOTE: signature_type is the account type (0=EOA, 1=proxy, 2=Safe) - must
+        // match what orders are signed with or the CLOB reports the wrong wallet's balance
         // AIDEV-NOTE: HMAC signature uses path only, not query params
         let path = "/balance-allowance";
-        let url = format!("{}{}?asset_type=COLLATERAL&signature_type=2", self.base_url, path);
+        let url = format!(
+            "{}{}?asset_type=COLLATERAL&signature_type={}",
+            self.inner.base_url,
+            path,
+            self.inner.signature_type.as_u8()
+        );
         let headers = hmac.generate_headers("GET", path, None)?;
 
-        debug!("Fetching balance from: {}", url);
+        debug!(%url, "Fetching balance");
 
-        let response = headers.apply_to_request(self.client.get(&url))
-            .send()
+        let response = self
+            .logged_send(headers.apply_to_request(self.inner.client.get(&url)), "get_balance")
             .await?;
 
         if !response.status().is_success() {
@@ -128,7 +466,7 @@ impl ClobClient {
 
         // Debug: Log raw response
         let text = response.text().await?;
-        debug!("Balance raw response: {}", text);
+        debug!(body = %text, "Balance raw response");
 
         let balance: Balance = serde_json::from_str(&text)
             .map_err(|e| ApiError::Api(format!("Failed to parse balance: {}", e)))?;
@@ -136,18 +474,67 @@ impl ClobClient {
     }
 
     /// Get user's positions from Data API (uses address, not auth)
-    #[instrument(skip(self))]
+    /// AIDEV-NOTE: One request at the API's default page size (100) - large accounts
+    /// are truncated, use `get_all_positions` when every position matters
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
     pub async fn get_positions(&self, address: &str) -> Result<Vec<Position>, ApiError> {
-        let url = format!("{}/positions?user={}", DATA_API_BASE, address);
+        let url = format!("{}/positions?user={}", self.inner.data_api_url, address);
+        self.fetch_positions(&url).await
+    }
 
-        debug!("Fetching positions from: {}", url);
+    /// One page of the user's positions: at most `limit`, skipping the first `offset`
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_positions_page(&self, address: &str, limit: u32, offset: u32) -> Result<Vec<Position>, ApiError> {
+        let url = format!(
+            "{}/positions?user={}&limit={}&offset={}",
+            self.inner.data_api_url, address, limit, offset
+        );
+        self.fetch_positions(&url).await
+    }
 
-        let response = self.client.get(&url)
-            .send()
-            .await?;
+    /// All of the user's positions, paging through the Data API until a short page
+    #[instrument(skip(self))]
+    pub async fn get_all_positions(&self, address: &str) -> Result<Vec<Position>, ApiError> {
+        self.collect_positions(address, POSITIONS_PAGE_SIZE).await
+    }
+
+    /// All of the user's positions without dust below `min_size` shares, sorted by `sort_by`
+    #[instrument(skip(self))]
+    pub async fn get_positions_filtered(
+        &self,
+        address: &str,
+        sort_by: Option<PositionSortKey>,
+        min_size: Option<f64>,
+    ) -> Result<Vec<Position>, ApiError> {
+        let positions = self.get_all_positions(address).await?;
+        Ok(filter_positions(positions, sort_by, min_size))
+    }
+
+    /// `get_all_positions` with an explicit page size
+    pub(crate) async fn collect_positions(&self, address: &str, page_size: u32) -> Result<Vec<Position>, ApiError> {
+        let page_size = page_size.max(1);
+        let mut positions = Vec::new();
+
+        for page in 0..MAX_POSITION_PAGES {
+            let batch = self.get_positions_page(address, page_size, page * page_size).await?;
+            let done = (batch.len() as u32) < page_size;
+            positions.extend(batch);
+            if done {
+                return Ok(positions);
+            }
+        }
+
+        warn!(count = positions.len(), "Stopped paging positions at the page limit");
+        Ok(positions)
+    }
+
+    async fn fetch_positions(&self, url: &str) -> Result<Vec<Position>, ApiError> {
+        debug!(%url, "Fetching positions");
+
+        let response = self.logged_send(self.inner.client.get(url), "get_positions").await?;
 
         let status = response.status();
-        debug!("Positions response status: {}", status);
+        debug!(%status, "Positions response");
 
         if !status.is_success() {
             let text = response.text().await.unwrap_or_default();
@@ -155,73 +542,233 @@ impl ClobClient {
         }
 
         let text = response.text().await?;
-        debug!("Positions response body length: {} chars", text.len());
+        debug!(body_len = text.len(), "Positions response body");
 
         // Try to parse, with detailed error on failure
         let positions: Vec<Position> = serde_json::from_str(&text).map_err(|e| {
-            debug!("Failed to parse positions: {}. First 500 chars: {}", e, &text[..text.len().min(500)]);
+            debug!(error = %e, body = %body_preview(&text), "Failed to parse positions");
             ApiError::Api(format!("Failed to parse positions: {}", e))
         })?;
 
-        debug!("Parsed {} positions", positions.len());
+        debug!(count = positions.len(), "Parsed positions");
         Ok(positions)
     }
 
-    /// Get authenticated user's open orders
+    /// Recent public trades in a market, newest first (backfill for the live trades panel)
+    /// AIDEV-NOTE: No auth required - Data API, covers all traders, not just the user
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_market_activity(&self, condition_id: &str, limit: u32) -> Result<Vec<ClobTrade>, ApiError> {
+        let url = format!(
+            "{}/trades?market={}&limit={}",
+            self.inner.data_api_url,
+            urlencoding::encode(condition_id),
+            limit
+        );
+        let value = self.get_public_json(&url, "Market activity").await?;
+
+        let trades: Vec<RawActivityTrade> = serde_json::from_value(value)?;
+        Ok(trades.into_iter().map(ClobTrade::from).collect())
+    }
+
+    /// Top holders of one outcome token, largest first
+    /// AIDEV-NOTE: No auth required. The Data API keys /holders by market (condition ID)
+    /// and returns every outcome token - we keep only `token_id`'s group.
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_holders(&self, condition_id: &str, token_id: &str) -> Result<Vec<Holder>, ApiError> {
+        let url = format!("{}/holders?market={}", self.inner.data_api_url, urlencoding::encode(condition_id));
+        let value = self.get_public_json(&url, "Holders").await?;
+
+        let groups: Vec<TokenHolders> = serde_json::from_value(value)?;
+        Ok(groups
+            .into_iter()
+            .find(|g| g.token == token_id)
+            .map(|g| g.holders)
+            .unwrap_or_default())
+    }
+
+    /// Top traders for a period ("day", "week", "month", "all")
+    /// AIDEV-NOTE: No auth required - Data API, address-based
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_leaderboard(&self, period: &str) -> Result<Vec<LeaderboardEntry>, ApiError> {
+        let url = format!("{}/v1/leaderboard?timePeriod={}", self.inner.data_api_url, urlencoding::encode(period));
+        let value = self.get_public_json(&url, "Leaderboard").await?;
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// Aggregate P&L for an address (Data API /pnl, no auth)
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_pnl_summary(&self, address: &str) -> Result<PnlSummary, ApiError> {
+        let url = format!("{}/pnl?user={}", self.inner.data_api_url, urlencoding::encode(address));
+        let value = self.get_public_json(&url, "P&L").await?;
+        Ok(serde_json::from_value(value)?)
+    }
+
+    /// All-time leaderboard entry for one address (None if the address is unranked)
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_user_rank(&self, address: &str) -> Result<Option<LeaderboardEntry>, ApiError> {
+        let url = format!(
+            "{}/v1/leaderboard?timePeriod=all&user={}",
+            self.inner.data_api_url,
+            urlencoding::encode(address)
+        );
+        let value = self.get_public_json(&url, "User rank").await?;
+        let entries: Vec<LeaderboardEntry> = serde_json::from_value(value)?;
+
+        Ok(entries.into_iter().find(|e| e.address.eq_ignore_ascii_case(address)))
+    }
+
+    /// Get authenticated user's open orders, optionally filtered server-side
+    /// by market (condition ID) and/or status
     /// AIDEV-NOTE: Endpoint is /data/orders, NOT /orders (405 error)
-    #[instrument(skip(self))]
-    pub async fn get_orders(&self) -> Result<Vec<Order>, ApiError> {
-        let hmac = self.hmac_auth.as_ref()
-            .ok_or_else(|| ApiError::Auth("Not authenticated".to_string()))?;
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_orders(&self, market: Option<&str>, status: Option<&str>) -> Result<Vec<Order>, ApiError> {
+        let hmac = self.hmac().await?;
 
+        // AIDEV-NOTE: HMAC signature uses path only, not query params
         let path = "/data/orders";
-        let url = format!("{}{}", self.base_url, path);
+        let url = format!("{}{}{}", self.inner.base_url, path, orders_query(market, status));
         let headers = hmac.generate_headers("GET", path, None)?;
 
-        debug!("Fetching orders from: {}", url);
+        debug!(%url, "Fetching orders");
 
-        let response = headers.apply_to_request(self.client.get(&url))
-            .send()
+        let response = self
+            .logged_send(headers.apply_to_request(self.inner.client.get(&url)), "get_orders")
             .await?;
 
         let status = response.status();
         let text = response.text().await.unwrap_or_default();
 
-        debug!("Orders response status: {}, body length: {}", status, text.len());
+        debug!(%status, body_len = text.len(), "Orders response");
 
         if !status.is_success() {
             return Err(ApiError::Api(format!("Orders request failed ({}): {}", status, text)));
         }
 
         // AIDEV-NOTE: Log first 500 chars of response for debugging parse errors
-        let preview = if text.len() > 500 { &text[..500] } else { &text };
-        debug!("Orders response preview: {}", preview);
+        let preview = body_preview(&text);
+        debug!(%preview, "Orders response preview");
 
         // AIDEV-NOTE: Response is wrapped in {"data": [...], ...}
         let response: OrdersResponse = serde_json::from_str(&text).map_err(|e| {
             error!("Failed to parse orders: {}. Response: {}", e, preview);
             ApiError::Api(format!("Failed to parse orders: {}", e))
         })?;
-        debug!("Fetched {} orders", response.data.len());
+        debug!(count = response.data.len(), "Fetched orders");
         Ok(response.data)
     }
 
+    /// Positions (Data API, for `address`) and open orders (CLOB, authenticated) in one call,
+    /// plus the account P&L when available
+    /// AIDEV-NOTE: The requests hit different hosts and run concurrently. If positions and
+    /// orders both fail the error carries both messages; a failed P&L fetch only leaves
+    /// `pnl` as None.
+    #[instrument(skip(self))]
+    pub async fn get_portfolio_summary(&self, address: &str) -> Result<PortfolioSummary, ApiError> {
+        let (positions, orders, pnl) = tokio::join!(
+            self.get_all_positions(address),
+            self.get_orders(None, None),
+            self.get_pnl_summary(address)
+        );
+
+        match (positions, orders) {
+            (Ok(positions), Ok(orders)) => {
+                let mut summary = PortfolioSummary::new(positions, orders);
+                summary.pnl = pnl.inspect_err(|e| warn!(error = %e, "P&L fetch failed")).ok();
+                Ok(summary)
+            }
+            (Err(e), Ok(_)) | (Ok(_), Err(e)) => Err(e),
+            (Err(positions_err), Err(orders_err)) => Err(ApiError::Api(format!(
+                "Portfolio fetch failed - positions: {}; orders: {}",
+                positions_err, orders_err
+            ))),
+        }
+    }
+
+    /// Fetch a single order by ID (any status, not just open)
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_order(&self, order_id: &str) -> Result<Order, ApiError> {
+        let hmac = self.hmac().await?;
+
+        // Encoded so a `/` or `?` in the ID can't reach (and sign for) another endpoint
+        let path = format!("/data/order/{}", urlencoding::encode(order_id));
+        let url = format!("{}{}", self.inner.base_url, path);
+        let headers = hmac.generate_headers("GET", &path, None)?;
+
+        let response = self
+            .logged_send(headers.apply_to_request(self.inner.client.get(&url)), "get_order")
+            .await?;
+
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+
+        if !status.is_success() {
+            return Err(ApiError::Api(format!("Order request failed ({}): {}", status, text)));
+        }
+
+        // AIDEV-NOTE: Unknown IDs come back as 200 with a null body
+        let order: Option<Order> = serde_json::from_str(&text).map_err(|e| {
+            error!("Failed to parse order: {}. Response: {}", e, body_preview(&text));
+            ApiError::Api(format!("Failed to parse order: {}", e))
+        })?;
+        order.ok_or_else(|| ApiError::Api(format!("Order not found: {}", order_id)))
+    }
+
+    /// Open orders in one market (condition ID)
+    pub async fn get_open_orders_for_market(&self, market: &str) -> Result<Vec<Order>, ApiError> {
+        self.get_orders(Some(market), None).await
+    }
+
+    /// Get fills for a specific order
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_trades_for_order(&self, order_id: &str) -> Result<Vec<OrderFill>, ApiError> {
+        let hmac = self.hmac().await?;
+
+        // AIDEV-NOTE: HMAC signature uses path only, not query params
+        let path = "/data/trades";
+        let url = format!("{}{}?order_id={}", self.inner.base_url, path, urlencoding::encode(order_id));
+        let headers = hmac.generate_headers("GET", path, None)?;
+
+        debug!(%order_id, "Fetching fills");
+
+        let response = self
+            .logged_send(headers.apply_to_request(self.inner.client.get(&url)), "get_trades_for_order")
+            .await?;
+
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+
+        if !status.is_success() {
+            return Err(ApiError::Api(format!("Trades request failed ({}): {}", status, text)));
+        }
+
+        let trades = match serde_json::from_str::<TradesResponse>(&text) {
+            Ok(TradesResponse::Paged { data }) | Ok(TradesResponse::List(data)) => data,
+            Err(e) => {
+                error!("Failed to parse trades: {}. Response: {}", e, body_preview(&text));
+                return Err(ApiError::Api(format!("Failed to parse trades: {}", e)));
+            }
+        };
+
+        let fills: Vec<OrderFill> = trades.iter().filter_map(|t| t.fill_for(order_id)).collect();
+        debug!(%order_id, count = fills.len(), "Fetched fills");
+        Ok(fills)
+    }
+
     // ========== Order Placement & Cancellation ==========
 
     /// Place a new order
     /// AIDEV-NOTE: Requires EIP-712 signed order + L2 HMAC headers
-    #[instrument(skip(self, signed_order))]
+    #[instrument(skip(self, signed_order), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
     pub async fn place_order(
         &self,
         signed_order: SignedOrder,
         owner: &str,
         order_type: OrderType,
     ) -> Result<PlaceOrderResponse, ApiError> {
-        let hmac = self.hmac_auth.as_ref()
-            .ok_or_else(|| ApiError::Auth("Not authenticated".to_string()))?;
+        let hmac = self.hmac().await?;
 
         let path = "/order";
-        let url = format!("{}{}", self.base_url, path);
+        let url = format!("{}{}", self.inner.base_url, path);
 
         let request = PlaceOrderRequest {
             order: signed_order,
@@ -232,21 +779,22 @@ impl ClobClient {
         let body_json = serde_json::to_string(&request)
             .map_err(|e| ApiError::Api(format!("Failed to serialize order: {}", e)))?;
 
-        debug!("Placing order at: {}", url);
-        debug!("Order body: {}", body_json);
+        debug!(%url, "Placing order");
+        debug!(body = %body_json, "Order body");
 
         let headers = hmac.generate_headers("POST", path, Some(&body_json))?;
 
-        let response = headers.apply_to_request(
-            self.client.post(&url)
+        let request = headers.apply_to_request(
+            self.inner.client.post(&url)
                 .header("Content-Type", "application/json")
                 .body(body_json)
-        ).send().await?;
+        );
+        let response = self.logged_send(request, "place_order").await?;
 
         let status = response.status();
         let text = response.text().await.unwrap_or_default();
 
-        debug!("Place order response ({}): {}", status, text);
+        debug!(%status, body = %text, "Place order response");
 
         if !status.is_success() {
             return Err(ApiError::Api(format!("Order placement failed ({}): {}", status, text)));
@@ -258,27 +806,84 @@ impl ClobClient {
         Ok(result)
     }
 
+    /// Place several orders in one request (e.g. quoting both outcomes of a market)
+    /// Responses come back in the same order as `orders`
+    /// AIDEV-NOTE: POST /orders takes a JSON array; the HMAC covers the serialized array
+    #[instrument(skip(self, orders), fields(count = orders.len(), status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn place_orders_batch(
+        &self,
+        orders: Vec<PlaceOrderRequest>,
+    ) -> Result<Vec<PlaceOrderResponse>, ApiError> {
+        if orders.is_empty() {
+            return Ok(Vec::new());
+        }
+        if orders.len() > MAX_BATCH_ORDERS {
+            return Err(ApiError::Api(format!(
+                "Too many orders in batch: {} (max {})",
+                orders.len(),
+                MAX_BATCH_ORDERS
+            )));
+        }
+
+        let hmac = self.hmac().await?;
+
+        let path = "/orders";
+        let url = format!("{}{}", self.inner.base_url, path);
+
+        let body_json = serde_json::to_string(&orders)
+            .map_err(|e| ApiError::Api(format!("Failed to serialize orders: {}", e)))?;
+
+        debug!(%url, count = orders.len(), "Placing orders");
+        debug!(body = %body_json, "Orders body");
+
+        let headers = hmac.generate_headers("POST", path, Some(&body_json))?;
+
+        let request = headers.apply_to_request(
+            self.inner.client.post(&url)
+                .header("Content-Type", "application/json")
+                .body(body_json)
+        );
+        let response = self.logged_send(request, "place_orders_batch").await?;
+
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+
+        debug!(%status, body = %text, "Place orders response");
+
+        if !status.is_success() {
+            return Err(ApiError::Api(format!("Batch order placement failed ({}): {}", status, text)));
+        }
+
+        let results: Vec<PlaceOrderResponse> = serde_json::from_str(&text)
+            .map_err(|e| ApiError::Api(format!("Failed to parse batch order response: {}", e)))?;
+
+        if results.len() != orders.len() {
+            warn!("Batch order response has {} results for {} orders", results.len(), orders.len());
+        }
+
+        Ok(results)
+    }
+
     /// Cancel a specific order by ID
-    #[instrument(skip(self))]
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
     pub async fn cancel_order(&self, order_id: &str) -> Result<CancelResponse, ApiError> {
-        let hmac = self.hmac_auth.as_ref()
-            .ok_or_else(|| ApiError::Auth("Not authenticated".to_string()))?;
+        let hmac = self.hmac().await?;
 
         // AIDEV-NOTE: Path for HMAC is just /order, query params are separate
         let path = "/order";
-        let url = format!("{}{}?orderID={}", self.base_url, path, order_id);
+        let url = format!("{}{}?orderID={}", self.inner.base_url, path, order_id);
         let headers = hmac.generate_headers("DELETE", path, None)?;
 
-        debug!("Cancelling order: {}", order_id);
+        debug!(%order_id, "Cancelling order");
 
-        let response = headers.apply_to_request(self.client.delete(&url))
-            .send()
+        let response = self
+            .logged_send(headers.apply_to_request(self.inner.client.delete(&url)), "cancel_order")
             .await?;
 
         let status = response.status();
         let text = response.text().await.unwrap_or_default();
 
-        debug!("Cancel order response ({}): {}", status, text);
+        debug!(%status, body = %text, "Cancel order response");
 
         if !status.is_success() {
             return Err(ApiError::Api(format!("Cancel failed ({}): {}", status, text)));
@@ -290,26 +895,150 @@ impl ClobClient {
         Ok(result)
     }
 
+    /// Replace an open order with a new one (cancel + place, NOT atomic)
+    ///
+    /// The CLOB has no amend endpoint, so this is two independent requests ordered
+    /// by the client's `ReplacePolicy`. If the first leg fails the second is skipped:
+    /// - PlaceFirst: a rejected new order leaves the old one resting untouched. If the
+    ///   cancel then fails, BOTH orders are live - check `old_order_cancelled()`.
+    /// - CancelFirst: a failed cancel leaves the old order and nothing is placed. If the
+    ///   place then fails, NO order is live - check `new_order_placed()`.
+    ///
+    /// The old order may also fill between the two requests; that shows up as a
+    /// cancel failure (`not_canceled`) with the fill already done.
+    #[instrument(skip(self, new_order), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn replace_order(
+        &self,
+        order_id: &str,
+        new_order: SignedOrder,
+        owner: &str,
+        order_type: OrderType,
+    ) -> ReplaceResult {
+        let mut result = ReplaceResult { policy: self.inner.replace_policy, ..Default::default() };
+
+        match self.inner.replace_policy {
+            ReplacePolicy::PlaceFirst => {
+                self.replace_place_leg(&mut result, new_order, owner, order_type).await;
+                if result.new_order_placed() {
+                    self.replace_cancel_leg(&mut result, order_id).await;
+                }
+            }
+            ReplacePolicy::CancelFirst => {
+                self.replace_cancel_leg(&mut result, order_id).await;
+                if result.old_order_cancelled() {
+                    self.replace_place_leg(&mut result, new_order, owner, order_type).await;
+                }
+            }
+        }
+
+        if !result.is_complete() {
+            warn!(
+                "Replace of {} incomplete: placed={} cancelled={}",
+                order_id,
+                result.new_order_placed(),
+                result.old_order_cancelled()
+            );
+        }
+
+        result
+    }
+
+    async fn replace_place_leg(
+        &self,
+        result: &mut ReplaceResult,
+        new_order: SignedOrder,
+        owner: &str,
+        order_type: OrderType,
+    ) {
+        match self.place_order(new_order, owner, order_type).await {
+            Ok(response) => {
+                if !response.success {
+                    result.place_error = Some(
+                        response.error_msg.clone().unwrap_or_else(|| "Order rejected".to_string()),
+                    );
+                }
+                result.placed = Some(response);
+            }
+            Err(e) => result.place_error = Some(e.to_string()),
+        }
+    }
+
+    async fn replace_cancel_leg(&self, result: &mut ReplaceResult, order_id: &str) {
+        match self.cancel_order(order_id).await {
+            Ok(response) => {
+                if !response.canceled.iter().any(|id| id == order_id) {
+                    let reason = response
+                        .not_canceled
+                        .get(order_id)
+                        .cloned()
+                        .unwrap_or_else(|| "Order not cancelled".to_string());
+                    result.cancel_error = Some(reason);
+                }
+                result.cancelled = Some(response);
+            }
+            Err(e) => result.cancel_error = Some(e.to_string()),
+        }
+    }
+
+    /// Cancel a specific set of orders in one request
+    /// AIDEV-NOTE: DELETE /orders takes a JSON array of IDs; HMAC covers that body
+    #[instrument(skip(self, order_ids), fields(count = order_ids.len(), status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn cancel_orders(&self, order_ids: &[String]) -> Result<CancelResponse, ApiError> {
+        // Before hmac() - nothing to cancel shouldn't cost a server-time sync
+        if order_ids.is_empty() {
+            return Ok(CancelResponse::default());
+        }
+
+        let hmac = self.hmac().await?;
+
+        let path = "/orders";
+        let url = format!("{}{}", self.inner.base_url, path);
+        let body_json = serde_json::to_string(order_ids)?;
+        let headers = hmac.generate_headers("DELETE", path, Some(&body_json))?;
+
+        debug!(count = order_ids.len(), "Cancelling orders");
+
+        let request = headers.apply_to_request(
+            self.inner.client.delete(&url)
+                .header("Content-Type", "application/json")
+                .body(body_json)
+        );
+        let response = self.logged_send(request, "cancel_orders").await?;
+
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+
+        debug!(%status, body = %text, "Cancel orders response");
+
+        if !status.is_success() {
+            return Err(ApiError::Api(format!("Batch cancel failed ({}): {}", status, text)));
+        }
+
+        let result: CancelResponse = serde_json::from_str(&text)
+            .map_err(|e| ApiError::Api(format!("Failed to parse cancel response: {}", e)))?;
+
+        Ok(result)
+    }
+
     /// Cancel all open orders
-    #[instrument(skip(self))]
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
     pub async fn cancel_all_orders(&self) -> Result<CancelResponse, ApiError> {
-        let hmac = self.hmac_auth.as_ref()
-            .ok_or_else(|| ApiError::Auth("Not authenticated".to_string()))?;
+        let hmac = self.hmac().await?;
 
         let path = "/cancel-all";
-        let url = format!("{}{}", self.base_url, path);
+        let url = format!("{}{}", self.inner.base_url, path);
         let headers = hmac.generate_headers("DELETE", path, None)?;
 
         debug!("Cancelling all orders");
 
-        let response = headers.apply_to_request(self.client.delete(&url))
-            .send()
+        let response = self
+            .logged_send(headers.apply_to_request(self.inner.client.delete(&url)), "cancel_all_orders")
             .await?;
 
         let status = response.status();
         let text = response.text().await.unwrap_or_default();
 
-        debug!("Cancel all response ({}): {}", status, text);
+        debug!(%status, body = %text, "Cancel all response");
 
         if !status.is_success() {
             return Err(ApiError::Api(format!("Cancel all failed ({}): {}", status, text)));
@@ -321,6 +1050,259 @@ impl ClobClient {
         Ok(result)
     }
 
+    /// Cancel all open orders, then fetch what is still open (e.g. orders that refused
+    /// to cancel) so the caller can update its view without a second round trip
+    #[instrument(skip(self))]
+    pub async fn cancel_all_orders_and_refresh(&self) -> Result<(CancelResponse, Vec<Order>), ApiError> {
+        let cancelled = self.cancel_all_orders().await?;
+        let remaining = self.get_orders(None, None).await?;
+        Ok((cancelled, remaining))
+    }
+
+    // ========== Market Lookups ==========
+
+    /// Translate a condition_id to the Gamma numeric market ID
+    /// AIDEV-NOTE: Results are cached in the embedded GammaClient, so repeat lookups skip the network
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_gamma_market_id(&self, condition_id: &str) -> Result<String, ApiError> {
+        self.inner.gamma.get_gamma_market_id(condition_id).await
+    }
+
+    /// Synchronous cache read for a previously resolved condition_id
+    pub fn cached_gamma_id(&self, condition_id: &str) -> Option<String> {
+        self.inner.gamma.cached_gamma_id(condition_id)
+    }
+
+    /// Gamma market metadata for a CLOB token ID (cached in the embedded GammaClient)
+    pub async fn get_gamma_market_for_token(&self, token_id: &str) -> Result<Market, ApiError> {
+        self.inner.gamma.get_market_by_token_id(token_id).await
+    }
+
+    // ========== Quotes ==========
+
+    /// Get the current midpoint price for a token
+    /// AIDEV-NOTE: No auth required - response is {"mid": "0.55"}
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_midpoint(&self, token_id: &str) -> Result<f64, ApiError> {
+        let url = format!("{}/midpoint?token_id={}", self.inner.base_url, urlencoding::encode(token_id));
+        let value = self.get_public_json(&url, "Midpoint").await?;
+
+        parse_price_field(&value, "mid")
+            .ok_or_else(|| ApiError::Api(format!("Midpoint response missing 'mid': {}", value)))
+    }
+
+    /// Get the last trade price for a token
+    /// AIDEV-NOTE: No auth required - response is {"price": "0.55", "side": "BUY"}
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_last_trade_price(&self, token_id: &str) -> Result<f64, ApiError> {
+        let url = format!("{}/last-trade-price?token_id={}", self.inner.base_url, urlencoding::encode(token_id));
+        let value = self.get_public_json(&url, "Last trade price").await?;
+
+        parse_price_field(&value, "price")
+            .ok_or_else(|| ApiError::Api(format!("Last trade price response missing 'price': {}", value)))
+    }
+
+    /// Fetch midpoint + last trade price for many tokens with bounded concurrency
+    /// AIDEV-NOTE: Per-token failures are logged and leave the quote field as None
+    #[instrument(skip(self, token_ids), fields(tokens = token_ids.len(), status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_token_quotes(
+        &self,
+        token_ids: &[String],
+        concurrency: usize,
+    ) -> HashMap<String, TokenQuote> {
+        stream::iter(token_ids.iter().cloned())
+            .map(|token_id| async move {
+                let (midpoint, last_trade_price) = tokio::join!(
+                    self.get_midpoint(&token_id),
+                    self.get_last_trade_price(&token_id),
+                );
+
+                let midpoint = midpoint
+                    .map_err(|e| debug!(%token_id, error = %e, "Midpoint unavailable"))
+                    .ok();
+                let last_trade_price = last_trade_price
+                    .map_err(|e| debug!(%token_id, error = %e, "Last trade price unavailable"))
+                    .ok();
+
+                let quote = TokenQuote { token_id: token_id.clone(), midpoint, last_trade_price };
+                (token_id, quote)
+            })
+            .buffer_unordered(concurrency.max(1))
+            .collect()
+            .await
+    }
+
+    /// Fetch the current order book for a token over REST (no WebSocket needed)
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_orderbook(&self, token_id: &str) -> Result<OrderBook, ApiError> {
+        let url = format!("{}/book?token_id={}", self.inner.base_url, urlencoding::encode(token_id));
+        let value = self.get_public_json(&url, "Order book").await?;
+
+        let book: BookResponse = serde_json::from_value(value)?;
+        Ok(OrderBook {
+            asset_id: book.asset_id,
+            market: book.market,
+            timestamp: None,
+            bids: book.bids,
+            asks: book.asks,
+        })
+    }
+
+    /// Best bid/ask, spread and midpoint for a token
+    /// Convenience wrapper over `get_orderbook` that keeps only the top level of each side
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_book_spread(&self, token_id: &str) -> Result<BookSpread, ApiError> {
+        let book = self.get_orderbook(token_id).await?;
+
+        book.spread()
+            .ok_or_else(|| ApiError::Api(format!("Order book for {} has no bid or no ask", token_id)))
+    }
+
+    /// Current maker/taker fee rates in basis points
+    /// AIDEV-NOTE: No auth required. Some deployments only return {"base_fee": N} -
+    /// that is used for both sides when the split fields are absent.
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_fee_rates(&self) -> Result<FeeRates, ApiError> {
+        let url = format!("{}/fee-rate-bps", self.inner.base_url);
+        let value = self.get_public_json(&url, "Fee rates").await?;
+
+        let raw: RawFeeRates = serde_json::from_value(value)?;
+        let maker = raw.maker_fee_rate_bps.or(raw.base_fee);
+        let taker = raw.taker_fee_rate_bps.or(raw.base_fee);
+        match (maker, taker) {
+            (Some(maker_fee_rate_bps), Some(taker_fee_rate_bps)) => Ok(FeeRates {
+                maker_fee_rate_bps,
+                taker_fee_rate_bps,
+            }),
+            _ => Err(ApiError::Api("Fee rate response missing maker/taker rates".to_string())),
+        }
+    }
+
+    /// Server clock minus local clock in seconds, from GET /time (cached after first fetch)
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn server_time_offset(&self) -> Result<i64, ApiError> {
+        if let Some(offset) = *self.inner.time_offset.read() {
+            return Ok(offset);
+        }
+
+        let url = format!("{}/time", self.inner.base_url);
+        let before = unix_time_secs_f64();
+        let value = self.get_public_json(&url, "Server time").await?;
+        let after = unix_time_secs_f64();
+
+        let server = value
+            .as_i64()
+            .or_else(|| value.as_str().and_then(|s| s.trim().parse().ok()))
+            .ok_or_else(|| ApiError::Api(format!("Unexpected /time response: {}", value)))?;
+
+        // Compare against the midpoint of the request to cancel out latency
+        let offset = (server as f64 - (before + after) / 2.0).round() as i64;
+        debug!(offset_secs = offset, "Server time offset");
+
+        *self.inner.time_offset.write() = Some(offset);
+        Ok(offset)
+    }
+
+    /// Credentials for authenticated calls, or the standard "not authenticated" error
+    /// AIDEV-NOTE: Single gate for every L2 method (via `hmac`) - token refresh would go here
+    fn require_auth(&self) -> Result<&HmacAuth, ApiError> {
+        self.inner
+            .hmac_auth
+            .as_ref()
+            .ok_or_else(|| ApiError::Auth("Not authenticated. Call with_credentials first.".to_string()))
+    }
+
+    /// HMAC helper for authenticated calls, with the server time offset applied if enabled
+    async fn hmac(&self) -> Result<&HmacAuth, ApiError> {
+        let hmac = self.require_auth()?;
+
+        if self.inner.server_time_sync {
+            hmac.set_time_offset(self.server_time_offset().await?);
+        }
+
+        Ok(hmac)
+    }
+
+    /// Health check: round-trip latency of GET /time
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn ping(&self) -> Result<std::time::Duration, ApiError> {
+        let url = format!("{}/time", self.inner.base_url);
+        let started = Instant::now();
+
+        let response = self.logged_send(self.inner.client.get(&url), "ping").await?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(ApiError::Api(format!("CLOB ping failed ({})", status)));
+        }
+
+        Ok(started.elapsed())
+    }
+
+    /// Send a request inside a tracing span with method, URL, status and timing
+    /// AIDEV-NOTE: Error bodies are read for the warn! preview, then re-wrapped so
+    /// callers can still consume the response as if it came straight off the wire
+    /// AIDEV-NOTE: status_code/elapsed_ms are also recorded on the calling method's
+    /// #[instrument] span (declared Empty there) so it can be indexed by operation.
+    /// Methods that send several requests end up with the last one's values.
+    async fn logged_send(&self, req: RequestBuilder, op: &str) -> Result<Response, ApiError> {
+        let caller = tracing::Span::current();
+        let request = req.build()?;
+        let span = info_span!(
+            "clob_request",
+            op,
+            http.method = %request.method(),
+            http.url = %request.url(),
+            http.status = field::Empty,
+            elapsed_ms = field::Empty,
+        );
+
+        async {
+            let started = Instant::now();
+            let response = self.inner.client.execute(request).await?;
+            let status = response.status();
+
+            let elapsed_ms = started.elapsed().as_millis() as u64;
+
+            let span = tracing::Span::current();
+            span.record("http.status", status.as_u16());
+            span.record("elapsed_ms", elapsed_ms);
+            caller.record("status_code", status.as_u16());
+            caller.record("elapsed_ms", elapsed_ms);
+
+            if status.is_success() {
+                debug!(%op, %status, "Request succeeded");
+                return Ok(response);
+            }
+
+            let headers = response.headers().clone();
+            let body = response.bytes().await?;
+            warn!("{} failed ({}): {}", op, status, body_preview(&String::from_utf8_lossy(&body)));
+
+            let mut rebuilt = http::Response::new(body);
+            *rebuilt.status_mut() = status;
+            *rebuilt.headers_mut() = headers;
+            Ok(Response::from(rebuilt))
+        }
+        .instrument(span)
+        .await
+    }
+
+    /// GET a public (unauthenticated) CLOB endpoint and parse the JSON body
+    async fn get_public_json(&self, url: &str, what: &str) -> Result<serde_json::Value, ApiError> {
+        debug!(%url, what = %what.to_lowercase(), "Fetching");
+
+        let response = self.logged_send(self.inner.client.get(url), what).await?;
+        let status = response.status();
+        let text = response.text().await.unwrap_or_default();
+
+        if !status.is_success() {
+            return Err(ApiError::Api(format!("{} request failed ({}): {}", what, status, text)));
+        }
+
+        serde_json::from_str(&text)
+            .map_err(|e| ApiError::Api(format!("Failed to parse {} response: {}", what.to_lowercase(), e)))
+    }
+
     // ========== Price History ==========
 
     /// Fetch price history for a token
@@ -330,7 +1312,7 @@ impl ClobClient {
     /// - interval: "1h", "6h", "1d", "1w", "max" (optional, defaults to "max")
     /// - fidelity: resolution in minutes (optional, e.g., 60 for hourly)
     /// - start_ts/end_ts: Unix timestamps for custom range (optional)
-    #[instrument(skip(self))]
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
     pub async fn get_price_history(
         &self,
         token_id: &str,
@@ -339,7 +1321,7 @@ impl ClobClient {
         start_ts: Option<i64>,
         end_ts: Option<i64>,
     ) -> Result<Vec<PricePoint>, ApiError> {
-        let mut url = format!("{}/prices-history?market={}", self.base_url, token_id);
+        let mut url = format!("{}/prices-history?market={}", self.inner.base_url, token_id);
 
         // Add optional parameters
         if let Some(iv) = interval {
@@ -355,9 +1337,9 @@ impl ClobClient {
             url.push_str(&format!("&endTs={}", end));
         }
 
-        debug!("Fetching price history from: {}", url);
+        debug!(%url, "Fetching price history");
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.logged_send(self.inner.client.get(&url), "get_price_history").await?;
 
         let status = response.status();
         if !status.is_success() {
@@ -370,35 +1352,73 @@ impl ClobClient {
 
         let text = response.text().await?;
         let parsed: PriceHistoryResponse = serde_json::from_str(&text).map_err(|e| {
-            debug!("Failed to parse price history: {}. Response: {}", e, &text[..text.len().min(500)]);
+            debug!(error = %e, body = %body_preview(&text), "Failed to parse price history");
             ApiError::Api(format!("Failed to parse price history: {}", e))
         })?;
 
-        debug!("Fetched {} price history points for {}", parsed.history.len(), token_id);
+        debug!(%token_id, count = parsed.history.len(), "Fetched price history");
         Ok(parsed.history)
     }
 
+    /// Fetch price history in bounded time windows, handing each chunk to `on_chunk`
+    /// AIDEV-NOTE: Keeps memory bounded for long ranges - callers can persist each chunk as it arrives
+    /// Windows are inclusive and tile [start_ts, end_ts] exactly (see `price_history_windows`).
+    /// Returns the total number of points fetched.
+    #[instrument(skip(self, on_chunk), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_price_history_chunked<F>(
+        &self,
+        token_id: &str,
+        start_ts: i64,
+        end_ts: i64,
+        window_secs: i64,
+        fidelity: Option<u32>,
+        mut on_chunk: F,
+    ) -> Result<usize, ApiError>
+    where
+        F: FnMut(Vec<PricePoint>),
+    {
+        if window_secs <= 0 {
+            return Err(ApiError::Api(format!("Invalid price history window: {}s", window_secs)));
+        }
+
+        let mut total = 0;
+        for (window_start, window_end) in price_history_windows(start_ts, end_ts, window_secs) {
+            // AIDEV-NOTE: interval must be omitted when startTs/endTs are given
+            let points = self
+                .get_price_history(token_id, None, fidelity, Some(window_start), Some(window_end))
+                .await?;
+
+            debug!(window_start, window_end, count = points.len(), "Fetched price history window");
+            total += points.len();
+
+            if !points.is_empty() {
+                on_chunk(points);
+            }
+        }
+
+        Ok(total)
+    }
+
     /// Cancel all orders for a specific market
-    #[instrument(skip(self))]
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
     pub async fn cancel_market_orders(&self, market_id: &str) -> Result<CancelResponse, ApiError> {
-        let hmac = self.hmac_auth.as_ref()
-            .ok_or_else(|| ApiError::Auth("Not authenticated".to_string()))?;
+        let hmac = self.hmac().await?;
 
         // AIDEV-NOTE: Path for HMAC is just /cancel-market-orders
         let path = "/cancel-market-orders";
-        let url = format!("{}{}?market={}", self.base_url, path, market_id);
+        let url = format!("{}{}?market={}", self.inner.base_url, path, market_id);
         let headers = hmac.generate_headers("DELETE", path, None)?;
 
-        debug!("Cancelling orders for market: {}", market_id);
+        debug!(%market_id, "Cancelling market orders");
 
-        let response = headers.apply_to_request(self.client.delete(&url))
-            .send()
+        let response = self
+            .logged_send(headers.apply_to_request(self.inner.client.delete(&url)), "cancel_market_orders")
             .await?;
 
         let status = response.status();
         let text = response.text().await.unwrap_or_default();
 
-        debug!("Cancel market orders response ({}): {}", status, text);
+        debug!(%status, body = %text, "Cancel market orders response");
 
         if !status.is_success() {
             return Err(ApiError::Api(format!("Cancel market orders failed ({}): {}", status, text)));
@@ -409,6 +1429,89 @@ impl ClobClient {
 
         Ok(result)
     }
+
+    /// Cancel all open orders for one outcome token (e.g. only YES on a multi-outcome market)
+    /// AIDEV-NOTE: The CLOB has no per-token cancel endpoint, so this fetches open orders,
+    /// keeps those for `token_id` and batch-cancels them. Orders placed between the two
+    /// requests are not cancelled.
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn cancel_token_orders(&self, token_id: &str) -> Result<CancelResponse, ApiError> {
+        let order_ids: Vec<String> = self
+            .get_orders(None, None)
+            .await?
+            .into_iter()
+            .filter(|order| order.asset == token_id)
+            .map(|order| order.id)
+            .collect();
+
+        debug!(%token_id, count = order_ids.len(), "Cancelling token orders");
+        self.cancel_orders(&order_ids).await
+    }
+}
+
+/// Query string for /data/orders filters ("" when unfiltered)
+fn orders_query(market: Option<&str>, status: Option<&str>) -> String {
+    let params: Vec<String> = [("market", market), ("status", status)]
+        .into_iter()
+        .filter_map(|(key, value)| Some(format!("{}={}", key, urlencoding::encode(value?))))
+        .collect();
+
+    if params.is_empty() {
+        String::new()
+    } else {
+        format!("?{}", params.join("&"))
+    }
+}
+
+/// Local Unix time with sub-second precision
+fn unix_time_secs_f64() -> f64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs_f64())
+        .unwrap_or(0.0)
+}
+
+/// Read a price field that the CLOB may encode as either a string or a number
+fn parse_price_field(value: &serde_json::Value, field: &str) -> Option<f64> {
+    match value.get(field)? {
+        serde_json::Value::String(s) => s.parse().ok(),
+        serde_json::Value::Number(n) => n.as_f64(),
+        _ => None,
+    }
+}
+
+/// Default price history fidelity (minutes per point) for a chart interval
+/// AIDEV-NOTE: Keeps every chart to a few hundred points; unknown intervals are
+/// treated like "max" since that is the endpoint's default
+pub fn recommended_fidelity(interval: &str) -> u32 {
+    match interval {
+        "1h" => 1,
+        "6h" => 5,
+        "1d" => 60,
+        "1w" => 360,
+        _ => 1440,
+    }
+}
+
+/// Split [start_ts, end_ts] into inclusive windows of at most `window_secs` seconds
+/// Consecutive windows never overlap and leave no gaps: next.start == prev.end + 1
+pub fn price_history_windows(start_ts: i64, end_ts: i64, window_secs: i64) -> Vec<(i64, i64)> {
+    let mut windows = Vec::new();
+    if window_secs <= 0 || start_ts > end_ts {
+        return windows;
+    }
+
+    let mut window_start = start_ts;
+    loop {
+        let window_end = window_start.saturating_add(window_secs - 1).min(end_ts);
+        windows.push((window_start, window_end));
+        if window_end >= end_ts {
+            break;
+        }
+        window_start = window_end + 1;
+    }
+
+    windows
 }
 
 impl Default for ClobClient {
@@ -416,3 +1519,81 @@ impl Default for ClobClient {
         Self::new()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn credentials() -> ApiCredentials {
+        ApiCredentials {
+            api_key: "key".to_string(),
+            api_secret: "c2VjcmV0".to_string(),
+            api_passphrase: "pass".to_string(),
+            address: "0x0000000000000000000000000000000000000001".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_clone_shares_config() {
+        let client = ClobClient::new().with_base_url("http://localhost:1234/");
+        let clone = client.clone();
+
+        assert!(Arc::ptr_eq(&client.inner, &clone.inner));
+        assert_eq!(clone.inner.base_url, "http://localhost:1234");
+    }
+
+    #[test]
+    fn test_updates_are_copy_on_write() {
+        let mut client = ClobClient::new();
+        let before = client.clone();
+
+        client.set_credentials(&credentials()).unwrap();
+        assert!(!Arc::ptr_eq(&client.inner, &before.inner));
+        assert!(client.inner.hmac_auth.is_some());
+        assert!(before.inner.hmac_auth.is_none());
+
+        // Server time offset cache stays shared with pre-existing clones
+        *client.inner.time_offset.write() = Some(3);
+        assert_eq!(*before.inner.time_offset.read(), Some(3));
+    }
+
+    #[tokio::test]
+    async fn test_require_auth() {
+        let err = ClobClient::new().get_orders(None, None).await.unwrap_err();
+        assert!(matches!(&err, ApiError::Auth(msg) if msg.contains("with_credentials")), "{:?}", err);
+
+        let client = ClobClient::with_credentials(&credentials()).unwrap();
+        assert!(client.require_auth().is_ok());
+    }
+
+    #[test]
+    fn test_with_config() {
+        let config = ClobClientConfig {
+            base_url: "http://127.0.0.1:9000/".to_string(),
+            data_api_url: "http://127.0.0.1:9001".to_string(),
+            timeout: Some(Duration::from_secs(5)),
+            credentials: Some(credentials()),
+            signature_type: SignatureType::Proxy,
+            replace_policy: ReplacePolicy::CancelFirst,
+            server_time_sync: true,
+        };
+
+        let client = ClobClient::with_config(config.clone()).unwrap();
+        assert_eq!(client.inner.base_url, "http://127.0.0.1:9000");
+        assert_eq!(client.inner.data_api_url, "http://127.0.0.1:9001");
+        assert!(client.require_auth().is_ok());
+        assert_eq!(client.signature_type(), SignatureType::Proxy);
+        assert_eq!(client.inner.replace_policy, ReplacePolicy::CancelFirst);
+        assert!(client.inner.server_time_sync);
+
+        // Malformed credentials are rejected like with_credentials
+        let mut bad = credentials();
+        bad.address = "0xnope".to_string();
+        assert!(ClobClient::with_config(ClobClientConfig { credentials: Some(bad), ..config }).is_err());
+
+        // Defaults match new()
+        let client = ClobClient::with_config(ClobClientConfig::default()).unwrap();
+        assert_eq!(client.inner.base_url, CLOB_API_BASE);
+        assert!(client.require_auth().is_err());
+    }
+}
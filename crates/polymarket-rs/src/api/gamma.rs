@@ -1,18 +1,43 @@
 // AIDEV-NOTE: Gamma API client for market metadata (public, no auth)
 
-use reqwest::Client;
-use tracing::{debug, error, instrument};
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use chrono::{DateTime, SecondsFormat, Utc};
+use futures_util::stream::{self, StreamExt};
+use parking_lot::RwLock;
+use reqwest::{Client, Response};
+use serde::Deserialize;
+use tracing::{debug, error, field, instrument, warn, Span};
 
 use crate::error::ApiError;
-use crate::types::{Event, Market, RawMarket};
+use crate::types::{Event, Market, MarketCategory, Position, RawMarket};
 
 const GAMMA_API_BASE: &str = "https://gamma-api.polymarket.com";
 
+/// Max condition IDs per Gamma request (keeps query strings well under URL limits)
+const CONDITION_IDS_PER_REQUEST: usize = 20;
+
+/// Categories change rarely - refetch at most this often
+const CATEGORY_CACHE_TTL: Duration = Duration::from_secs(60 * 60);
+
+/// Cached category list and when it was fetched
+/// AIDEV-NOTE: tokio RwLock so the write guard can be held across the refresh request,
+/// letting concurrent callers wait for one fetch instead of each issuing their own
+type CategoryCache = Arc<tokio::sync::RwLock<Option<(Vec<MarketCategory>, Instant)>>>;
+
 /// Client for the Polymarket Gamma API (market metadata)
 #[derive(Clone)]
 pub struct GammaClient {
     client: Client,
     base_url: String,
+    /// condition_id (lowercase) -> Gamma numeric market ID, shared across clones
+    gamma_ids: Arc<RwLock<HashMap<String, String>>>,
+    /// CLOB token ID -> market, shared across clones (see `get_market_by_token_id`)
+    token_markets: Arc<RwLock<HashMap<String, Market>>>,
+    /// Market categories, shared across clones
+    categories: CategoryCache,
 }
 
 // AIDEV-NOTE: API returns arrays directly, not wrapped objects
@@ -22,99 +47,289 @@ impl GammaClient {
         Self {
             client: Client::new(),
             base_url: GAMMA_API_BASE.to_string(),
+            gamma_ids: Arc::new(RwLock::new(HashMap::new())),
+            token_markets: Arc::new(RwLock::new(HashMap::new())),
+            categories: Arc::new(tokio::sync::RwLock::new(None)),
         }
     }
 
+    /// Override the Gamma base URL (e.g. for a local mock server)
+    pub fn with_base_url(mut self, base_url: &str) -> Self {
+        self.base_url = base_url.trim_end_matches('/').to_string();
+        self
+    }
+
+    /// Health check: round-trip latency of a one-market query
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn ping(&self) -> Result<std::time::Duration, ApiError> {
+        let url = format!("{}/markets?limit=1", self.base_url);
+        let started = std::time::Instant::now();
+
+        let response = self.send_get(&url).await?;
+        Self::response_text(&url, response).await?;
+
+        Ok(started.elapsed())
+    }
+
     /// Fetch markets with optional filtering
-    #[instrument(skip(self))]
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
     pub async fn get_markets(
         &self,
         query: Option<&str>,
         limit: Option<u32>,
         offset: Option<u32>,
     ) -> Result<Vec<Market>, ApiError> {
-        let mut url = format!("{}/markets", self.base_url);
-        let mut params = Vec::new();
-
-        // Only show active, non-closed markets by default
-        params.push("active=true".to_string());
-        params.push("closed=false".to_string());
-        params.push("archived=false".to_string());
-
-        if let Some(q) = query {
-            if !q.is_empty() {
-                params.push(format!("slug_contains={}", urlencoding::encode(q)));
-            }
-        }
-
-        if let Some(l) = limit {
-            params.push(format!("limit={}", l));
-        } else {
-            params.push("limit=50".to_string());
-        }
+        let filter = MarketFilter {
+            query: query.map(str::to_string),
+            limit,
+            offset,
+            ..Default::default()
+        };
+        self.get_markets_filtered(&filter).await
+    }
 
-        if let Some(o) = offset {
-            params.push(format!("offset={}", o));
-        }
+    /// Fetch markets resolved by a specific oracle
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_markets_by_resolution_source(
+        &self,
+        source: ResolutionSource,
+    ) -> Result<Vec<Market>, ApiError> {
+        let filter = MarketFilter::default().with_resolution_source(source);
+        self.get_markets_filtered(&filter).await
+    }
 
-        // Sort by volume descending (API uses camelCase)
-        params.push("order=volumeNum".to_string());
-        params.push("ascending=false".to_string());
+    /// Active markets ending within the next `hours` (closing-soon alerts)
+    /// `active_trading_only` additionally requires the market to be accepting orders
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_markets_resolving_within(
+        &self,
+        hours: u32,
+        limit: Option<u32>,
+        active_trading_only: bool,
+    ) -> Result<Vec<Market>, ApiError> {
+        let filter = MarketFilter { limit, ..Default::default() }
+            .resolving_within(Utc::now(), hours)
+            .accepting_orders_only(active_trading_only);
+        self.get_markets_filtered(&filter).await
+    }
 
-        if !params.is_empty() {
-            url = format!("{}?{}", url, params.join("&"));
-        }
+    /// Fetch markets matching a `MarketFilter`
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_markets_filtered(&self, filter: &MarketFilter) -> Result<Vec<Market>, ApiError> {
+        let url = format!("{}/markets?{}", self.base_url, filter.to_query_string());
 
-        debug!("Fetching markets from: {}", url);
+        debug!(%url, "Fetching markets");
 
-        let response = self.client.get(&url).send().await?;
-        let text = response.text().await?;
+        let response = self.send_get(&url).await?;
+        let text = Self::response_text(&url, response).await?;
 
-        let raw_markets: Vec<RawMarket> = match serde_json::from_str(&text) {
-            Ok(m) => m,
-            Err(e) => {
-                tracing::error!("Failed to parse markets: {}", e);
-                tracing::error!("Response text (first 500 chars): {}", &text[..text.len().min(500)]);
-                return Err(ApiError::Json(e));
-            }
-        };
+        let raw_markets = parse_market_list(&url, &text)?;
 
-        debug!("Parsed {} markets successfully", raw_markets.len());
-        let markets: Vec<Market> = raw_markets.into_iter().map(Market::from).collect();
+        debug!(count = raw_markets.len(), "Parsed markets");
+        let markets: Vec<Market> = raw_markets
+            .into_iter()
+            .map(Market::from)
+            .filter(|m| filter.matches(m))
+            .collect();
 
         Ok(markets)
     }
 
     /// Fetch a single market by its internal ID
-    #[instrument(skip(self))]
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
     pub async fn get_market(&self, market_id: &str) -> Result<Market, ApiError> {
         // AIDEV-NOTE: Gamma API uses internal numeric ID in path, not condition_id
         let url = format!("{}/markets/{}", self.base_url, market_id);
 
-        debug!("Fetching market: {}", url);
+        debug!(%url, "Fetching market");
 
-        let response = self.client.get(&url).send().await?;
+        let response = self.send_get(&url).await?;
         let status = response.status();
-        debug!("Market response status: {}", status);
+        debug!(%status, "Market response");
 
         if status == 404 {
             return Err(ApiError::MarketNotFound(market_id.to_string()));
         }
 
-        let body = response.text().await?;
-        debug!("Market response body length: {} chars", body.len());
+        let body = Self::response_text(&url, response).await?;
+        debug!(body_len = body.len(), "Market response body");
 
         let raw_market: RawMarket = serde_json::from_str(&body).map_err(|e| {
-            error!("Failed to parse market JSON: {}", e);
-            debug!("Raw response: {}", &body[..body.len().min(500)]);
+            error!("Failed to parse market JSON from {}: {}", url, e);
+            debug!(body = %body_preview(&body), "Raw response");
             ApiError::Api(format!("Failed to parse market: {}", e))
         })?;
 
         Ok(Market::from(raw_market))
     }
 
+    /// Fetch a single market by its on-chain condition ID
+    /// AIDEV-NOTE: Gamma has no /markets/{condition_id} route - filter the list endpoint instead
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_market_by_condition_id(&self, condition_id: &str) -> Result<Market, ApiError> {
+        let url = format!(
+            "{}/markets?condition_ids={}",
+            self.base_url,
+            urlencoding::encode(condition_id)
+        );
+
+        debug!(%url, "Fetching market by condition_id");
+
+        let response = self.send_get(&url).await?;
+        let text = Self::response_text(&url, response).await?;
+
+        let raw_markets = parse_market_list(&url, &text)?;
+
+        let market = find_by_condition_id(raw_markets, condition_id)?;
+        self.cache_gamma_id(&market.condition_id, &market.id);
+        Ok(market)
+    }
+
+    /// Fetch the market an outcome token (CLOB token ID) belongs to (cached after first lookup)
+    /// AIDEV-NOTE: The cached Market is for metadata - its prices are as of the first fetch
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_market_by_token_id(&self, token_id: &str) -> Result<Market, ApiError> {
+        if let Some(market) = self.token_markets.read().get(token_id) {
+            return Ok(market.clone());
+        }
+
+        let url = format!("{}/markets?clob_token_ids={}", self.base_url, urlencoding::encode(token_id));
+        debug!(%url, "Fetching market by token ID");
+
+        let response = self.send_get(&url).await?;
+        let text = Self::response_text(&url, response).await?;
+
+        let raw_markets = parse_market_list(&url, &text)?;
+
+        let market = raw_markets
+            .into_iter()
+            .map(Market::from)
+            .find(|m| m.tokens.iter().any(|t| t.token_id == token_id))
+            .ok_or_else(|| ApiError::MarketNotFound(token_id.to_string()))?;
+
+        self.cache_gamma_id(&market.condition_id, &market.id);
+        self.token_markets.write().insert(token_id.to_string(), market.clone());
+        Ok(market)
+    }
+
+    /// Translate a condition ID to the Gamma numeric market ID (cached after first lookup)
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_gamma_market_id(&self, condition_id: &str) -> Result<String, ApiError> {
+        if let Some(id) = self.cached_gamma_id(condition_id) {
+            return Ok(id);
+        }
+
+        let market = self.get_market_by_condition_id(condition_id).await?;
+        Ok(market.id)
+    }
+
+    /// Synchronous cache read for a condition_id -> Gamma ID mapping
+    pub fn cached_gamma_id(&self, condition_id: &str) -> Option<String> {
+        self.gamma_ids.read().get(&condition_id.to_lowercase()).cloned()
+    }
+
+    fn cache_gamma_id(&self, condition_id: &str, gamma_id: &str) {
+        self.gamma_ids
+            .write()
+            .insert(condition_id.to_lowercase(), gamma_id.to_string());
+    }
+
+    /// Fetch markets for many condition IDs, batching requests with bounded concurrency
+    #[instrument(skip(self, condition_ids), fields(count = condition_ids.len(), status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_markets_by_condition_ids(
+        &self,
+        condition_ids: &[String],
+        concurrency: usize,
+    ) -> Result<Vec<Market>, ApiError> {
+        let batches: Vec<Vec<Market>> = stream::iter(condition_ids.chunks(CONDITION_IDS_PER_REQUEST).map(<[String]>::to_vec))
+            .map(|batch| async move { self.fetch_condition_batch(&batch).await })
+            .buffer_unordered(concurrency.max(1))
+            .collect::<Vec<_>>()
+            .await
+            .into_iter()
+            .collect::<Result<_, _>>()?;
+
+        let markets: Vec<Market> = batches.into_iter().flatten().collect();
+        for market in &markets {
+            self.cache_gamma_id(&market.condition_id, &market.id);
+        }
+
+        Ok(markets)
+    }
+
+    async fn fetch_condition_batch(&self, condition_ids: &[String]) -> Result<Vec<Market>, ApiError> {
+        let params: Vec<String> = condition_ids
+            .iter()
+            .map(|id| format!("condition_ids={}", urlencoding::encode(id)))
+            .collect();
+        let url = format!("{}/markets?{}", self.base_url, params.join("&"));
+
+        debug!(%url, count = condition_ids.len(), "Fetching markets by condition_id");
+
+        let response = self.send_get(&url).await?;
+        let text = Self::response_text(&url, response).await?;
+
+        let raw_markets = parse_market_list(&url, &text)?;
+
+        Ok(raw_markets.into_iter().map(Market::from).collect())
+    }
+
+    /// Fill in missing `title`/`outcome` on positions from Gamma market data
+    /// AIDEV-NOTE: Best effort - on Gamma failure positions are returned unchanged
+    #[instrument(skip(self, positions), fields(count = positions.len(), status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn enrich_positions(&self, mut positions: Vec<Position>, concurrency: usize) -> Vec<Position> {
+        let mut missing: Vec<String> = positions
+            .iter()
+            .filter(|p| p.title.is_empty() || p.outcome.is_empty())
+            .map(|p| p.condition_id.clone())
+            .collect();
+        missing.sort();
+        missing.dedup();
+
+        if missing.is_empty() {
+            return positions;
+        }
+
+        let markets = match self.get_markets_by_condition_ids(&missing, concurrency).await {
+            Ok(markets) => markets,
+            Err(e) => {
+                warn!("Position enrichment failed: {}", e);
+                return positions;
+            }
+        };
+
+        let by_condition: HashMap<String, &Market> = markets
+            .iter()
+            .map(|m| (m.condition_id.to_lowercase(), m))
+            .collect();
+
+        for position in &mut positions {
+            let Some(market) = by_condition.get(&position.condition_id.to_lowercase()) else {
+                continue;
+            };
+            if position.title.is_empty() {
+                position.title = market.question.clone();
+            }
+            if position.outcome.is_empty() {
+                if let Some(token) = market.tokens.iter().find(|t| t.token_id == position.asset) {
+                    position.outcome = token.outcome.clone();
+                }
+            }
+        }
+
+        positions
+    }
+
+    /// Get the CLOB token IDs for a condition ID
+    /// Bridges the Data API (condition_id based) and CLOB (token_id based)
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn token_ids_for_condition(&self, condition_id: &str) -> Result<Vec<String>, ApiError> {
+        let market = self.get_market_by_condition_id(condition_id).await?;
+        Ok(market.tokens.into_iter().map(|t| t.token_id).collect())
+    }
+
     /// Fetch events (market collections)
-    #[instrument(skip(self))]
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
     pub async fn get_events(&self, limit: Option<u32>) -> Result<Vec<Event>, ApiError> {
         let mut url = format!("{}/events", self.base_url);
         let mut params = Vec::new();
@@ -136,16 +351,100 @@ impl GammaClient {
             url = format!("{}?{}", url, params.join("&"));
         }
 
-        debug!("Fetching events from: {}", url);
+        debug!(%url, "Fetching events");
 
-        let response = self.client.get(&url).send().await?;
-        let events: Vec<Event> = response.json().await?;
+        let response = self.send_get(&url).await?;
+        let text = Self::response_text(&url, response).await?;
+
+        let events: Vec<Event> = serde_json::from_str(&text).map_err(|e| {
+            error!("Failed to parse events from {}: {}", url, e);
+            debug!(body = %body_preview(&text), "Raw response");
+            ApiError::Json(e)
+        })?;
 
         Ok(events)
     }
 
+    /// Fetch a single event with its nested markets, by slug or numeric ID
+    /// AIDEV-NOTE: Gamma serves numeric IDs at /events/{id} and slugs at /events/slug/{slug}
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_event(&self, slug_or_id: &str) -> Result<Event, ApiError> {
+        let slug_or_id = slug_or_id.trim();
+        let url = if !slug_or_id.is_empty() && slug_or_id.chars().all(|c| c.is_ascii_digit()) {
+            format!("{}/events/{}", self.base_url, slug_or_id)
+        } else {
+            format!("{}/events/slug/{}", self.base_url, urlencoding::encode(slug_or_id))
+        };
+
+        debug!(%url, "Fetching event");
+
+        let response = self.send_get(&url).await?;
+        if response.status() == 404 {
+            return Err(ApiError::Api(format!("Event not found: {}", slug_or_id)));
+        }
+
+        let text = Self::response_text(&url, response).await?;
+        serde_json::from_str(&text).map_err(|e| {
+            error!("Failed to parse event from {}: {}", url, e);
+            debug!(body = %body_preview(&text), "Raw response");
+            ApiError::Json(e)
+        })
+    }
+
+    /// Markets belonging to one event (`event_id` is the numeric `Event::id`)
+    /// AIDEV-NOTE: Cheaper than get_event when only the markets are needed - list
+    /// responses often omit the nested `markets` array
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_event_markets(&self, event_id: &str) -> Result<Vec<Market>, ApiError> {
+        let url = format!("{}/markets?event_id={}", self.base_url, urlencoding::encode(event_id));
+        debug!(%url, "Fetching event markets");
+
+        let response = self.send_get(&url).await?;
+        let text = Self::response_text(&url, response).await?;
+        let raw_markets = parse_market_list(&url, &text)?;
+
+        Ok(raw_markets.into_iter().map(Market::from).collect())
+    }
+
+    /// List the market categories Gamma knows about (cached for an hour)
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
+    pub async fn get_market_categories(&self) -> Result<Vec<MarketCategory>, ApiError> {
+        if let Some(categories) = Self::fresh_categories(&*self.categories.read().await) {
+            return Ok(categories);
+        }
+
+        let mut cache = self.categories.write().await;
+        // Another caller may have refreshed while we waited for the lock
+        if let Some(categories) = Self::fresh_categories(&cache) {
+            return Ok(categories);
+        }
+
+        let url = format!("{}/categories", self.base_url);
+        debug!(%url, "Fetching categories");
+
+        let response = self.send_get(&url).await?;
+        let text = Self::response_text(&url, response).await?;
+        let categories: Vec<MarketCategory> = serde_json::from_str(&text).map_err(|e| {
+            error!("Failed to parse categories from {}: {}", url, e);
+            debug!(body = %body_preview(&text), "Raw response");
+            ApiError::Json(e)
+        })?;
+
+        *cache = Some((categories.clone(), Instant::now()));
+        Ok(categories)
+    }
+
+    fn fresh_categories(
+        cache: &Option<(Vec<MarketCategory>, Instant)>,
+    ) -> Option<Vec<MarketCategory>> {
+        cache
+            .as_ref()
+            .filter(|(_, fetched_at)| fetched_at.elapsed() < CATEGORY_CACHE_TTL)
+            .map(|(categories, _)| categories.clone())
+    }
+
     /// Search markets by text query
-    #[instrument(skip(self))]
+    #[instrument(skip(self), fields(status_code = field::Empty, elapsed_ms = field::Empty))]
     pub async fn search_markets(&self, query: &str) -> Result<Vec<Market>, ApiError> {
         // Use the text_query parameter for search
         let url = format!(
@@ -154,14 +453,247 @@ impl GammaClient {
             urlencoding::encode(query)
         );
 
-        debug!("Searching markets: {}", url);
+        debug!(%url, "Searching markets");
+
+        let response = self.send_get(&url).await?;
+        let text = Self::response_text(&url, response).await?;
 
-        let response = self.client.get(&url).send().await?;
-        let raw_markets: Vec<RawMarket> = response.json().await?;
+        let raw_markets = parse_market_list(&url, &text)?;
         let markets: Vec<Market> = raw_markets.into_iter().map(Market::from).collect();
 
         Ok(markets)
     }
+
+    /// Send a GET request, logging the URL if the request itself fails
+    /// AIDEV-NOTE: Records status_code/elapsed_ms on the calling method's #[instrument] span
+    async fn send_get(&self, url: &str) -> Result<Response, ApiError> {
+        let started = Instant::now();
+        let response = self.client.get(url).send().await.map_err(|e| {
+            error!(%url, error = %e, "Gamma request failed");
+            ApiError::Http(e)
+        })?;
+
+        let span = Span::current();
+        span.record("status_code", response.status().as_u16());
+        span.record("elapsed_ms", started.elapsed().as_millis() as u64);
+
+        Ok(response)
+    }
+
+    /// Read the response body, turning non-2xx statuses into `ApiError::Api`
+    /// AIDEV-NOTE: Gamma 5xx responses carry a JSON error body - keep it in the error message
+    async fn response_text(url: &str, response: Response) -> Result<String, ApiError> {
+        let status = response.status();
+
+        if !status.is_success() {
+            let body = response.text().await.unwrap_or_default();
+            let preview = body_preview(&body);
+            error!("Gamma API {} error for {}: {}", status, url, preview);
+            return Err(ApiError::Api(format!("Gamma API {} error: {}", status, preview)));
+        }
+
+        response.text().await.map_err(|e| {
+            error!("Failed to read Gamma response body from {}: {}", url, e);
+            ApiError::Http(e)
+        })
+    }
+}
+
+/// `{ "data": [...] }` envelope Gamma sometimes wraps list responses in
+#[derive(Deserialize)]
+struct WrappedMarkets {
+    data: Vec<RawMarket>,
+}
+
+/// Parse a `/markets` list body: a bare array, else the `data` envelope, else an error
+/// AIDEV-NOTE: Gamma has returned all three shapes with a 200 - an error object
+/// (`error`/`message`) becomes ApiError::Api so its text reaches the UI
+fn parse_market_list(url: &str, text: &str) -> Result<Vec<RawMarket>, ApiError> {
+    let array_err = match serde_json::from_str::<Vec<RawMarket>>(text) {
+        Ok(markets) => return Ok(markets),
+        Err(e) => e,
+    };
+
+    if let Ok(wrapped) = serde_json::from_str::<WrappedMarkets>(text) {
+        debug!(%url, "Markets response wrapped in data envelope");
+        return Ok(wrapped.data);
+    }
+
+    error!("Failed to parse markets from {}: {}", url, array_err);
+    debug!(body = %body_preview(text), "Raw response");
+
+    let value = serde_json::from_str::<serde_json::Value>(text).ok();
+    let message = value
+        .as_ref()
+        .and_then(|v| v.get("error").or_else(|| v.get("message")))
+        .and_then(|m| m.as_str());
+    match message {
+        Some(message) => Err(ApiError::Api(format!("Gamma API error: {}", message))),
+        None => Err(ApiError::Json(array_err)),
+    }
+}
+
+/// Pick the market matching `condition_id` (case-insensitive hex) from a Gamma response
+fn find_by_condition_id(raw_markets: Vec<RawMarket>, condition_id: &str) -> Result<Market, ApiError> {
+    raw_markets
+        .into_iter()
+        .find(|m| m.condition_id.eq_ignore_ascii_case(condition_id))
+        .map(Market::from)
+        .ok_or_else(|| ApiError::MarketNotFound(condition_id.to_string()))
+}
+
+/// Oracle that resolves a market (Gamma `resolutionSource`)
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(from = "String")]
+pub enum ResolutionSource {
+    Uma,
+    Chainlink,
+    Manual,
+    Custom(String),
+}
+
+impl ResolutionSource {
+    /// Value sent as the `resolutionSource` query parameter
+    pub fn as_str(&self) -> &str {
+        match self {
+            ResolutionSource::Uma => "uma",
+            ResolutionSource::Chainlink => "chainlink",
+            ResolutionSource::Manual => "manual",
+            ResolutionSource::Custom(name) => name,
+        }
+    }
+}
+
+impl From<&str> for ResolutionSource {
+    fn from(s: &str) -> Self {
+        match s.trim().to_lowercase().as_str() {
+            "uma" => ResolutionSource::Uma,
+            "chainlink" => ResolutionSource::Chainlink,
+            "manual" => ResolutionSource::Manual,
+            _ => ResolutionSource::Custom(s.trim().to_string()),
+        }
+    }
+}
+
+impl From<String> for ResolutionSource {
+    fn from(s: String) -> Self {
+        ResolutionSource::from(s.as_str())
+    }
+}
+
+/// Query options for `GammaClient::get_markets_filtered`
+/// AIDEV-NOTE: Defaults to active, non-closed, non-archived markets, sorted by volume.
+/// include_archived switches to closed=true (archived markets are always closed).
+/// Deserializable so Tauri commands can take a filter straight from the frontend.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(default)]
+pub struct MarketFilter {
+    /// Substring match on the market slug
+    pub query: Option<String>,
+    /// Page size (defaults to 50)
+    pub limit: Option<u32>,
+    pub offset: Option<u32>,
+    pub resolution_source: Option<ResolutionSource>,
+    /// Query resolved/archived markets (sends closed=true, no archived filter)
+    pub include_archived: bool,
+    /// Include closed-but-not-archived markets (drops closed=false)
+    pub include_closed: bool,
+    /// Only return markets currently accepting orders (applied client-side)
+    pub accepting_orders_only: bool,
+    /// Only markets ending at or after this time
+    pub end_date_min: Option<DateTime<Utc>>,
+    /// Only markets ending at or before this time
+    pub end_date_max: Option<DateTime<Utc>>,
+}
+
+impl MarketFilter {
+    pub fn include_archived(mut self, include: bool) -> Self {
+        self.include_archived = include;
+        self
+    }
+
+    pub fn include_closed(mut self, include: bool) -> Self {
+        self.include_closed = include;
+        self
+    }
+
+    pub fn accepting_orders_only(mut self, only: bool) -> Self {
+        self.accepting_orders_only = only;
+        self
+    }
+
+    /// Markets ending between `now` and `now + hours`
+    pub fn resolving_within(self, now: DateTime<Utc>, hours: u32) -> Self {
+        self.ending_between(now, now + chrono::Duration::hours(i64::from(hours)))
+    }
+
+    pub fn ending_between(mut self, min: DateTime<Utc>, max: DateTime<Utc>) -> Self {
+        self.end_date_min = Some(min);
+        self.end_date_max = Some(max);
+        self
+    }
+
+    /// Client-side checks for options Gamma can't filter on
+    /// AIDEV-NOTE: Post-filtering means a page can hold fewer than `limit` markets.
+    /// accepting_orders_only is `Market::is_tradeable`, like `predicates::accepting_orders`
+    pub fn matches(&self, market: &Market) -> bool {
+        !self.accepting_orders_only || market.is_tradeable()
+    }
+
+    pub fn with_resolution_source(mut self, source: ResolutionSource) -> Self {
+        self.resolution_source = Some(source);
+        self
+    }
+
+    /// Build the `/markets` query string (without the leading `?`)
+    pub fn to_query_string(&self) -> String {
+        let mut params = vec!["active=true".to_string()];
+
+        if self.include_archived {
+            params.push("closed=true".to_string());
+        } else {
+            if !self.include_closed {
+                params.push("closed=false".to_string());
+            }
+            params.push("archived=false".to_string());
+        }
+
+        if let Some(q) = self.query.as_deref().filter(|q| !q.is_empty()) {
+            params.push(format!("slug_contains={}", urlencoding::encode(q)));
+        }
+
+        params.push(format!("limit={}", self.limit.unwrap_or(50)));
+
+        if let Some(o) = self.offset {
+            params.push(format!("offset={}", o));
+        }
+
+        if let Some(source) = &self.resolution_source {
+            params.push(format!("resolutionSource={}", urlencoding::encode(source.as_str())));
+        }
+
+        // AIDEV-NOTE: Gamma takes ISO-8601 here; whole seconds in UTC ("Z") keep it unambiguous
+        for (key, date) in [("end_date_min", self.end_date_min), ("end_date_max", self.end_date_max)] {
+            if let Some(date) = date {
+                let iso = date.to_rfc3339_opts(SecondsFormat::Secs, true);
+                params.push(format!("{}={}", key, urlencoding::encode(&iso)));
+            }
+        }
+
+        // Sort by volume descending (API uses camelCase)
+        params.push("order=volumeNum".to_string());
+        params.push("ascending=false".to_string());
+
+        params.join("&")
+    }
+}
+
+/// First 500 characters of a response body (char-safe) for logs and errors
+pub(crate) fn body_preview(body: &str) -> &str {
+    match body.char_indices().nth(500) {
+        Some((idx, _)) => &body[..idx],
+        None => body,
+    }
 }
 
 impl Default for GammaClient {
@@ -174,6 +706,75 @@ impl Default for GammaClient {
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_body_preview_truncates_on_char_boundary() {
+        assert_eq!(body_preview("short"), "short");
+
+        let long = "é".repeat(600);
+        let preview = body_preview(&long);
+        assert_eq!(preview.chars().count(), 500);
+    }
+
+    #[test]
+    fn test_find_by_condition_id_extracts_token_ids() {
+        let json = r#"[{
+            "id": "516710",
+            "conditionId": "0xABC123",
+            "question": "Mocked market?",
+            "outcomes": "[\"Yes\",\"No\"]",
+            "outcomePrices": "[\"0.4\",\"0.6\"]",
+            "clobTokenIds": "[\"111\",\"222\"]"
+        }]"#;
+        let raw_markets: Vec<RawMarket> = serde_json::from_str(json).unwrap();
+
+        let market = find_by_condition_id(raw_markets, "0xabc123").unwrap();
+        let token_ids: Vec<String> = market.tokens.into_iter().map(|t| t.token_id).collect();
+
+        assert_eq!(market.id, "516710");
+        assert_eq!(token_ids, vec!["111", "222"]);
+    }
+
+    #[test]
+    fn test_gamma_id_cache_is_case_insensitive_and_shared() {
+        let client = GammaClient::new();
+        let clone = client.clone();
+        assert!(client.cached_gamma_id("0xABC").is_none());
+
+        client.cache_gamma_id("0xABC", "516710");
+
+        assert_eq!(client.cached_gamma_id("0xabc").as_deref(), Some("516710"));
+        assert_eq!(clone.cached_gamma_id("0xAbC").as_deref(), Some("516710"));
+    }
+
+    const MARKET_JSON: &str = r#"{"id": "1", "conditionId": "0xabc", "question": "Q?"}"#;
+
+    #[test]
+    fn test_parse_market_list_array_and_wrapped() {
+        let array = format!("[{}]", MARKET_JSON);
+        let markets = parse_market_list("/markets", &array).unwrap();
+        assert_eq!(markets.len(), 1);
+        assert_eq!(markets[0].condition_id, "0xabc");
+
+        let wrapped = format!(r#"{{"data": [{}, {}], "next_cursor": "LTE="}}"#, MARKET_JSON, MARKET_JSON);
+        assert_eq!(parse_market_list("/markets", &wrapped).unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_parse_market_list_error_body() {
+        let err = parse_market_list("/markets", r#"{"error": "rate limited"}"#).unwrap_err();
+        assert!(matches!(&err, ApiError::Api(msg) if msg.contains("rate limited")), "{:?}", err);
+
+        let err = parse_market_list("/markets", r#"{"unexpected": true}"#).unwrap_err();
+        assert!(matches!(err, ApiError::Json(_)), "{:?}", err);
+        assert!(parse_market_list("/markets", "<html>").is_err());
+    }
+
+    #[test]
+    fn test_find_by_condition_id_not_found() {
+        let result = find_by_condition_id(Vec::new(), "0xmissing");
+        assert!(matches!(result, Err(ApiError::MarketNotFound(id)) if id == "0xmissing"));
+    }
+
     #[tokio::test]
     #[ignore = "hits real API"]
     async fn test_get_markets() {
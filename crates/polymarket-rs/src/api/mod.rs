@@ -1,11 +1,18 @@
 // AIDEV-NOTE: API module - REST clients for Polymarket
 
+mod balance;
 mod clob;
 mod gamma;
+mod order_watcher;
+mod resolution_watcher;
 pub mod order;
 
 #[cfg(test)]
 mod tests;
 
-pub use clob::ClobClient;
-pub use gamma::GammaClient;
+pub use balance::BalanceWatcher;
+pub use clob::{price_history_windows, recommended_fidelity, ClobClient, ClobClientConfig, MAX_BATCH_ORDERS};
+pub use gamma::{GammaClient, MarketFilter, ResolutionSource};
+pub(crate) use gamma::body_preview;
+pub use order_watcher::OrderWatcher;
+pub use resolution_watcher::ResolutionWatcher;
@@ -1,8 +1,12 @@
 // AIDEV-NOTE: Order structures for Polymarket CTF Exchange trading
 // These types are used for EIP-712 order signing and CLOB API requests
 
+use std::str::FromStr;
+
 use serde::{Deserialize, Serialize};
 
+use crate::error::ApiError;
+
 /// Side of the order (matches Polymarket enum: Buy=0, Sell=1)
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
 #[serde(rename_all = "UPPERCASE")]
@@ -18,14 +22,51 @@ impl OrderSide {
             OrderSide::Sell => 1,
         }
     }
+
+    /// Stable string form (same as the serde encoding)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderSide::Buy => "BUY",
+            OrderSide::Sell => "SELL",
+        }
+    }
+}
+
+impl FromStr for OrderSide {
+    type Err = ApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "BUY" => Ok(OrderSide::Buy),
+            "SELL" => Ok(OrderSide::Sell),
+            _ => Err(ApiError::Api(format!("Invalid order side: {}", s))),
+        }
+    }
+}
+
+impl TryFrom<u8> for OrderSide {
+    type Error = ApiError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        match value {
+            0 => Ok(OrderSide::Buy),
+            1 => Ok(OrderSide::Sell),
+            _ => Err(ApiError::Api(format!("Invalid order side: {}", value))),
+        }
+    }
 }
 
 /// Signature type for orders (matches Polymarket enum)
-#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq)]
+/// AIDEV-NOTE: This is the account type - it must agree between order signing and the
+/// balance-allowance query. Default is Proxy, the type orders were always signed with.
+/// Serializes as its numeric value (0/1/2), which is what the CLOB order payload expects.
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq)]
+#[serde(into = "u8", try_from = "u8")]
 pub enum SignatureType {
     /// EIP712 signature signed by an EOA
     Eoa = 0,
     /// EIP712 signature signed by Polymarket proxy wallet
+    #[default]
     Proxy = 1,
     /// EIP712 signature signed by Gnosis Safe
     GnosisSafe = 2,
@@ -39,6 +80,63 @@ impl SignatureType {
             SignatureType::GnosisSafe => 2,
         }
     }
+
+    /// Stable string form for storage and logs
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SignatureType::Eoa => "EOA",
+            SignatureType::Proxy => "PROXY",
+            SignatureType::GnosisSafe => "GNOSIS_SAFE",
+        }
+    }
+
+    pub fn from_u8(value: u8) -> Option<Self> {
+        match value {
+            0 => Some(SignatureType::Eoa),
+            1 => Some(SignatureType::Proxy),
+            2 => Some(SignatureType::GnosisSafe),
+            _ => None,
+        }
+    }
+
+    /// Address that holds the funds and goes in the order's `maker` field
+    /// EOAs trade from the signer itself; proxy and Safe accounts from the funder wallet
+    pub fn maker_address<'a>(&self, signer: &'a str, funder: Option<&'a str>) -> &'a str {
+        match self {
+            SignatureType::Eoa => signer,
+            SignatureType::Proxy | SignatureType::GnosisSafe => funder.unwrap_or(signer),
+        }
+    }
+}
+
+/// AIDEV-NOTE: For loading a persisted account type - out-of-range values are an error,
+/// use `from_u8` where falling back to a default is fine
+impl TryFrom<u8> for SignatureType {
+    type Error = ApiError;
+
+    fn try_from(value: u8) -> Result<Self, Self::Error> {
+        SignatureType::from_u8(value)
+            .ok_or_else(|| ApiError::Api(format!("Invalid signature type: {}", value)))
+    }
+}
+
+impl From<SignatureType> for u8 {
+    fn from(signature_type: SignatureType) -> Self {
+        signature_type.as_u8()
+    }
+}
+
+impl FromStr for SignatureType {
+    type Err = ApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "EOA" => Ok(SignatureType::Eoa),
+            "PROXY" => Ok(SignatureType::Proxy),
+            "GNOSIS_SAFE" => Ok(SignatureType::GnosisSafe),
+            _ => Err(ApiError::Api(format!("Invalid signature type: {}", s))),
+        }
+    }
 }
 
 /// Order type for time-in-force
@@ -53,12 +151,32 @@ pub enum OrderType {
     Gtd,
 }
 
+impl OrderType {
+    /// Stable string form (same as the serde encoding)
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OrderType::Gtc => "GTC",
+            OrderType::Fok => "FOK",
+            OrderType::Gtd => "GTD",
+        }
+    }
+}
+
 impl std::fmt::Display for OrderType {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            OrderType::Gtc => write!(f, "GTC"),
-            OrderType::Fok => write!(f, "FOK"),
-            OrderType::Gtd => write!(f, "GTD"),
+        f.write_str(self.as_str())
+    }
+}
+
+impl FromStr for OrderType {
+    type Err = ApiError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_uppercase().as_str() {
+            "GTC" => Ok(OrderType::Gtc),
+            "FOK" => Ok(OrderType::Fok),
+            "GTD" => Ok(OrderType::Gtd),
+            _ => Err(ApiError::Api(format!("Invalid order type: {}", s))),
         }
     }
 }
@@ -94,6 +212,13 @@ pub struct UnsignedOrder {
     pub signature_type: SignatureType,
 }
 
+impl UnsignedOrder {
+    /// EIP-712 struct hash of this order (the primitive behind `SignedOrder::compute_hash`)
+    pub fn compute_struct_hash(&self) -> Result<[u8; 32], ApiError> {
+        crate::auth::order_struct_hash(self)
+    }
+}
+
 /// Signed order with EIP-712 signature
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -105,6 +230,15 @@ pub struct SignedOrder {
     pub signature: String,
 }
 
+impl SignedOrder {
+    /// 0x-prefixed EIP-712 digest that was signed - the CLOB uses it as the order ID
+    /// AIDEV-NOTE: Lets the UI show an optimistic order ID before POST /order returns
+    pub fn compute_hash(&self) -> Result<String, ApiError> {
+        let digest = crate::auth::order_digest(&self.order)?;
+        Ok(format!("0x{}", hex::encode(digest)))
+    }
+}
+
 /// Request payload for POST /order
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -155,10 +289,34 @@ pub struct OrderParams {
     /// Seconds until expiration (None = 30 days default)
     #[serde(default)]
     pub expiration_secs: Option<u64>,
+    /// FOK only: max adverse deviation (percent) of the estimated fill price
+    /// from `price` before the order is refused (None = no price-impact check)
+    #[serde(default)]
+    pub max_slippage_pct: Option<f64>,
+}
+
+impl OrderParams {
+    /// Estimated fee in USDC: `price * size * rate_bps / 10000`
+    /// AIDEV-NOTE: FOK orders always take liquidity so they pay the taker rate;
+    /// GTC/GTD are assumed to rest on the book and pay the maker rate
+    pub fn estimated_fee_usdc(&self, rates: &FeeRates) -> f64 {
+        let rate_bps = match self.order_type {
+            OrderType::Fok => rates.taker_fee_rate_bps,
+            OrderType::Gtc | OrderType::Gtd => rates.maker_fee_rate_bps,
+        };
+        self.price * self.size * f64::from(rate_bps) / 10_000.0
+    }
+}
+
+/// Maker/taker fee rates in basis points (GET /fee-rate-bps)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FeeRates {
+    pub maker_fee_rate_bps: u32,
+    pub taker_fee_rate_bps: u32,
 }
 
 /// Response for cancel operations
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct CancelResponse {
     /// Successfully canceled order IDs
     #[serde(default)]
@@ -168,6 +326,99 @@ pub struct CancelResponse {
     pub not_canceled: std::collections::HashMap<String, String>,
 }
 
+/// Why the CLOB refused to cancel an order (classified from `not_canceled` reasons)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CancelFailure {
+    /// Order was matched/filled before the cancel landed
+    AlreadyFilled,
+    /// Unknown order ID, or already cancelled
+    NotFound,
+    /// Order belongs to another key/owner
+    Unauthorized,
+    /// Anything else - raw reason kept for logs
+    Other(String),
+}
+
+impl CancelFailure {
+    /// Classify a raw `not_canceled` reason string
+    /// AIDEV-NOTE: Matched on substrings; CLOB reasons look like
+    /// "matched orders can't be canceled" / "order can't be found - already canceled or matched"
+    pub fn from_reason(reason: &str) -> Self {
+        let lower = reason.to_lowercase();
+        if lower.contains("can't be found") || lower.contains("not found") || lower.contains("already canceled") {
+            CancelFailure::NotFound
+        } else if lower.contains("matched") || lower.contains("filled") {
+            CancelFailure::AlreadyFilled
+        } else if lower.contains("unauthorized") || lower.contains("not authorized") || lower.contains("owner") {
+            CancelFailure::Unauthorized
+        } else {
+            CancelFailure::Other(reason.to_string())
+        }
+    }
+}
+
+impl CancelResponse {
+    /// Every requested order was cancelled
+    pub fn fully_succeeded(&self) -> bool {
+        self.not_canceled.is_empty()
+    }
+
+    /// Classified reason for each order that was not cancelled
+    pub fn failures(&self) -> std::collections::HashMap<String, CancelFailure> {
+        self.not_canceled
+            .iter()
+            .map(|(order_id, reason)| (order_id.clone(), CancelFailure::from_reason(reason)))
+            .collect()
+    }
+}
+
+/// Which leg of a cancel/replace goes first
+/// AIDEV-NOTE: PlaceFirst risks briefly holding both orders (double exposure);
+/// CancelFirst risks briefly holding neither (lost queue position, no fill window)
+#[derive(Debug, Clone, Copy, Default, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(rename_all = "camelCase")]
+pub enum ReplacePolicy {
+    /// Place the new order, then cancel the old one (default)
+    #[default]
+    PlaceFirst,
+    /// Cancel the old order, then place the new one
+    CancelFirst,
+}
+
+/// Outcome of both legs of `ClobClient::replace_order`
+/// A leg that was skipped (because the first leg failed) has neither result nor error.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ReplaceResult {
+    pub policy: ReplacePolicy,
+    /// Response from POST /order, if it returned one
+    pub placed: Option<PlaceOrderResponse>,
+    /// Transport/API error or rejection message from placing the new order
+    pub place_error: Option<String>,
+    /// Response from DELETE /order, if it returned one
+    pub cancelled: Option<CancelResponse>,
+    /// Transport/API error or not-canceled reason for the old order
+    pub cancel_error: Option<String>,
+}
+
+impl ReplaceResult {
+    /// The new order was accepted
+    pub fn new_order_placed(&self) -> bool {
+        self.placed.as_ref().is_some_and(|p| p.success)
+    }
+
+    /// The old order is confirmed cancelled
+    pub fn old_order_cancelled(&self) -> bool {
+        self.cancelled.is_some() && self.cancel_error.is_none()
+    }
+
+    /// Both legs succeeded
+    pub fn is_complete(&self) -> bool {
+        self.new_order_placed() && self.old_order_cancelled()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -178,12 +429,160 @@ mod tests {
         assert_eq!(OrderSide::Sell.as_u8(), 1);
     }
 
+    #[test]
+    fn test_order_side_try_from_u8() {
+        for side in [OrderSide::Buy, OrderSide::Sell] {
+            assert_eq!(OrderSide::try_from(side.as_u8()).unwrap(), side);
+        }
+        let err = OrderSide::try_from(2).unwrap_err();
+        assert!(err.to_string().contains("Invalid order side: 2"));
+    }
+
+    #[test]
+    fn test_signature_type_roundtrip() {
+        for sig_type in [SignatureType::Eoa, SignatureType::Proxy, SignatureType::GnosisSafe] {
+            assert_eq!(SignatureType::from_u8(sig_type.as_u8()), Some(sig_type));
+        }
+        assert_eq!(SignatureType::from_u8(3), None);
+        assert_eq!(SignatureType::default(), SignatureType::Proxy);
+    }
+
+    #[test]
+    fn test_signature_type_try_from_u8() {
+        for sig_type in [SignatureType::Eoa, SignatureType::Proxy, SignatureType::GnosisSafe] {
+            assert_eq!(SignatureType::try_from(sig_type.as_u8()).unwrap(), sig_type);
+        }
+        for invalid in [3, u8::MAX] {
+            let err = SignatureType::try_from(invalid).unwrap_err();
+            assert!(err.to_string().contains("Invalid signature type"));
+        }
+    }
+
+    /// AIDEV-NOTE: Pins the persisted encodings - changing these breaks stored rows
+    #[test]
+    fn test_stable_encodings() {
+        let sides = [(OrderSide::Buy, "BUY"), (OrderSide::Sell, "SELL")];
+        for (side, text) in sides {
+            assert_eq!(side.as_str(), text);
+            assert_eq!(serde_json::to_string(&side).unwrap(), format!("\"{}\"", text));
+            assert_eq!(text.parse::<OrderSide>().unwrap(), side);
+        }
+
+        let types = [(OrderType::Gtc, "GTC"), (OrderType::Fok, "FOK"), (OrderType::Gtd, "GTD")];
+        for (order_type, text) in types {
+            assert_eq!(order_type.as_str(), text);
+            assert_eq!(serde_json::to_string(&order_type).unwrap(), format!("\"{}\"", text));
+            assert_eq!(text.parse::<OrderType>().unwrap(), order_type);
+        }
+
+        let sig_types = [
+            (SignatureType::Eoa, "EOA", "0"),
+            (SignatureType::Proxy, "PROXY", "1"),
+            (SignatureType::GnosisSafe, "GNOSIS_SAFE", "2"),
+        ];
+        for (sig_type, text, json) in sig_types {
+            assert_eq!(sig_type.as_str(), text);
+            assert_eq!(text.parse::<SignatureType>().unwrap(), sig_type);
+            assert_eq!(serde_json::to_string(&sig_type).unwrap(), json);
+            assert_eq!(serde_json::from_str::<SignatureType>(json).unwrap(), sig_type);
+        }
+
+        assert!("sideways".parse::<OrderSide>().is_err());
+        assert!("IOC".parse::<OrderType>().is_err());
+        assert!("Safe".parse::<SignatureType>().is_err());
+        assert!(serde_json::from_str::<SignatureType>("3").is_err());
+        assert!(serde_json::from_str::<SignatureType>("\"Eoa\"").is_err());
+    }
+
+    #[test]
+    fn test_signature_type_maker_address() {
+        let funder = Some("0xfunder");
+        assert_eq!(SignatureType::Eoa.maker_address("0xsigner", funder), "0xsigner");
+        assert_eq!(SignatureType::Proxy.maker_address("0xsigner", funder), "0xfunder");
+        assert_eq!(SignatureType::GnosisSafe.maker_address("0xsigner", funder), "0xfunder");
+        // No funder configured yet - fall back to the signer
+        assert_eq!(SignatureType::GnosisSafe.maker_address("0xsigner", None), "0xsigner");
+    }
+
     #[test]
     fn test_order_type_display() {
         assert_eq!(format!("{}", OrderType::Gtc), "GTC");
         assert_eq!(format!("{}", OrderType::Fok), "FOK");
     }
 
+    #[test]
+    fn test_order_type_roundtrip() {
+        for (order_type, wire) in [
+            (OrderType::Gtc, "\"GTC\""),
+            (OrderType::Fok, "\"FOK\""),
+            (OrderType::Gtd, "\"GTD\""),
+        ] {
+            assert_eq!(serde_json::to_string(&order_type).unwrap(), wire);
+            assert_eq!(serde_json::from_str::<OrderType>(wire).unwrap(), order_type);
+        }
+
+        // Order status objects from the CLOB carry the same field
+        let status: serde_json::Value = serde_json::json!({ "id": "0xabc", "orderType": "GTD" });
+        let order_type: OrderType = serde_json::from_value(status["orderType"].clone()).unwrap();
+        assert_eq!(order_type, OrderType::Gtd);
+    }
+
+    #[test]
+    fn test_cancel_response_classifies_failures() {
+        let response: CancelResponse = serde_json::from_str(
+            r#"{
+                "canceled": ["0x1"],
+                "not_canceled": {
+                    "0x2": "matched orders can't be canceled",
+                    "0x3": "order can't be found - already canceled or matched",
+                    "0x4": "Unauthorized/Invalid api key",
+                    "0x5": "market is in cancel-only mode"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert!(!response.fully_succeeded());
+        let failures = response.failures();
+        assert_eq!(failures["0x2"], CancelFailure::AlreadyFilled);
+        assert_eq!(failures["0x3"], CancelFailure::NotFound);
+        assert_eq!(failures["0x4"], CancelFailure::Unauthorized);
+        assert_eq!(failures["0x5"], CancelFailure::Other("market is in cancel-only mode".to_string()));
+    }
+
+    #[test]
+    fn test_cancel_response_fully_succeeded() {
+        let response: CancelResponse =
+            serde_json::from_str(r#"{"canceled": ["0x1", "0x2"], "not_canceled": {}}"#).unwrap();
+        assert!(response.fully_succeeded());
+        assert!(response.failures().is_empty());
+    }
+
+    fn params(order_type: OrderType, price: f64, size: f64) -> OrderParams {
+        OrderParams {
+            token_id: "12345".to_string(),
+            side: OrderSide::Buy,
+            price,
+            size,
+            order_type,
+            expiration_secs: None,
+            max_slippage_pct: None,
+        }
+    }
+
+    #[test]
+    fn test_estimated_fee_usdc() {
+        let rates = FeeRates { maker_fee_rate_bps: 0, taker_fee_rate_bps: 200 };
+
+        // 0.5 * 100 * 200 / 10000 = 1.0 USDC
+        assert!((params(OrderType::Fok, 0.5, 100.0).estimated_fee_usdc(&rates) - 1.0).abs() < 1e-9);
+        assert_eq!(params(OrderType::Gtc, 0.5, 100.0).estimated_fee_usdc(&rates), 0.0);
+
+        let rates = FeeRates { maker_fee_rate_bps: 25, taker_fee_rate_bps: 100 };
+        // 0.8 * 250 * 25 / 10000 = 0.5 USDC
+        assert!((params(OrderType::Gtd, 0.8, 250.0).estimated_fee_usdc(&rates) - 0.5).abs() < 1e-9);
+    }
+
     #[test]
     fn test_order_params_serialization() {
         let params = OrderParams {
@@ -193,6 +592,7 @@ mod tests {
             size: 100.0,
             order_type: OrderType::Gtc,
             expiration_secs: None,
+            max_slippage_pct: None,
         };
 
         let json = serde_json::to_string(&params).unwrap();
@@ -0,0 +1,181 @@
+// AIDEV-NOTE: Background open-order poller - diffs successive get_orders snapshots into
+// fill/cancel events. Stopgap until the authenticated user-channel WebSocket exists.
+
+use std::collections::HashMap;
+use std::sync::Arc;
+use std::time::Duration;
+use parking_lot::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{debug, warn};
+
+use crate::error::ApiError;
+use crate::types::{Order, OrderEvent, OrderEventKind};
+use crate::ws::EventEmitter;
+use super::ClobClient;
+
+/// Size comparisons tolerate float noise from the 6-decimal string amounts
+const SIZE_EPSILON: f64 = 1e-9;
+
+/// Polls `ClobClient::get_orders` on an interval and emits an `OrderEvent` for each
+/// order that filled, partially filled or was cancelled since the previous poll
+#[derive(Default)]
+pub struct OrderWatcher {
+    handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl OrderWatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start polling every `interval`. The first successful poll only records a baseline.
+    /// Panics if `interval` is zero.
+    /// AIDEV-NOTE: Replaces any running poller. The client is captured by value, so
+    /// restart after login/logout to pick up the new credentials.
+    pub fn start<E: EventEmitter>(&self, client: ClobClient, emitter: Arc<E>, interval: Duration) {
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            let mut previous = None;
+            loop {
+                ticker.tick().await;
+                // Errors keep the previous snapshot, so nothing is missed once polls recover
+                if let Err(e) = poll_orders(&client, emitter.as_ref(), &mut previous).await {
+                    warn!(error = %e, "Order poll failed");
+                }
+            }
+        });
+
+        if let Some(old) = self.handle.lock().replace(handle) {
+            old.abort();
+        }
+    }
+
+    /// Stop polling, if running
+    pub fn stop(&self) {
+        if let Some(handle) = self.handle.lock().take() {
+            handle.abort();
+        }
+    }
+
+    /// Whether the poller is running
+    pub fn is_running(&self) -> bool {
+        self.handle.lock().as_ref().is_some_and(|h| !h.is_finished())
+    }
+}
+
+impl Drop for OrderWatcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Fetch open orders once, emit events for changes since `previous` (then replace it)
+/// Returns the number of events emitted. With no previous snapshot nothing is emitted.
+pub(crate) async fn poll_orders<E: EventEmitter + ?Sized>(
+    client: &ClobClient,
+    emitter: &E,
+    previous: &mut Option<HashMap<String, Order>>,
+) -> Result<usize, ApiError> {
+    let current = client.get_orders(None, None).await?;
+
+    let mut events = Vec::new();
+    if let Some(previous) = previous.as_ref() {
+        let (changed, vanished) = diff_orders(previous, &current);
+        events = changed;
+        for order in vanished {
+            if let Some(event) = resolve_vanished(client, order).await {
+                events.push(event);
+            }
+        }
+    }
+
+    let now = chrono::Utc::now().timestamp();
+    for event in &events {
+        debug!(order_id = %event.order.id, kind = ?event.kind, newly_matched = event.newly_matched, "Order update");
+        emitter.emit_order_update(event);
+        if let Some(fill) = event.fill(now) {
+            emitter.emit_fill(&fill);
+        }
+    }
+
+    *previous = Some(current.into_iter().map(|order| (order.id.clone(), order)).collect());
+    Ok(events.len())
+}
+
+/// Events for orders still listed in `current`, plus orders from `previous` that vanished
+/// AIDEV-NOTE: /data/orders only lists open orders, so a vanished order either filled
+/// completely or was cancelled - the snapshot alone can't tell which
+pub(crate) fn diff_orders(previous: &HashMap<String, Order>, current: &[Order]) -> (Vec<OrderEvent>, Vec<Order>) {
+    let mut events = Vec::new();
+
+    for order in current {
+        let matched = size(&order.size_matched);
+        let (prev_matched, was_cancelled) = match previous.get(&order.id) {
+            Some(prev) => (size(&prev.size_matched), is_cancelled(&prev.status)),
+            None => (0.0, false),
+        };
+
+        let newly_matched = matched - prev_matched;
+        let kind = if newly_matched > SIZE_EPSILON {
+            if is_filled(order) {
+                OrderEventKind::Filled
+            } else {
+                OrderEventKind::PartiallyFilled
+            }
+        } else if is_cancelled(&order.status) && !was_cancelled {
+            OrderEventKind::Cancelled
+        } else {
+            continue;
+        };
+
+        events.push(OrderEvent { kind, order: order.clone(), newly_matched: newly_matched.max(0.0) });
+    }
+
+    let vanished = previous
+        .values()
+        .filter(|prev| !current.iter().any(|order| order.id == prev.id))
+        .cloned()
+        .collect();
+
+    (events, vanished)
+}
+
+/// Decide whether a vanished order filled or was cancelled from its fills
+/// None (and a warning) if the fills can't be fetched - better no event than a wrong one
+async fn resolve_vanished(client: &ClobClient, mut order: Order) -> Option<OrderEvent> {
+    let fills = match client.get_trades_for_order(&order.id).await {
+        Ok(fills) => fills,
+        Err(e) => {
+            warn!(order_id = %order.id, error = %e, "Could not resolve closed order");
+            return None;
+        }
+    };
+
+    let prev_matched = size(&order.size_matched);
+    let total_matched = fills.iter().map(|fill| fill.size).sum::<f64>().max(prev_matched);
+    order.size_matched = total_matched.to_string();
+
+    let kind = if total_matched + SIZE_EPSILON >= size(&order.original_size) {
+        order.status = "MATCHED".to_string();
+        OrderEventKind::Filled
+    } else {
+        order.status = "CANCELED".to_string();
+        OrderEventKind::Cancelled
+    };
+
+    Some(OrderEvent { kind, newly_matched: total_matched - prev_matched, order })
+}
+
+fn size(value: &str) -> f64 {
+    value.parse().unwrap_or(0.0)
+}
+
+fn is_filled(order: &Order) -> bool {
+    order.status.to_ascii_uppercase().contains("MATCHED")
+        || size(&order.size_matched) + SIZE_EPSILON >= size(&order.original_size)
+}
+
+fn is_cancelled(status: &str) -> bool {
+    status.to_ascii_uppercase().contains("CANCEL")
+}
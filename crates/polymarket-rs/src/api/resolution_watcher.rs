@@ -0,0 +1,112 @@
+// AIDEV-NOTE: Background market-resolution poller - the price stream just goes quiet when
+// a market resolves, so poll Gamma for the watched markets and report each settlement once
+
+use std::sync::Arc;
+use std::time::Duration;
+use parking_lot::Mutex;
+use tokio::task::JoinHandle;
+use tracing::{debug, info, warn};
+
+use crate::error::ApiError;
+use crate::ws::EventEmitter;
+use super::GammaClient;
+
+/// Max concurrent Gamma requests per poll (condition IDs are batched per request)
+const POLL_CONCURRENCY: usize = 4;
+
+/// Polls Gamma for a set of markets and emits `emit_market_resolved` once per market
+/// when it closes with a winning outcome
+#[derive(Default)]
+pub struct ResolutionWatcher {
+    handle: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl ResolutionWatcher {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Start polling `condition_ids` every `interval`. The first poll already reports
+    /// markets that resolved before watching started.
+    /// Panics if `interval` is zero.
+    /// AIDEV-NOTE: Replaces any running poller. The task ends by itself once every
+    /// watched market has resolved.
+    pub fn start<E: EventEmitter>(
+        &self,
+        client: GammaClient,
+        condition_ids: Vec<String>,
+        emitter: Arc<E>,
+        interval: Duration,
+    ) {
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            let mut pending = condition_ids;
+            while !pending.is_empty() {
+                ticker.tick().await;
+                // Errors keep every market pending, so a resolution during an outage is still reported
+                if let Err(e) = poll_resolutions(&client, emitter.as_ref(), &mut pending).await {
+                    warn!(error = %e, "Resolution poll failed");
+                }
+            }
+            debug!("All watched markets resolved");
+        });
+
+        if let Some(old) = self.handle.lock().replace(handle) {
+            old.abort();
+        }
+    }
+
+    /// Stop polling, if running
+    pub fn stop(&self) {
+        if let Some(handle) = self.handle.lock().take() {
+            handle.abort();
+        }
+    }
+
+    /// Whether the poller is running
+    pub fn is_running(&self) -> bool {
+        self.handle.lock().as_ref().is_some_and(|h| !h.is_finished())
+    }
+}
+
+impl Drop for ResolutionWatcher {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// Fetch the `pending` markets once, emit for each that resolved and drop it from `pending`
+/// Returns the number of markets that resolved in this poll.
+/// AIDEV-NOTE: Closed but not yet settled (no winning price) stays pending - the event
+/// fires once Gamma reports the winner
+pub(crate) async fn poll_resolutions<E: EventEmitter + ?Sized>(
+    client: &GammaClient,
+    emitter: &E,
+    pending: &mut Vec<String>,
+) -> Result<usize, ApiError> {
+    if pending.is_empty() {
+        return Ok(0);
+    }
+
+    let markets = client.get_markets_by_condition_ids(pending, POLL_CONCURRENCY).await?;
+
+    let mut resolved = 0;
+    for market in &markets {
+        // Winners are only set on closed markets (archived ones included)
+        let Some(winner) = market.resolved_outcome() else {
+            continue;
+        };
+        let before = pending.len();
+        pending.retain(|id| !id.eq_ignore_ascii_case(&market.condition_id));
+        if pending.len() == before {
+            continue;
+        }
+
+        info!(condition_id = %market.condition_id, outcome = %winner.outcome, "Market resolved");
+        emitter.emit_market_resolved(&market.condition_id, winner);
+        resolved += 1;
+    }
+
+    Ok(resolved)
+}
@@ -2,9 +2,169 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::api::order::{OrderParams, OrderSide, OrderType, SignatureType, UnsignedOrder};
-    use crate::api::{ClobClient, GammaClient};
-    use crate::types::{Balance, Market, Order, Position, RawMarket};
+    use crate::api::order::{
+        FeeRates, OrderParams, OrderSide, OrderType, PlaceOrderRequest, ReplacePolicy, SignatureType,
+        SignedOrder, UnsignedOrder,
+    };
+    use crate::auth::{ApiCredentials, FixedClock, HmacAuth, PolymarketSigner};
+    use crate::api::{ClobClient, GammaClient, MarketFilter, ResolutionSource};
+    use crate::api::balance::{poll_balance, BalanceWatcher};
+    use crate::api::order_watcher::{diff_orders, poll_orders};
+    use crate::api::resolution_watcher::poll_resolutions;
+    use crate::position_list::PositionSortKey;
+    use crate::types::{
+        Balance, ClobTrade, ConnectionStatus, Market, Order, OrderBookSnapshot, OrderEvent, OrderEventKind, OrderFillEvent, PnlSummary, Position,
+        PriceUpdate, RawMarket, Token,
+    };
+    use crate::ws::{EventEmitter, RtdsTrade};
+
+    /// Well-known test key (Anvil/Hardhat account 0)
+    const TEST_PRIVATE_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+    // ==================== Mock HTTP Server ====================
+
+    use std::sync::{Arc, Mutex};
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+    use tokio::net::TcpListener;
+
+    /// Canned response for requests whose "METHOD /path?query" starts with `prefix`
+    struct MockRoute {
+        prefix: String,
+        status: u16,
+        body: String,
+        /// Wait this long before responding (simulates a slow endpoint)
+        delay: std::time::Duration,
+    }
+
+    fn route(prefix: &str, status: u16, body: &str) -> MockRoute {
+        MockRoute { prefix: prefix.to_string(), status, body: body.to_string(), delay: std::time::Duration::ZERO }
+    }
+
+    fn delayed_route(prefix: &str, status: u16, body: &str, delay: std::time::Duration) -> MockRoute {
+        MockRoute { delay, ..route(prefix, status, body) }
+    }
+
+    /// Header (name, value) pairs of one request, in the order sent
+    type Headers = Vec<(String, String)>;
+
+    /// Minimal HTTP/1.1 server for exercising clients without hitting real APIs
+    /// AIDEV-NOTE: Unmatched requests get 404; every request line, header set and body is recorded in order
+    struct MockServer {
+        url: String,
+        requests: Arc<Mutex<Vec<String>>>,
+        headers: Arc<Mutex<Vec<Headers>>>,
+        bodies: Arc<Mutex<Vec<String>>>,
+    }
+
+    impl MockServer {
+        async fn start(routes: Vec<MockRoute>) -> Self {
+            let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("http://{}", listener.local_addr().unwrap());
+            let requests = Arc::new(Mutex::new(Vec::new()));
+            let headers = Arc::new(Mutex::new(Vec::new()));
+            let bodies = Arc::new(Mutex::new(Vec::new()));
+            let routes = Arc::new(routes);
+
+            let recorded = requests.clone();
+            let recorded_headers = headers.clone();
+            let recorded_bodies = bodies.clone();
+            tokio::spawn(async move {
+                while let Ok((mut socket, _)) = listener.accept().await {
+                    let routes = routes.clone();
+                    let recorded = recorded.clone();
+                    let recorded_headers = recorded_headers.clone();
+                    let recorded_bodies = recorded_bodies.clone();
+                    tokio::spawn(async move {
+                        let Some((request_line, request_headers, body)) = read_request(&mut socket).await else {
+                            return;
+                        };
+                        recorded.lock().unwrap().push(request_line.clone());
+                        recorded_headers.lock().unwrap().push(request_headers);
+                        recorded_bodies.lock().unwrap().push(body);
+
+                        let (status, body, delay) = routes
+                            .iter()
+                            .find(|r| request_line.starts_with(&r.prefix))
+                            .map(|r| (r.status, r.body.clone(), r.delay))
+                            .unwrap_or((404, r#"{"error":"not found"}"#.to_string(), std::time::Duration::ZERO));
+                        tokio::time::sleep(delay).await;
+
+                        let response = format!(
+                            "HTTP/1.1 {} Mock\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                            status,
+                            body.len(),
+                            body
+                        );
+                        let _ = socket.write_all(response.as_bytes()).await;
+                    });
+                }
+            });
+
+            Self { url, requests, headers, bodies }
+        }
+
+        fn requests(&self) -> Vec<String> {
+            self.requests.lock().unwrap().clone()
+        }
+
+        /// Value of header `name` (case-insensitive) on the `index`-th request
+        fn header(&self, index: usize, name: &str) -> Option<String> {
+            self.headers.lock().unwrap().get(index)?.iter().find_map(|(key, value)| {
+                key.eq_ignore_ascii_case(name).then(|| value.clone())
+            })
+        }
+
+        fn bodies(&self) -> Vec<String> {
+            self.bodies.lock().unwrap().clone()
+        }
+    }
+
+    /// Read headers and body (per Content-Length); returns ("METHOD /path?query", headers, body)
+    async fn read_request(socket: &mut tokio::net::TcpStream) -> Option<(String, Headers, String)> {
+        let mut buf = Vec::new();
+        let mut chunk = [0u8; 4096];
+
+        let header_end = loop {
+            let n = socket.read(&mut chunk).await.ok()?;
+            if n == 0 {
+                return None;
+            }
+            buf.extend_from_slice(&chunk[..n]);
+            if let Some(pos) = buf.windows(4).position(|w| w == b"\r\n\r\n") {
+                break pos + 4;
+            }
+        };
+
+        let head = String::from_utf8_lossy(&buf[..header_end]).to_string();
+        let content_length = head
+            .lines()
+            .find_map(|l| {
+                let (name, value) = l.split_once(':')?;
+                name.eq_ignore_ascii_case("content-length").then(|| value.trim().parse::<usize>().ok())?
+            })
+            .unwrap_or(0);
+
+        while buf.len() < header_end + content_length {
+            let n = socket.read(&mut chunk).await.ok()?;
+            if n == 0 {
+                break;
+            }
+            buf.extend_from_slice(&chunk[..n]);
+        }
+
+        let body_end = buf.len().min(header_end + content_length);
+        let body = String::from_utf8_lossy(&buf[header_end..body_end]).to_string();
+
+        let headers = head
+            .lines()
+            .skip(1)
+            .filter_map(|l| l.split_once(':'))
+            .map(|(name, value)| (name.trim().to_string(), value.trim().to_string()))
+            .collect();
+
+        let mut parts = head.lines().next()?.split_whitespace();
+        Some((format!("{} {}", parts.next()?, parts.next()?), headers, body))
+    }
 
     // ==================== Type Deserialization Tests ====================
 
@@ -124,6 +284,7 @@ mod tests {
             side: OrderSide::Buy,
             order_type: OrderType::Gtc,
             expiration_secs: Some(86400),
+            max_slippage_pct: None,
         };
 
         assert_eq!(params.token_id, "123456");
@@ -154,9 +315,9 @@ mod tests {
         let eoa = SignatureType::Eoa;
         let proxy = SignatureType::Proxy;
 
-        // SignatureType serializes as variant names
-        assert_eq!(serde_json::to_string(&eoa).unwrap(), "\"Eoa\"");
-        assert_eq!(serde_json::to_string(&proxy).unwrap(), "\"Proxy\"");
+        // SignatureType serializes as its numeric value
+        assert_eq!(serde_json::to_string(&eoa).unwrap(), "0");
+        assert_eq!(serde_json::to_string(&proxy).unwrap(), "1");
     }
 
     #[test]
@@ -208,10 +369,1553 @@ mod tests {
         };
 
         let mut client = ClobClient::new();
-        client.set_credentials(&creds);
+        client.set_credentials(&creds).unwrap();
+        assert!(client.is_authenticated());
 
-        // Client should accept credentials
-        assert!(std::mem::size_of_val(&client) > 0);
+        // Malformed credentials are refused and the client keeps the ones it had
+        let bad = ApiCredentials { api_secret: "not base64!".to_string(), ..creds.clone() };
+        assert!(client.set_credentials(&bad).is_err());
+        let mut fresh = ClobClient::new();
+        assert!(fresh.set_credentials(&bad).is_err());
+        assert!(!fresh.is_authenticated());
+        assert!(client.is_authenticated());
+    }
+
+    #[test]
+    fn test_clob_client_with_credentials_rejects_malformed_address() {
+        use crate::auth::ApiCredentials;
+
+        let creds = ApiCredentials {
+            api_key: "test_key".to_string(),
+            api_secret: "dGVzdF9zZWNyZXQ=".to_string(),
+            api_passphrase: "test_pass".to_string(),
+            address: "0x12345".to_string(),
+        };
+
+        let err = ClobClient::with_credentials(&creds).err().expect("malformed address accepted");
+        assert!(matches!(&err, crate::error::ApiError::Auth(msg) if msg.contains("address")), "{:?}", err);
+    }
+
+    // ==================== Quote Tests ====================
+
+    #[tokio::test]
+    async fn test_get_token_quotes_over_mock_server() {
+        let server = MockServer::start(vec![
+            route("GET /midpoint?token_id=111", 200, r#"{"mid": "0.55"}"#),
+            route("GET /last-trade-price?token_id=111", 200, r#"{"price": "0.56", "side": "BUY"}"#),
+            route("GET /midpoint?token_id=222", 200, r#"{"mid": 0.2}"#),
+            route("GET /last-trade-price?token_id=222", 200, r#"{"price": "0.21", "side": "SELL"}"#),
+            // Token 333 has a book but has never traded
+            route("GET /midpoint?token_id=333", 200, r#"{"mid": "0.9"}"#),
+        ])
+        .await;
+
+        let client = ClobClient::new().with_base_url(&server.url);
+        let token_ids: Vec<String> = ["111", "222", "333", "444"].iter().map(|s| s.to_string()).collect();
+
+        let quotes = client.get_token_quotes(&token_ids, 2).await;
+
+        assert_eq!(quotes.len(), 4);
+        assert_eq!(quotes["111"].midpoint, Some(0.55));
+        assert_eq!(quotes["111"].last_trade_price, Some(0.56));
+        assert_eq!(quotes["222"].midpoint, Some(0.2));
+        assert_eq!(quotes["222"].last_trade_price, Some(0.21));
+        assert_eq!(quotes["333"].midpoint, Some(0.9));
+        assert!(quotes["333"].last_trade_price.is_none());
+        assert!(quotes["444"].midpoint.is_none());
+        assert_eq!(server.requests().len(), 8);
+    }
+
+    #[tokio::test]
+    async fn test_quote_endpoints_encode_token_id() {
+        let server = MockServer::start(vec![
+            route("GET /midpoint?token_id=1%262", 200, r#"{"mid": "0.5"}"#),
+            route("GET /last-trade-price?token_id=1%262", 200, r#"{"price": "0.4"}"#),
+        ])
+        .await;
+        let client = ClobClient::new().with_base_url(&server.url);
+
+        assert_eq!(client.get_midpoint("1&2").await.unwrap(), 0.5);
+        assert_eq!(client.get_last_trade_price("1&2").await.unwrap(), 0.4);
+    }
+
+    #[tokio::test]
+    async fn test_error_body_survives_logged_send() {
+        let server = MockServer::start(vec![route(
+            "GET /prices-history",
+            400,
+            r#"{"error":"invalid filters: startTs after endTs"}"#,
+        )])
+        .await;
+        let client = ClobClient::new().with_base_url(&server.url);
+
+        let err = client
+            .get_price_history("111", None, None, Some(200), Some(100))
+            .await
+            .unwrap_err()
+            .to_string();
+
+        assert!(err.contains("400"), "missing status: {}", err);
+        assert!(err.contains("startTs after endTs"), "missing body: {}", err);
+    }
+
+    #[tokio::test]
+    async fn test_get_orderbook() {
+        let body = r#"{
+            "market": "0xabc",
+            "asset_id": "111",
+            "timestamp": "1734000000000",
+            "hash": "0xhash",
+            "bids": [{"price": "0.48", "size": "30"}],
+            "asks": [{"price": "0.52", "size": "10"}, {"price": "0.55", "size": "20"}],
+            "tick_size": "0.01"
+        }"#;
+        let server = MockServer::start(vec![route("GET /book?token_id=111", 200, body)]).await;
+
+        let book = ClobClient::new().with_base_url(&server.url).get_orderbook("111").await.unwrap();
+
+        assert_eq!(book.asset_id, "111");
+        assert_eq!(book.market.as_deref(), Some("0xabc"));
+        assert_eq!(book.bids.len(), 1);
+        assert_eq!(book.asks.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_get_book_spread() {
+        let body = r#"{
+            "asset_id": "111",
+            "bids": [{"price": "0.46", "size": "5"}, {"price": "0.48", "size": "30"}],
+            "asks": [{"price": "0.55", "size": "20"}, {"price": "0.52", "size": "10"}]
+        }"#;
+        let server = MockServer::start(vec![
+            route("GET /book?token_id=111", 200, body),
+            route("GET /book?token_id=222", 200, r#"{"asset_id": "222", "bids": [], "asks": []}"#),
+        ])
+        .await;
+        let client = ClobClient::new().with_base_url(&server.url);
+
+        let spread = client.get_book_spread("111").await.unwrap();
+        assert_eq!((spread.best_bid, spread.best_ask), (0.48, 0.52));
+        assert!((spread.spread - 0.04).abs() < 1e-12);
+        assert!((spread.mid_price - 0.50).abs() < 1e-12);
+
+        let err = client.get_book_spread("222").await.unwrap_err();
+        assert!(err.to_string().contains("no bid or no ask"), "unexpected error: {}", err);
+    }
+
+    #[tokio::test]
+    async fn test_get_market_activity() {
+        let body = r#"[
+            {
+                "proxyWallet": "0xwallet",
+                "side": "BUY",
+                "asset": "111",
+                "conditionId": "0xaaa",
+                "size": 25.5,
+                "price": 0.62,
+                "timestamp": 1734000000,
+                "title": "Will it rain tomorrow?",
+                "outcome": "Yes",
+                "transactionHash": "0xtx1"
+            },
+            {"side": "SELL", "asset": "222", "size": 10, "price": 0.38}
+        ]"#;
+        let server = MockServer::start(vec![route("GET /trades?market=0xaaa", 200, body)]).await;
+        let client = ClobClient::new().with_data_api_url(&server.url);
+
+        let trades = client.get_market_activity("0xaaa", 50).await.unwrap();
+
+        assert_eq!(server.requests(), vec!["GET /trades?market=0xaaa&limit=50"]);
+        assert_eq!(trades.len(), 2);
+        assert_eq!(trades[0].asset_id, "111");
+        assert_eq!(trades[0].market.as_deref(), Some("0xaaa"));
+        assert_eq!(trades[0].price, "0.62");
+        assert_eq!(trades[0].size, "25.5");
+        assert_eq!(trades[0].side, "BUY");
+        assert_eq!(trades[0].timestamp, Some(1_734_000_000_000));
+        assert_eq!(trades[0].trade_id.as_deref(), Some("0xtx1"));
+        assert!(trades[1].timestamp.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_holders_for_token() {
+        let body = r#"[
+            {"token": "111", "holders": [
+                {"proxyWallet": "0xaaa", "pseudonym": "Big-Holder", "amount": 5000.5, "outcomeIndex": 0},
+                {"proxyWallet": "0xbbb", "amount": 120}
+            ]},
+            {"token": "222", "holders": [{"proxyWallet": "0xccc", "amount": 42}]}
+        ]"#;
+        let server = MockServer::start(vec![route("GET /holders?market=0xmarket", 200, body)]).await;
+        let client = ClobClient::new().with_data_api_url(&server.url);
+
+        let holders = client.get_holders("0xmarket", "111").await.unwrap();
+        assert_eq!(holders.len(), 2);
+        assert_eq!(holders[0].address, "0xaaa");
+        assert_eq!(holders[0].amount, 5000.5);
+        assert_eq!(holders[1].amount, 120.0);
+
+        // Unknown token yields no holders rather than an error
+        assert!(client.get_holders("0xmarket", "999").await.unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_get_fee_rates() {
+        let server = MockServer::start(vec![route(
+            "GET /fee-rate-bps",
+            200,
+            r#"{"maker_fee_rate_bps": 0, "taker_fee_rate_bps": 200}"#,
+        )])
+        .await;
+
+        let rates = ClobClient::new().with_base_url(&server.url).get_fee_rates().await.unwrap();
+        assert_eq!(rates, FeeRates { maker_fee_rate_bps: 0, taker_fee_rate_bps: 200 });
+    }
+
+    #[tokio::test]
+    async fn test_get_fee_rates_base_fee_fallback() {
+        let server = MockServer::start(vec![route("GET /fee-rate-bps", 200, r#"{"base_fee": 100}"#)]).await;
+        let client = ClobClient::new().with_base_url(&server.url);
+
+        let rates = client.get_fee_rates().await.unwrap();
+        assert_eq!(rates, FeeRates { maker_fee_rate_bps: 100, taker_fee_rate_bps: 100 });
+    }
+
+    #[tokio::test]
+    async fn test_ping_both_clients() {
+        let server = MockServer::start(vec![
+            route("GET /time", 200, "1734000000"),
+            route("GET /markets?limit=1", 200, "[]"),
+        ])
+        .await;
+
+        let clob_latency = ClobClient::new().with_base_url(&server.url).ping().await.unwrap();
+        let gamma_latency = GammaClient::new().with_base_url(&server.url).ping().await.unwrap();
+
+        assert!(clob_latency < std::time::Duration::from_secs(5));
+        assert!(gamma_latency < std::time::Duration::from_secs(5));
+        assert_eq!(server.requests(), vec!["GET /time", "GET /markets?limit=1"]);
+    }
+
+    #[tokio::test]
+    async fn test_server_time_offset_is_cached() {
+        let now = std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        let server_time = (now + 1000).to_string();
+        let server = MockServer::start(vec![route("GET /time", 200, &server_time)]).await;
+        let client = ClobClient::new().with_base_url(&server.url);
+
+        let offset = client.server_time_offset().await.unwrap();
+        assert!((offset - 1000).abs() <= 2, "offset {}", offset);
+
+        // Second call (including from a clone) uses the cache
+        assert_eq!(client.clone().server_time_offset().await.unwrap(), offset);
+        assert_eq!(server.requests(), vec!["GET /time"]);
+    }
+
+    #[tokio::test]
+    async fn test_server_time_sync_is_opt_in() {
+        let server = MockServer::start(vec![
+            route("GET /time", 200, "1734000000"),
+            route("GET /data/orders", 200, r#"{"data": []}"#),
+        ])
+        .await;
+
+        authed_client(&server).get_orders(None, None).await.unwrap();
+        assert_eq!(server.requests(), vec!["GET /data/orders"]);
+
+        authed_client(&server).with_server_time_sync(true).get_orders(None, None).await.unwrap();
+        assert_eq!(server.requests(), vec!["GET /data/orders", "GET /time", "GET /data/orders"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_order_by_id() {
+        let order = r#"{"id": "0xo1", "market": "0xm", "asset_id": "t1", "side": "BUY", "originalSize": "10",
+            "sizeMatched": "0", "price": "0.5", "status": "LIVE", "createdAt": "1734000000"}"#;
+        let server = MockServer::start(vec![
+            route("GET /data/order/0xo1", 200, order),
+            route("GET /data/order/0xmissing", 200, "null"),
+        ])
+        .await;
+        let client = authed_client(&server);
+
+        let fetched = client.get_order("0xo1").await.unwrap();
+        assert_eq!(fetched.status, "LIVE");
+        assert_eq!(fetched.asset, "t1");
+
+        let err = client.get_order("0xmissing").await.unwrap_err();
+        assert!(err.to_string().contains("Order not found: 0xmissing"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_get_order_encodes_id() {
+        let server = MockServer::start(vec![route("GET /data/order/0xo1%2F..%2Forders%3Fa%3D1", 200, "null")]).await;
+
+        let err = authed_client(&server).get_order("0xo1/../orders?a=1").await.unwrap_err();
+
+        assert!(err.to_string().contains("Order not found"), "{}", err);
+        assert_eq!(server.requests(), vec!["GET /data/order/0xo1%2F..%2Forders%3Fa%3D1"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_orders_filters_stay_out_of_signature() {
+        let server = MockServer::start(vec![route("GET /data/orders", 200, r#"{"data": []}"#)]).await;
+        let client = authed_client(&server);
+
+        client.get_orders(Some("0xabc"), Some("LIVE")).await.unwrap();
+        client.get_open_orders_for_market("0x a&b").await.unwrap();
+
+        assert_eq!(
+            server.requests(),
+            vec!["GET /data/orders?market=0xabc&status=LIVE", "GET /data/orders?market=0x%20a%26b"]
+        );
+
+        // Signature covers the bare path, as if no filters were sent
+        let timestamp: u64 = server.header(0, "POLY_TIMESTAMP").unwrap().parse().unwrap();
+        let expected = HmacAuth::new(&ApiCredentials {
+            api_key: "key".to_string(),
+            api_secret: "c2VjcmV0".to_string(),
+            api_passphrase: "pass".to_string(),
+            address: "0x0000000000000000000000000000000000000001".to_string(),
+        })
+        .with_clock(Arc::new(FixedClock(timestamp)))
+        .generate_headers("GET", "/data/orders", None)
+        .unwrap();
+        assert_eq!(server.header(0, "POLY_SIGNATURE"), Some(expected.signature));
+    }
+
+    #[tokio::test]
+    async fn test_ping_non_success_is_error() {
+        let server = MockServer::start(vec![route("GET /markets", 503, "maintenance")]).await;
+
+        // Unrouted /time returns 404
+        assert!(ClobClient::new().with_base_url(&server.url).ping().await.is_err());
+        assert!(GammaClient::new().with_base_url(&server.url).ping().await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_market_by_token_id_parses_and_caches() {
+        let body = r#"[{
+            "id": "516710",
+            "conditionId": "0xabc",
+            "question": "Will it rain?",
+            "outcomes": "[\"Yes\",\"No\"]",
+            "outcomePrices": "[\"0.3\",\"0.7\"]",
+            "clobTokenIds": "[\"111\",\"222\"]"
+        }]"#;
+        let server = MockServer::start(vec![
+            route("GET /markets?clob_token_ids=222", 200, body),
+            route("GET /markets?clob_token_ids=999", 200, "[]"),
+        ])
+        .await;
+        let client = GammaClient::new().with_base_url(&server.url);
+
+        let market = client.get_market_by_token_id("222").await.unwrap();
+        assert_eq!(market.id, "516710");
+        assert_eq!(market.question, "Will it rain?");
+        assert_eq!(market.tokens[1].token_id, "222");
+        assert_eq!(market.tokens[1].outcome, "No");
+        assert_eq!(client.cached_gamma_id("0xABC").as_deref(), Some("516710"));
+
+        // Second lookup is served from the cache
+        client.get_market_by_token_id("222").await.unwrap();
+        assert_eq!(server.requests().len(), 1);
+
+        let err = client.get_market_by_token_id("999").await.unwrap_err();
+        assert!(err.to_string().contains("999"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_get_event_markets() {
+        let body = r#"[
+            {"id": "1", "conditionId": "0xa", "question": "Candidate A?"},
+            {"id": "2", "conditionId": "0xb", "question": "Candidate B?"}
+        ]"#;
+        let server = MockServer::start(vec![route("GET /markets?event_id=903", 200, body)]).await;
+        let client = GammaClient::new().with_base_url(&server.url);
+
+        let markets = client.get_event_markets("903").await.unwrap();
+        let ids: Vec<_> = markets.iter().map(|m| m.id.as_str()).collect();
+        assert_eq!(ids, ["1", "2"]);
+        assert_eq!(server.requests(), vec!["GET /markets?event_id=903"]);
+    }
+
+    // ==================== Portfolio Tests ====================
+
+    const POSITIONS_BODY: &str = r#"[
+        {"asset": "t1", "conditionId": "0xa", "size": 10, "avgPrice": 0.5, "initialValue": 5,
+         "currentValue": 6, "cashPnl": 1, "percentPnl": 20, "curPrice": 0.6},
+        {"asset": "t2", "conditionId": "0xb", "size": 4, "avgPrice": 0.5, "initialValue": 2,
+         "currentValue": 1.5, "cashPnl": -0.5, "percentPnl": -25, "curPrice": 0.375}
+    ]"#;
+
+    const PNL_BODY: &str = r#"{"totalPnl": 1234.5, "realizedPnl": 1000, "unrealizedPnl": 234.5, "totalVolume": 98765.25}"#;
+
+    #[tokio::test]
+    async fn test_get_pnl_summary() {
+        let server = MockServer::start(vec![route("GET /pnl?user=0xme", 200, PNL_BODY)]).await;
+        let client = ClobClient::new().with_data_api_url(&server.url);
+
+        let pnl = client.get_pnl_summary("0xme").await.unwrap();
+
+        assert_eq!(
+            pnl,
+            PnlSummary { total_pnl: 1234.5, realized_pnl: 1000.0, unrealized_pnl: 234.5, total_volume: 98765.25 }
+        );
+        // Serialized camelCase, like the PortfolioSummary it is nested in
+        let json = serde_json::to_value(&pnl).unwrap();
+        assert_eq!(json["unrealizedPnl"], 234.5);
+
+        let server = MockServer::start(vec![route("GET /pnl", 404, "not found")]).await;
+        let client = ClobClient::new().with_data_api_url(&server.url);
+        let err = client.get_pnl_summary("0xme").await.unwrap_err().to_string();
+        assert!(err.contains("P&L request failed (404"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_portfolio_summary_fetches_concurrently() {
+        let order = r#"{"data": [{"id": "0xo1", "market": "0xm", "asset_id": "t1", "side": "BUY", "originalSize": "10",
+            "sizeMatched": "0", "price": "0.5", "status": "LIVE", "createdAt": "1734000000"}]}"#;
+        let delay = std::time::Duration::from_millis(300);
+        let server = MockServer::start(vec![
+            delayed_route("GET /positions?user=0xme", 200, POSITIONS_BODY, delay),
+            delayed_route("GET /data/orders", 200, order, delay),
+            delayed_route("GET /pnl?user=0xme", 200, PNL_BODY, delay),
+        ])
+        .await;
+        let client = authed_client(&server).with_data_api_url(&server.url);
+
+        let started = std::time::Instant::now();
+        let summary = client.get_portfolio_summary("0xme").await.unwrap();
+        let elapsed = started.elapsed();
+
+        // Sequential would take at least 2 * delay
+        assert!(elapsed < delay * 2, "took {:?}", elapsed);
+        assert_eq!(summary.positions.len(), 2);
+        assert_eq!(summary.open_orders[0].id, "0xo1");
+        assert!((summary.total_value - 7.5).abs() < 1e-9);
+        assert!((summary.total_cash_pnl - 0.5).abs() < 1e-9);
+        assert_eq!(summary.pnl.unwrap().total_pnl, 1234.5);
+    }
+
+    #[tokio::test]
+    async fn test_portfolio_summary_drops_unexpected_pnl_shape() {
+        let server = MockServer::start(vec![
+            route("GET /positions?user=0xme", 200, POSITIONS_BODY),
+            route("GET /data/orders", 200, r#"{"data": []}"#),
+            route("GET /pnl?user=0xme", 200, r#"{"pnl": 1234.5}"#),
+        ])
+        .await;
+        let client = authed_client(&server).with_data_api_url(&server.url);
+
+        let summary = client.get_portfolio_summary("0xme").await.unwrap();
+
+        assert_eq!(summary.positions.len(), 2);
+        assert!(summary.pnl.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_all_positions_pages_until_short_page() {
+        let page2 = r#"[
+            {"asset": "t3", "conditionId": "0xc", "size": 1, "avgPrice": 0.5, "initialValue": 0.5,
+             "currentValue": 0.5, "cashPnl": 0, "percentPnl": 0, "curPrice": 0.5}
+        ]"#;
+        let server = MockServer::start(vec![
+            route("GET /positions?user=0xme&limit=2&offset=0", 200, POSITIONS_BODY),
+            route("GET /positions?user=0xme&limit=2&offset=2", 200, page2),
+        ])
+        .await;
+        let client = ClobClient::new().with_data_api_url(&server.url);
+
+        let positions = client.collect_positions("0xme", 2).await.unwrap();
+
+        let assets: Vec<_> = positions.iter().map(|p| p.asset.as_str()).collect();
+        assert_eq!(assets, ["t1", "t2", "t3"]);
+        assert_eq!(
+            server.requests(),
+            vec!["GET /positions?user=0xme&limit=2&offset=0", "GET /positions?user=0xme&limit=2&offset=2"]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_get_all_positions_exact_multiple_needs_empty_page() {
+        let server = MockServer::start(vec![
+            route("GET /positions?user=0xme&limit=2&offset=0", 200, POSITIONS_BODY),
+            route("GET /positions?user=0xme&limit=2&offset=2", 200, "[]"),
+        ])
+        .await;
+        let client = ClobClient::new().with_data_api_url(&server.url);
+
+        assert_eq!(client.collect_positions("0xme", 2).await.unwrap().len(), 2);
+        assert_eq!(server.requests().len(), 2);
+
+        // A failing page fails the whole fetch
+        let server = MockServer::start(vec![route("GET /positions?user=0xme&limit=2&offset=0", 200, POSITIONS_BODY)]).await;
+        let client = ClobClient::new().with_data_api_url(&server.url);
+        assert!(client.collect_positions("0xme", 2).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_get_all_positions_uses_max_page_size() {
+        let server = MockServer::start(vec![route("GET /positions?user=0xme", 200, POSITIONS_BODY)]).await;
+        let client = ClobClient::new().with_data_api_url(&server.url);
+
+        assert_eq!(client.get_all_positions("0xme").await.unwrap().len(), 2);
+        assert_eq!(server.requests(), vec!["GET /positions?user=0xme&limit=500&offset=0"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_positions_filtered() {
+        let body = r#"[
+            {"asset": "t1", "conditionId": "0xa", "size": 10, "avgPrice": 0.5, "initialValue": 5,
+             "currentValue": 6, "cashPnl": 1, "percentPnl": 20, "curPrice": 0.6},
+            {"asset": "dust", "conditionId": "0xc", "size": 0.001, "avgPrice": 0.5, "initialValue": 0.0005,
+             "currentValue": 0.0009, "cashPnl": 0.0004, "percentPnl": 80, "curPrice": 0.9},
+            {"asset": "t2", "conditionId": "0xb", "size": 4, "avgPrice": 0.5, "initialValue": 2,
+             "currentValue": 8, "cashPnl": 6, "percentPnl": 300, "curPrice": 2}
+        ]"#;
+        let server = MockServer::start(vec![route("GET /positions?user=0xme", 200, body)]).await;
+        let client = ClobClient::new().with_data_api_url(&server.url);
+
+        let positions = client
+            .get_positions_filtered("0xme", Some(PositionSortKey::CashPnl), Some(0.01))
+            .await
+            .unwrap();
+
+        let assets: Vec<_> = positions.iter().map(|p| p.asset.as_str()).collect();
+        assert_eq!(assets, ["t2", "t1"]);
+    }
+
+    #[tokio::test]
+    async fn test_portfolio_summary_errors() {
+        // Only orders fail: that error is returned as-is
+        let server = MockServer::start(vec![route("GET /positions?user=0xme", 200, POSITIONS_BODY)]).await;
+        let client = authed_client(&server).with_data_api_url(&server.url);
+        let err = client.get_portfolio_summary("0xme").await.unwrap_err().to_string();
+        assert!(err.starts_with("API error: Orders request failed (404"), "{}", err);
+
+        // Only P&L fails: the summary still comes back, without it
+        let order = r#"{"data": []}"#;
+        let server = MockServer::start(vec![
+            route("GET /positions?user=0xme", 200, POSITIONS_BODY),
+            route("GET /data/orders", 200, order),
+            route("GET /pnl", 500, "oops"),
+        ])
+        .await;
+        let client = authed_client(&server).with_data_api_url(&server.url);
+        let summary = client.get_portfolio_summary("0xme").await.unwrap();
+        assert_eq!(summary.positions.len(), 2);
+        assert!(summary.pnl.is_none());
+
+        // Both fail: both messages survive
+        let server = MockServer::start(vec![]).await;
+        let client = authed_client(&server).with_data_api_url(&server.url);
+        let err = client.get_portfolio_summary("0xme").await.unwrap_err().to_string();
+        assert!(err.contains("positions: API error: Positions request failed"), "{}", err);
+        assert!(err.contains("orders: API error: Orders request failed"), "{}", err);
+    }
+
+    // ==================== Market Filter Tests ====================
+
+    #[test]
+    fn test_market_filter_default_query_string() {
+        assert_eq!(
+            MarketFilter::default().to_query_string(),
+            "active=true&closed=false&archived=false&limit=50&order=volumeNum&ascending=false"
+        );
+    }
+
+    #[test]
+    fn test_market_filter_resolution_source_query_string() {
+        let filter = MarketFilter { limit: Some(10), ..Default::default() }
+            .with_resolution_source(ResolutionSource::Uma);
+        assert_eq!(
+            filter.to_query_string(),
+            "active=true&closed=false&archived=false&limit=10&resolutionSource=uma&order=volumeNum&ascending=false"
+        );
+
+        let filter = MarketFilter::default().with_resolution_source(ResolutionSource::from("My Oracle"));
+        assert!(filter.to_query_string().contains("resolutionSource=My%20Oracle"));
+    }
+
+    #[test]
+    fn test_market_filter_closed_and_archived() {
+        let closed = MarketFilter::default().include_closed(true).to_query_string();
+        assert!(closed.starts_with("active=true&archived=false&limit=50"), "{}", closed);
+        assert!(!closed.contains("closed="));
+
+        let archived = MarketFilter::default().include_archived(true).to_query_string();
+        assert!(archived.starts_with("active=true&closed=true&limit=50"), "{}", archived);
+        assert!(!archived.contains("archived="));
+
+        let historical = MarketFilter::default().include_archived(true).include_closed(true);
+        assert_eq!(historical.to_query_string(), archived);
+    }
+
+    #[test]
+    fn test_market_filter_deserializes_partial_json() {
+        let filter: MarketFilter = serde_json::from_str(
+            r#"{"query": "bitcoin", "limit": 20, "resolution_source": "UMA", "end_date_max": "2025-06-01T00:00:00Z"}"#,
+        )
+        .unwrap();
+        assert_eq!(filter.query.as_deref(), Some("bitcoin"));
+        assert_eq!(filter.limit, Some(20));
+        assert_eq!(filter.resolution_source, Some(ResolutionSource::Uma));
+        assert!(filter.end_date_max.is_some() && filter.end_date_min.is_none());
+        assert!(!filter.include_archived);
+    }
+
+    #[test]
+    fn test_market_filter_resolving_within_timestamps() {
+        let now = chrono::DateTime::parse_from_rfc3339("2025-03-31T22:15:30.750+00:00")
+            .unwrap()
+            .with_timezone(&chrono::Utc);
+
+        // 30h crosses a day and a month boundary; sub-second precision is dropped
+        let query = MarketFilter::default().resolving_within(now, 30).to_query_string();
+        assert!(
+            query.contains("&end_date_min=2025-03-31T22%3A15%3A30Z&end_date_max=2025-04-02T04%3A15%3A30Z&"),
+            "{}",
+            query
+        );
+        assert!(query.starts_with("active=true&closed=false"), "{}", query);
+
+        let now_only = MarketFilter::default().resolving_within(now, 0).to_query_string();
+        assert!(now_only.contains("end_date_max=2025-03-31T22%3A15%3A30Z"), "{}", now_only);
+    }
+
+    #[tokio::test]
+    async fn test_get_markets_resolving_within() {
+        let body = r#"[
+            {"id": "1", "conditionId": "0xa", "question": "Open?", "active": true, "acceptingOrders": true},
+            {"id": "2", "conditionId": "0xb", "question": "Paused?", "active": true, "acceptingOrders": false}
+        ]"#;
+        let server = MockServer::start(vec![route("GET /markets", 200, body)]).await;
+        let client = GammaClient::new().with_base_url(&server.url);
+
+        let all = client.get_markets_resolving_within(24, Some(5), false).await.unwrap();
+        assert_eq!(all.len(), 2);
+        let tradeable = client.get_markets_resolving_within(24, Some(5), true).await.unwrap();
+        assert_eq!(tradeable.iter().map(|m| m.id.as_str()).collect::<Vec<_>>(), vec!["1"]);
+
+        let request = &server.requests()[0];
+        assert!(request.contains("active=true"), "{}", request);
+        assert!(request.contains("limit=5"), "{}", request);
+        assert!(request.contains("end_date_min=") && request.contains("end_date_max="), "{}", request);
+    }
+
+    #[tokio::test]
+    async fn test_market_filter_accepting_orders_only() {
+        let body = r#"[
+            {"id": "1", "conditionId": "0xa", "question": "Open?", "active": true, "acceptingOrders": true},
+            {"id": "2", "conditionId": "0xb", "question": "Paused?", "active": true, "acceptingOrders": false},
+            {"id": "3", "conditionId": "0xc", "question": "Closed?", "active": true, "closed": true, "acceptingOrders": true}
+        ]"#;
+        let server = MockServer::start(vec![route("GET /markets", 200, body)]).await;
+        let client = GammaClient::new().with_base_url(&server.url);
+
+        let all = client.get_markets_filtered(&MarketFilter::default()).await.unwrap();
+        assert_eq!(all.len(), 3);
+
+        let tradeable = client
+            .get_markets_filtered(&MarketFilter::default().accepting_orders_only(true))
+            .await
+            .unwrap();
+        // Closed markets can still report acceptingOrders - same check as predicates::accepting_orders
+        assert_eq!(tradeable.len(), 1);
+        assert_eq!(tradeable[0].id, "1");
+    }
+
+    #[test]
+    fn test_resolution_source_parsing() {
+        assert_eq!(ResolutionSource::from("UMA"), ResolutionSource::Uma);
+        assert_eq!(ResolutionSource::from("chainlink"), ResolutionSource::Chainlink);
+        assert_eq!(ResolutionSource::from("manual"), ResolutionSource::Manual);
+        assert_eq!(ResolutionSource::from("augur"), ResolutionSource::Custom("augur".to_string()));
+    }
+
+    #[test]
+    fn test_raw_market_resolution_source() {
+        let json = r#"{"id": "1", "conditionId": "0xabc", "question": "Q?", "resolutionSource": "https://uma.xyz"}"#;
+        let market: Market = serde_json::from_str::<RawMarket>(json).unwrap().into();
+        assert_eq!(market.resolution_source.as_deref(), Some("https://uma.xyz"));
+    }
+
+    // ==================== Replace Order Tests ====================
+
+    fn authed_client(server: &MockServer) -> ClobClient {
+        let credentials = ApiCredentials {
+            api_key: "key".to_string(),
+            api_secret: "c2VjcmV0".to_string(),
+            api_passphrase: "pass".to_string(),
+            address: "0x0000000000000000000000000000000000000001".to_string(),
+        };
+        ClobClient::with_credentials(&credentials).unwrap().with_base_url(&server.url)
+    }
+
+    fn signed_order() -> SignedOrder {
+        SignedOrder {
+            order: UnsignedOrder {
+                salt: "1".to_string(),
+                maker: "0xmaker".to_string(),
+                signer: "0xmaker".to_string(),
+                taker: "0x0000000000000000000000000000000000000000".to_string(),
+                token_id: "111".to_string(),
+                maker_amount: "550000".to_string(),
+                taker_amount: "1000000".to_string(),
+                expiration: "0".to_string(),
+                nonce: "0".to_string(),
+                fee_rate_bps: "0".to_string(),
+                side: OrderSide::Buy,
+                signature_type: SignatureType::Proxy,
+            },
+            signature: "0xsig".to_string(),
+        }
+    }
+
+    const PLACED: &str = r#"{"success": true, "orderId": "0xnew", "status": "live"}"#;
+    const CANCELLED: &str = r#"{"canceled": ["0xold"], "not_canceled": {}}"#;
+
+    #[tokio::test]
+    async fn test_replace_order_place_first() {
+        let server = MockServer::start(vec![
+            route("POST /order", 200, PLACED),
+            route("DELETE /order?orderID=0xold", 200, CANCELLED),
+        ])
+        .await;
+
+        let result = authed_client(&server)
+            .replace_order("0xold", signed_order(), "key", OrderType::Gtc)
+            .await;
+
+        assert!(result.is_complete(), "{:?}", result);
+        assert_eq!(result.placed.unwrap().order_id.as_deref(), Some("0xnew"));
+        assert_eq!(server.requests(), vec!["POST /order", "DELETE /order?orderID=0xold"]);
+    }
+
+    #[tokio::test]
+    async fn test_replace_order_place_first_keeps_old_on_rejection() {
+        let server = MockServer::start(vec![
+            route("POST /order", 200, r#"{"success": false, "errorMsg": "not enough balance"}"#),
+            route("DELETE /order", 200, CANCELLED),
+        ])
+        .await;
+
+        let result = authed_client(&server)
+            .replace_order("0xold", signed_order(), "key", OrderType::Gtc)
+            .await;
+
+        assert!(!result.new_order_placed());
+        assert_eq!(result.place_error.as_deref(), Some("not enough balance"));
+        // Old order must not be touched when the new one was rejected
+        assert!(result.cancelled.is_none());
+        assert_eq!(server.requests(), vec!["POST /order"]);
+    }
+
+    #[tokio::test]
+    async fn test_replace_order_cancel_first_skips_place_when_cancel_fails() {
+        let server = MockServer::start(vec![
+            route("DELETE /order", 200, r#"{"canceled": [], "not_canceled": {"0xold": "order already matched"}}"#),
+            route("POST /order", 200, PLACED),
+        ])
+        .await;
+
+        let result = authed_client(&server)
+            .with_replace_policy(ReplacePolicy::CancelFirst)
+            .replace_order("0xold", signed_order(), "key", OrderType::Gtc)
+            .await;
+
+        assert_eq!(result.policy, ReplacePolicy::CancelFirst);
+        assert!(!result.old_order_cancelled());
+        assert_eq!(result.cancel_error.as_deref(), Some("order already matched"));
+        // Nothing placed when the cancel did not go through
+        assert!(result.placed.is_none());
+        assert_eq!(server.requests(), vec!["DELETE /order?orderID=0xold"]);
+    }
+
+    #[tokio::test]
+    async fn test_get_trades_for_order_taker_and_maker() {
+        let body = r#"{"data": [
+            {
+                "id": "trade-1",
+                "taker_order_id": "0xmine",
+                "size": "100",
+                "price": "0.5",
+                "fee_rate_bps": "100",
+                "match_time": "1734000000",
+                "maker_orders": [{"order_id": "0xother", "matched_amount": "100", "price": "0.5", "fee_rate_bps": "0"}]
+            },
+            {
+                "id": "trade-2",
+                "taker_order_id": "0xsomeone",
+                "size": "40",
+                "price": "0.52",
+                "fee_rate_bps": "0",
+                "match_time": "1734000100",
+                "maker_orders": [{"order_id": "0xmine", "matched_amount": "15", "price": "0.51", "fee_rate_bps": "0"}]
+            }
+        ], "next_cursor": "LTE="}"#;
+        let server = MockServer::start(vec![route("GET /data/trades?order_id=0xmine", 200, body)]).await;
+
+        let fills = authed_client(&server).get_trades_for_order("0xmine").await.unwrap();
+
+        assert_eq!(fills.len(), 2);
+        assert_eq!(fills[0].trade_id, "trade-1");
+        assert_eq!(fills[0].size, 100.0);
+        assert!((fills[0].fee - 0.5).abs() < 1e-12);
+        // Maker side uses the maker order's matched amount and price
+        assert_eq!(fills[1].size, 15.0);
+        assert_eq!(fills[1].price, 0.51);
+        assert_eq!(fills[1].timestamp, "1734000100");
+    }
+
+    #[tokio::test]
+    async fn test_cancel_orders_batch() {
+        let server = MockServer::start(vec![route(
+            "DELETE /orders",
+            200,
+            r#"{"canceled": ["0xa", "0xb"], "not_canceled": {"0xc": "order not found"}}"#,
+        )])
+        .await;
+        let order_ids = vec!["0xa".to_string(), "0xb".to_string(), "0xc".to_string()];
+
+        let response = authed_client(&server).cancel_orders(&order_ids).await.unwrap();
+
+        assert_eq!(server.requests(), vec!["DELETE /orders"]);
+        assert_eq!(server.bodies(), vec![r#"["0xa","0xb","0xc"]"#]);
+        assert_eq!(response.canceled, vec!["0xa", "0xb"]);
+        assert_eq!(response.not_canceled["0xc"], "order not found");
+    }
+
+    #[tokio::test]
+    async fn test_cancel_token_orders_filters_by_asset() {
+        let orders = r#"{"data": [
+            {"id": "0xyes1", "market": "0xm", "asset_id": "yes", "side": "BUY", "originalSize": "10",
+             "sizeMatched": "0", "price": "0.4", "status": "LIVE", "createdAt": "1"},
+            {"id": "0xno", "market": "0xm", "asset_id": "no", "side": "BUY", "originalSize": "10",
+             "sizeMatched": "0", "price": "0.6", "status": "LIVE", "createdAt": "1"},
+            {"id": "0xyes2", "market": "0xm", "asset_id": "yes", "side": "SELL", "originalSize": "5",
+             "sizeMatched": "0", "price": "0.45", "status": "LIVE", "createdAt": "2"}
+        ]}"#;
+        let server = MockServer::start(vec![
+            route("GET /data/orders", 200, orders),
+            route("DELETE /orders", 200, r#"{"canceled": ["0xyes1", "0xyes2"], "not_canceled": {}}"#),
+        ])
+        .await;
+
+        let response = authed_client(&server).cancel_token_orders("yes").await.unwrap();
+
+        assert_eq!(server.requests(), vec!["GET /data/orders", "DELETE /orders"]);
+        assert_eq!(server.bodies()[1], r#"["0xyes1","0xyes2"]"#);
+        assert_eq!(response.canceled, vec!["0xyes1", "0xyes2"]);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_token_orders_without_matches_skips_cancel() {
+        let server = MockServer::start(vec![route("GET /data/orders", 200, r#"{"data": []}"#)]).await;
+
+        let response = authed_client(&server).cancel_token_orders("yes").await.unwrap();
+
+        assert!(response.canceled.is_empty());
+        assert_eq!(server.requests(), vec!["GET /data/orders"]);
+    }
+
+    #[tokio::test]
+    async fn test_cancel_all_orders_and_refresh() {
+        let remaining = r#"{"data": [
+            {"id": "0xstuck", "market": "0xm", "asset_id": "yes", "side": "BUY", "originalSize": "10",
+             "sizeMatched": "0", "price": "0.4", "status": "LIVE", "createdAt": "1"}
+        ]}"#;
+        let server = MockServer::start(vec![
+            route("DELETE /cancel-all", 200, r#"{"canceled": ["0xa", "0xb"], "not_canceled": {"0xstuck": "order is being matched"}}"#),
+            route("GET /data/orders", 200, remaining),
+        ])
+        .await;
+
+        let (cancelled, orders) = authed_client(&server).cancel_all_orders_and_refresh().await.unwrap();
+
+        // Cancel first, then the refetch
+        assert_eq!(server.requests(), vec!["DELETE /cancel-all", "GET /data/orders"]);
+        assert_eq!(cancelled.canceled, vec!["0xa", "0xb"]);
+        assert_eq!(cancelled.not_canceled["0xstuck"], "order is being matched");
+        assert_eq!(orders.len(), 1);
+        assert_eq!(orders[0].id, "0xstuck");
+    }
+
+    #[tokio::test]
+    async fn test_cancel_all_orders_and_refresh_skips_refresh_on_failure() {
+        let server = MockServer::start(vec![route("DELETE /cancel-all", 500, "oops")]).await;
+
+        let err = authed_client(&server).cancel_all_orders_and_refresh().await.unwrap_err();
+
+        assert!(err.to_string().contains("Cancel all failed (500"), "{}", err);
+        assert_eq!(server.requests(), vec!["DELETE /cancel-all"]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_api_key() {
+        let server = MockServer::start(vec![route("DELETE /auth/api-key", 200, "OK")]).await;
+
+        authed_client(&server).delete_api_key().await.unwrap();
+
+        assert_eq!(server.requests(), vec!["DELETE /auth/api-key"]);
+    }
+
+    #[tokio::test]
+    async fn test_delete_api_key_failure() {
+        let server = MockServer::start(vec![route("DELETE /auth/api-key", 401, "Unauthorized")]).await;
+
+        let err = authed_client(&server).delete_api_key().await.unwrap_err();
+
+        assert!(err.to_string().contains("API key deletion failed (401"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_create_api_key() {
+        let server = MockServer::start(vec![route(
+            "POST /auth/api-key",
+            200,
+            r#"{"apiKey": "new-key", "secret": "bmV3", "passphrase": "new-pass"}"#,
+        )])
+        .await;
+        let signer = PolymarketSigner::from_private_key(TEST_PRIVATE_KEY).unwrap();
+
+        let credentials = ClobClient::new().with_base_url(&server.url).create_api_key(&signer, 0).await.unwrap();
+
+        assert_eq!(credentials.api_key, "new-key");
+        assert_eq!(credentials.api_secret, "bmV3");
+        assert_eq!(credentials.api_passphrase, "new-pass");
+        assert_eq!(credentials.address, signer.address_string());
+        // L1 auth, not HMAC - and not the derive endpoint, which would return the old key
+        assert_eq!(server.requests(), vec!["POST /auth/api-key"]);
+        assert_eq!(server.header(0, "POLY_NONCE").as_deref(), Some("0"));
+        assert!(server.header(0, "POLY_SIGNATURE").is_some());
+    }
+
+    #[tokio::test]
+    async fn test_create_api_key_failure() {
+        let server = MockServer::start(vec![route("POST /auth/api-key", 400, "Could not create api key")]).await;
+        let signer = PolymarketSigner::from_private_key(TEST_PRIVATE_KEY).unwrap();
+
+        let err = ClobClient::new().with_base_url(&server.url).create_api_key(&signer, 0).await.unwrap_err();
+
+        assert!(err.to_string().contains("API key creation failed (400"), "{}", err);
+    }
+
+    #[tokio::test]
+    async fn test_delete_api_key_requires_credentials() {
+        let server = MockServer::start(vec![]).await;
+
+        let result = ClobClient::new().with_base_url(&server.url).delete_api_key().await;
+
+        assert!(result.is_err());
+        assert!(server.requests().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_cancel_orders_empty_skips_request() {
+        let server = MockServer::start(vec![]).await;
+
+        // No GET /time either
+        let response = authed_client(&server).with_server_time_sync(true).cancel_orders(&[]).await.unwrap();
+
+        assert!(response.canceled.is_empty());
+        assert!(server.requests().is_empty());
+    }
+
+    // ==================== Batch Order Tests ====================
+
+    #[test]
+    fn test_batch_request_serialization() {
+        let mut no_leg = signed_order();
+        no_leg.order.token_id = "222".to_string();
+        no_leg.order.side = OrderSide::Sell;
+
+        let batch = vec![
+            PlaceOrderRequest { order: signed_order(), owner: "key".to_string(), order_type: OrderType::Gtc },
+            PlaceOrderRequest { order: no_leg, owner: "key".to_string(), order_type: OrderType::Fok },
+        ];
+
+        let body: serde_json::Value = serde_json::to_value(&batch).unwrap();
+        let items = body.as_array().unwrap();
+        assert_eq!(items.len(), 2);
+
+        assert_eq!(items[0]["owner"], "key");
+        assert_eq!(items[0]["orderType"], "GTC");
+        assert_eq!(items[0]["order"]["tokenId"], "111");
+        assert_eq!(items[0]["order"]["side"], "BUY");
+        assert_eq!(items[0]["order"]["signature"], "0xsig");
+
+        assert_eq!(items[1]["orderType"], "FOK");
+        assert_eq!(items[1]["order"]["tokenId"], "222");
+        assert_eq!(items[1]["order"]["side"], "SELL");
+    }
+
+    #[tokio::test]
+    async fn test_place_orders_batch() {
+        let server = MockServer::start(vec![route(
+            "POST /orders",
+            200,
+            r#"[
+                {"success": true, "orderId": "0xa", "status": "live"},
+                {"success": false, "errorMsg": "not enough balance"}
+            ]"#,
+        )])
+        .await;
+
+        let batch = vec![
+            PlaceOrderRequest { order: signed_order(), owner: "key".to_string(), order_type: OrderType::Gtc },
+            PlaceOrderRequest { order: signed_order(), owner: "key".to_string(), order_type: OrderType::Gtc },
+        ];
+        let results = authed_client(&server).place_orders_batch(batch).await.unwrap();
+
+        assert_eq!(server.requests(), vec!["POST /orders"]);
+        let body: serde_json::Value = serde_json::from_str(&server.bodies()[0]).unwrap();
+        assert_eq!(body.as_array().unwrap().len(), 2);
+
+        assert_eq!(results.len(), 2);
+        assert_eq!(results[0].order_id.as_deref(), Some("0xa"));
+        assert!(!results[1].success);
+        assert_eq!(results[1].error_msg.as_deref(), Some("not enough balance"));
+    }
+
+    #[tokio::test]
+    async fn test_place_orders_batch_empty_skips_request() {
+        let server = MockServer::start(vec![]).await;
+        let results = authed_client(&server).place_orders_batch(Vec::new()).await.unwrap();
+        assert!(results.is_empty());
+        assert!(server.requests().is_empty());
+    }
+
+    // ==================== Account Type Tests ====================
+
+    const BALANCE: &str = r#"{"balance": "1000000", "allowances": {}}"#;
+
+    #[tokio::test]
+    async fn test_balance_uses_configured_signature_type() {
+        for (sig_type, expected) in [
+            (SignatureType::Eoa, "signature_type=0"),
+            (SignatureType::Proxy, "signature_type=1"),
+            (SignatureType::GnosisSafe, "signature_type=2"),
+        ] {
+            let server = MockServer::start(vec![route("GET /balance-allowance", 200, BALANCE)]).await;
+            let client = authed_client(&server).with_signature_type(sig_type);
+
+            client.get_balance().await.unwrap();
+            assert_eq!(
+                server.requests(),
+                vec![format!("GET /balance-allowance?asset_type=COLLATERAL&{}", expected)]
+            );
+        }
+    }
+
+    #[tokio::test]
+    async fn test_safe_account_end_to_end() {
+        let server = MockServer::start(vec![
+            route("GET /balance-allowance", 200, BALANCE),
+            route("POST /order", 200, PLACED),
+        ])
+        .await;
+        let mut client = authed_client(&server).with_signature_type(SignatureType::GnosisSafe);
+
+        // Account type survives credential changes (login replaces credentials in place)
+        client.set_credentials(&ApiCredentials {
+            api_key: "key2".to_string(),
+            api_secret: "c2VjcmV0".to_string(),
+            api_passphrase: "pass".to_string(),
+            address: "0x0000000000000000000000000000000000000002".to_string(),
+        })
+        .unwrap();
+        assert_eq!(client.signature_type(), SignatureType::GnosisSafe);
+
+        client.get_balance().await.unwrap();
+
+        // Order built the way the app does it: Safe funds the order, EOA signs it
+        let sig_type = client.signature_type();
+        let mut order = signed_order();
+        order.order.signer = "0xsigner".to_string();
+        order.order.maker = sig_type.maker_address("0xsigner", Some("0xsafe")).to_string();
+        order.order.signature_type = sig_type;
+        client.place_order(order, "key2", OrderType::Gtc).await.unwrap();
+
+        assert_eq!(
+            server.requests(),
+            vec![
+                "GET /balance-allowance?asset_type=COLLATERAL&signature_type=2".to_string(),
+                "POST /order".to_string(),
+            ]
+        );
+        let body: serde_json::Value = serde_json::from_str(&server.bodies()[1]).unwrap();
+        assert_eq!(body["order"]["maker"], "0xsafe");
+        assert_eq!(body["order"]["signer"], "0xsigner");
+        assert_eq!(body["order"]["signatureType"], 2);
+    }
+
+    // ==================== Balance Watcher Tests ====================
+
+    /// Records every balance_update, order_update, fill and market_resolved; all other events are ignored
+    #[derive(Default)]
+    struct WatcherEmitter {
+        balances: Mutex<Vec<String>>,
+        order_events: Mutex<Vec<OrderEvent>>,
+        fills: Mutex<Vec<OrderFillEvent>>,
+        resolutions: Mutex<Vec<(String, String)>>,
+    }
+
+    impl EventEmitter for WatcherEmitter {
+        fn emit_price_update(&self, _update: &PriceUpdate) {}
+        fn emit_orderbook_snapshot(&self, _snapshot: &OrderBookSnapshot) {}
+        fn emit_trade(&self, _trade: &ClobTrade) {}
+        fn emit_trade_update(&self, _trade: &RtdsTrade) {}
+        fn emit_connection_status(&self, _status: &ConnectionStatus) {}
+
+        fn emit_balance_update(&self, balance: &Balance) {
+            self.balances.lock().unwrap().push(balance.balance.clone());
+        }
+
+        fn emit_order_update(&self, event: &OrderEvent) {
+            self.order_events.lock().unwrap().push(event.clone());
+        }
+
+        fn emit_fill(&self, fill: &OrderFillEvent) {
+            self.fills.lock().unwrap().push(fill.clone());
+        }
+
+        fn emit_market_resolved(&self, condition_id: &str, winning_token: &Token) {
+            self.resolutions.lock().unwrap().push((condition_id.to_string(), winning_token.token_id.clone()));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_balance_change_emits_once() {
+        let before = MockServer::start(vec![route("GET /balance-allowance", 200, BALANCE)]).await;
+        let after = MockServer::start(vec![
+            route("GET /balance-allowance", 200, r#"{"balance": "2500000", "allowances": {}}"#),
+        ])
+        .await;
+        let emitter = WatcherEmitter::default();
+        let mut last = None;
+
+        // First observation is reported, repeats of the same value are not
+        assert!(poll_balance(&authed_client(&before), &emitter, &mut last).await.unwrap());
+        assert!(!poll_balance(&authed_client(&before), &emitter, &mut last).await.unwrap());
+
+        // A changed value emits exactly once
+        assert!(poll_balance(&authed_client(&after), &emitter, &mut last).await.unwrap());
+        assert!(!poll_balance(&authed_client(&after), &emitter, &mut last).await.unwrap());
+
+        assert_eq!(*emitter.balances.lock().unwrap(), vec!["1000000", "2500000"]);
+    }
+
+    #[tokio::test]
+    async fn test_balance_poll_error_keeps_last_value() {
+        let server = MockServer::start(vec![route("GET /balance-allowance", 500, "oops")]).await;
+        let emitter = WatcherEmitter::default();
+        let mut last = Some("1000000".to_string());
+
+        assert!(poll_balance(&authed_client(&server), &emitter, &mut last).await.is_err());
+        assert_eq!(last.as_deref(), Some("1000000"));
+        assert!(emitter.balances.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_balance_watcher_polls_until_stopped() {
+        let server = MockServer::start(vec![route("GET /balance-allowance", 200, BALANCE)]).await;
+        let emitter = Arc::new(WatcherEmitter::default());
+        let watcher = BalanceWatcher::new();
+
+        watcher.start(authed_client(&server), emitter.clone(), std::time::Duration::from_millis(10));
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+        assert!(watcher.is_running());
+
+        watcher.stop();
+        assert!(!watcher.is_running());
+        assert!(server.requests().len() > 1, "expected repeated polls: {:?}", server.requests());
+        // Balance never changed, so only the initial value was emitted
+        assert_eq!(*emitter.balances.lock().unwrap(), vec!["1000000"]);
+    }
+
+    // ==================== Order Watcher Tests ====================
+
+    fn open_order(id: &str, original: &str, matched: &str, status: &str) -> Order {
+        Order {
+            id: id.to_string(),
+            market: "0xmarket".to_string(),
+            asset: "token1".to_string(),
+            side: "BUY".to_string(),
+            original_size: original.to_string(),
+            size_matched: matched.to_string(),
+            price: "0.5".to_string(),
+            status: status.to_string(),
+            order_type: "GTC".to_string(),
+            created_at: "1734000000".to_string(),
+        }
+    }
+
+    fn summary(events: &[OrderEvent]) -> Vec<(String, OrderEventKind, f64)> {
+        let mut summary: Vec<_> = events.iter().map(|e| (e.order.id.clone(), e.kind, e.newly_matched)).collect();
+        summary.sort_by(|a, b| a.0.cmp(&b.0));
+        summary
+    }
+
+    #[test]
+    fn test_diff_orders_between_snapshots() {
+        let first = [
+            open_order("idle", "100", "0", "LIVE"),
+            open_order("partial", "100", "10", "LIVE"),
+            open_order("fills", "50", "20", "LIVE"),
+            open_order("cancels", "100", "0", "LIVE"),
+            open_order("gone", "30", "5", "LIVE"),
+        ];
+        let previous = first.iter().map(|o| (o.id.clone(), o.clone())).collect();
+        let second = [
+            open_order("idle", "100", "0", "LIVE"),
+            open_order("partial", "100", "35", "LIVE"),
+            open_order("fills", "50", "50", "MATCHED"),
+            open_order("cancels", "100", "0", "CANCELED"),
+            open_order("new", "10", "4", "LIVE"),
+        ];
+
+        let (events, vanished) = diff_orders(&previous, &second);
+
+        assert_eq!(
+            summary(&events),
+            vec![
+                ("cancels".to_string(), OrderEventKind::Cancelled, 0.0),
+                ("fills".to_string(), OrderEventKind::Filled, 30.0),
+                ("new".to_string(), OrderEventKind::PartiallyFilled, 4.0),
+                ("partial".to_string(), OrderEventKind::PartiallyFilled, 25.0),
+            ]
+        );
+        assert_eq!(vanished.iter().map(|o| o.id.as_str()).collect::<Vec<_>>(), vec!["gone"]);
+
+        // Identical snapshots produce nothing
+        let again = second.iter().map(|o| (o.id.clone(), o.clone())).collect();
+        let (events, vanished) = diff_orders(&again, &second);
+        assert!(events.is_empty() && vanished.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_poll_orders_resolves_vanished_orders() {
+        let first = MockServer::start(vec![route(
+            "GET /data/orders",
+            200,
+            r#"{"data": [
+                {"id": "0xfilled", "market": "m", "asset_id": "t", "side": "BUY", "originalSize": "20",
+                 "sizeMatched": "5", "price": "0.5", "status": "LIVE", "createdAt": "1"},
+                {"id": "0xcancelled", "market": "m", "asset_id": "t", "side": "SELL", "originalSize": "20",
+                 "sizeMatched": "0", "price": "0.6", "status": "LIVE", "createdAt": "1"}
+            ]}"#,
+        )])
+        .await;
+        // Both orders are gone; only 0xfilled has fills covering its full size
+        let second = MockServer::start(vec![
+            route("GET /data/orders", 200, r#"{"data": []}"#),
+            route(
+                "GET /data/trades?order_id=0xfilled",
+                200,
+                r#"[{"id": "trade-1", "taker_order_id": "0xfilled", "size": "20", "price": "0.5",
+                     "fee_rate_bps": "0", "match_time": "2", "maker_orders": []}]"#,
+            ),
+            route("GET /data/trades?order_id=0xcancelled", 200, "[]"),
+        ])
+        .await;
+        let emitter = WatcherEmitter::default();
+        let mut previous = None;
+
+        // The first poll is only a baseline
+        assert_eq!(poll_orders(&authed_client(&first), &emitter, &mut previous).await.unwrap(), 0);
+        assert_eq!(poll_orders(&authed_client(&second), &emitter, &mut previous).await.unwrap(), 2);
+
+        let events = emitter.order_events.lock().unwrap().clone();
+        assert_eq!(
+            summary(&events),
+            vec![
+                ("0xcancelled".to_string(), OrderEventKind::Cancelled, 0.0),
+                ("0xfilled".to_string(), OrderEventKind::Filled, 15.0),
+            ]
+        );
+        assert!(previous.unwrap().is_empty());
+
+        // Only the fill produces an order_fill; the cancel does not
+        let fills = emitter.fills.lock().unwrap().clone();
+        assert_eq!(fills.len(), 1);
+        assert_eq!((fills[0].order_id.as_str(), fills[0].fill_size), ("0xfilled", 15.0));
+        assert_eq!((fills[0].fill_price, fills[0].remaining_size), (0.5, 0.0));
+    }
+
+    #[test]
+    fn test_order_event_fill() {
+        let event = OrderEvent {
+            kind: OrderEventKind::PartiallyFilled,
+            order: open_order("0xo", "100", "35", "LIVE"),
+            newly_matched: 25.0,
+        };
+        assert_eq!(
+            event.fill(1734000000),
+            Some(OrderFillEvent {
+                order_id: "0xo".to_string(),
+                fill_size: 25.0,
+                fill_price: 0.5,
+                remaining_size: 65.0,
+                timestamp: 1734000000,
+            })
+        );
+
+        let cancel = OrderEvent { kind: OrderEventKind::Cancelled, newly_matched: 0.0, ..event };
+        assert!(cancel.fill(1734000000).is_none());
+    }
+
+    // ==================== Resolution Watcher Tests ====================
+
+    fn gamma_market_json(condition_id: &str, closed: bool, prices: &str) -> String {
+        format!(
+            r#"{{"id": "1", "conditionId": "{}", "question": "Q?", "active": true, "closed": {},
+                "outcomes": "[\"Yes\", \"No\"]", "outcomePrices": "{}",
+                "clobTokenIds": "[\"yes-token\", \"no-token\"]"}}"#,
+            condition_id, closed, prices
+        )
+    }
+
+    #[tokio::test]
+    async fn test_market_flipping_to_closed_emits_resolved_once() {
+        let open = format!("[{}]", gamma_market_json("0xabc", false, r#"[\"0.97\", \"0.03\"]"#));
+        let settling = format!("[{}]", gamma_market_json("0xabc", true, r#"[\"0.97\", \"0.03\"]"#));
+        let resolved = format!("[{}]", gamma_market_json("0xabc", true, r#"[\"1\", \"0\"]"#));
+        let servers = [
+            MockServer::start(vec![route("GET /markets?condition_ids=", 200, &open)]).await,
+            MockServer::start(vec![route("GET /markets?condition_ids=", 200, &settling)]).await,
+            MockServer::start(vec![route("GET /markets?condition_ids=", 200, &resolved)]).await,
+        ];
+        let client = |server: &MockServer| GammaClient::new().with_base_url(&server.url);
+        let emitter = WatcherEmitter::default();
+        let mut pending = vec!["0xabc".to_string()];
+
+        // Still trading, then closed without a settled winner - nothing yet
+        assert_eq!(poll_resolutions(&client(&servers[0]), &emitter, &mut pending).await.unwrap(), 0);
+        assert_eq!(poll_resolutions(&client(&servers[1]), &emitter, &mut pending).await.unwrap(), 0);
+        assert_eq!(pending, vec!["0xabc"]);
+
+        // Settled: reported with the winning token, then no longer polled
+        assert_eq!(poll_resolutions(&client(&servers[2]), &emitter, &mut pending).await.unwrap(), 1);
+        assert!(pending.is_empty());
+        assert_eq!(poll_resolutions(&client(&servers[2]), &emitter, &mut pending).await.unwrap(), 0);
+        assert_eq!(servers[2].requests().len(), 1);
+
+        assert_eq!(
+            *emitter.resolutions.lock().unwrap(),
+            vec![("0xabc".to_string(), "yes-token".to_string())]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_resolution_poll_error_keeps_markets_pending() {
+        let server = MockServer::start(vec![route("GET /markets?condition_ids=", 500, "oops")]).await;
+        let emitter = WatcherEmitter::default();
+        let mut pending = vec!["0xabc".to_string()];
+
+        let client = GammaClient::new().with_base_url(&server.url);
+        assert!(poll_resolutions(&client, &emitter, &mut pending).await.is_err());
+        assert_eq!(pending, vec!["0xabc"]);
+        assert!(emitter.resolutions.lock().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_resolution_watcher_stops_once_all_resolved() {
+        let resolved = format!("[{}]", gamma_market_json("0xabc", true, r#"[\"0\", \"1\"]"#));
+        let server = MockServer::start(vec![route("GET /markets?condition_ids=", 200, &resolved)]).await;
+        let emitter = Arc::new(WatcherEmitter::default());
+        let watcher = crate::api::ResolutionWatcher::new();
+
+        let client = GammaClient::new().with_base_url(&server.url);
+        watcher.start(client, vec!["0xabc".to_string()], emitter.clone(), std::time::Duration::from_millis(10));
+        tokio::time::sleep(std::time::Duration::from_millis(100)).await;
+
+        assert!(!watcher.is_running());
+        assert_eq!(server.requests().len(), 1);
+        assert_eq!(
+            *emitter.resolutions.lock().unwrap(),
+            vec![("0xabc".to_string(), "no-token".to_string())]
+        );
+    }
+
+    // ==================== Compression Tests ====================
+
+    /// Serve `body` gzip-encoded to a client that advertises gzip; 406 otherwise
+    #[cfg(feature = "compression")]
+    async fn start_gzip_server(body: &'static str) -> String {
+        use flate2::{write::GzEncoder, Compression};
+        use std::io::Write;
+
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let mut buf = vec![0u8; 4096];
+                let n = socket.read(&mut buf).await.unwrap_or(0);
+                let head = String::from_utf8_lossy(&buf[..n]).to_lowercase();
+
+                let response = if head.contains("accept-encoding:") && head.contains("gzip") {
+                    let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                    encoder.write_all(body.as_bytes()).unwrap();
+                    let gz = encoder.finish().unwrap();
+                    let mut response = format!(
+                        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Encoding: gzip\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                        gz.len()
+                    )
+                    .into_bytes();
+                    response.extend_from_slice(&gz);
+                    response
+                } else {
+                    b"HTTP/1.1 406 Not Acceptable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n".to_vec()
+                };
+                let _ = socket.write_all(&response).await;
+            }
+        });
+
+        url
+    }
+
+    #[cfg(feature = "compression")]
+    #[tokio::test]
+    async fn test_gzip_response_is_decoded() {
+        let url = start_gzip_server(r#"{"mid": "0.55"}"#).await;
+
+        let midpoint = ClobClient::new().with_base_url(&url).get_midpoint("111").await.unwrap();
+        assert!((midpoint - 0.55).abs() < 1e-9);
+    }
+
+    // ==================== Enrichment Tests ====================
+
+    fn position(asset: &str, condition_id: &str, title: &str, outcome: &str) -> Position {
+        Position {
+            asset: asset.to_string(),
+            condition_id: condition_id.to_string(),
+            size: 10.0,
+            avg_price: 0.5,
+            initial_value: 5.0,
+            current_value: 6.0,
+            cash_pnl: 1.0,
+            percent_pnl: 20.0,
+            cur_price: 0.6,
+            title: title.to_string(),
+            outcome: outcome.to_string(),
+            proxy_wallet: String::new(),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_enrich_positions_fills_missing_titles() {
+        let gamma_body = r#"[{
+            "id": "516710",
+            "conditionId": "0xaaa",
+            "question": "Will it rain tomorrow?",
+            "outcomes": "[\"Yes\",\"No\"]",
+            "outcomePrices": "[\"0.6\",\"0.4\"]",
+            "clobTokenIds": "[\"111\",\"222\"]"
+        }]"#;
+        let server = MockServer::start(vec![route("GET /markets?condition_ids=", 200, gamma_body)]).await;
+        let client = GammaClient::new().with_base_url(&server.url);
+
+        let positions = vec![
+            position("222", "0xAAA", "", ""),
+            position("999", "0xbbb", "Already titled", "Yes"),
+        ];
+
+        let enriched = client.enrich_positions(positions, 4).await;
+
+        assert_eq!(enriched[0].title, "Will it rain tomorrow?");
+        assert_eq!(enriched[0].outcome, "No");
+        assert_eq!(enriched[1].title, "Already titled");
+        // Only the position missing data triggers a lookup
+        assert_eq!(server.requests(), vec!["GET /markets?condition_ids=0xAAA"]);
+        assert_eq!(client.cached_gamma_id("0xaaa").as_deref(), Some("516710"));
+    }
+
+    #[tokio::test]
+    async fn test_enrich_positions_keeps_positions_on_gamma_error() {
+        let server = MockServer::start(vec![route("GET /markets", 500, r#"{"error":"db unavailable"}"#)]).await;
+        let client = GammaClient::new().with_base_url(&server.url);
+
+        let enriched = client.enrich_positions(vec![position("1", "0xccc", "", "")], 4).await;
+
+        assert_eq!(enriched.len(), 1);
+        assert!(enriched[0].title.is_empty());
+    }
+
+    // ==================== Event Tests ====================
+
+    const EVENT_BODY: &str = r#"{
+        "id": "903",
+        "slug": "us-election",
+        "title": "US Election",
+        "startDate": "2024-01-01T00:00:00Z",
+        "volume": 1500.5,
+        "markets": [
+            {
+                "id": "516710",
+                "conditionId": "0xaaa",
+                "question": "Will A win?",
+                "outcomes": "[\"Yes\",\"No\"]",
+                "outcomePrices": "[\"0.6\",\"0.4\"]",
+                "clobTokenIds": "[\"111\",\"222\"]"
+            },
+            {"id": "516711", "note": "incomplete market is skipped"}
+        ]
+    }"#;
+
+    #[tokio::test]
+    async fn test_get_event_by_slug_resolves_nested_markets() {
+        let server = MockServer::start(vec![route("GET /events/slug/us-election", 200, EVENT_BODY)]).await;
+        let client = GammaClient::new().with_base_url(&server.url);
+
+        let event = client.get_event("us-election").await.unwrap();
+
+        assert_eq!(event.id, "903");
+        assert_eq!(event.start_date.as_deref(), Some("2024-01-01T00:00:00Z"));
+        assert_eq!(event.total_volume, 1500.5);
+        assert_eq!(event.markets.len(), 1);
+        assert_eq!(event.markets[0].condition_id, "0xaaa");
+        assert_eq!(event.markets[0].tokens[1].token_id, "222");
+    }
+
+    #[tokio::test]
+    async fn test_get_event_by_numeric_id() {
+        let server = MockServer::start(vec![route("GET /events/903", 200, EVENT_BODY)]).await;
+        let client = GammaClient::new().with_base_url(&server.url);
+
+        let event = client.get_event("903").await.unwrap();
+        assert_eq!(event.slug, "us-election");
+
+        let err = client.get_event("missing-slug").await.unwrap_err();
+        assert!(err.to_string().contains("Event not found"));
+    }
+
+    // ==================== Category Tests ====================
+
+    #[tokio::test]
+    async fn test_get_market_categories_is_cached() {
+        let body = r#"[
+            {"id": "sports", "label": "Sports", "marketCount": 120},
+            {"id": "politics", "label": "Politics"}
+        ]"#;
+        let server = MockServer::start(vec![route("GET /categories", 200, body)]).await;
+        let client = GammaClient::new().with_base_url(&server.url);
+
+        let categories = client.get_market_categories().await.unwrap();
+        assert_eq!(categories.len(), 2);
+        assert_eq!(categories[0].id, "sports");
+        assert_eq!(categories[0].label, "Sports");
+        assert_eq!(categories[0].market_count, Some(120));
+        assert_eq!(categories[1].market_count, None);
+
+        // Second call (also via a clone) is served from the cache
+        let again = client.clone().get_market_categories().await.unwrap();
+        assert_eq!(again.len(), 2);
+        assert_eq!(server.requests(), vec!["GET /categories"]);
     }
 
     // ==================== Price History Tests ====================
@@ -226,6 +1930,66 @@ mod tests {
         assert!((point.p - 0.65).abs() < 0.001);
     }
 
+    #[test]
+    fn test_price_history_windows_tile_range() {
+        use crate::api::price_history_windows;
+
+        let start = 1_704_067_200;
+        let end = start + 10 * 86_400 + 123;
+        let windows = price_history_windows(start, end, 86_400);
+
+        assert_eq!(windows.first().unwrap().0, start);
+        assert_eq!(windows.last().unwrap().1, end);
+        assert_eq!(windows.len(), 11);
+
+        for (window_start, window_end) in &windows {
+            assert!(window_start <= window_end);
+            assert!(window_end - window_start < 86_400);
+        }
+        // No gaps and no overlaps between consecutive windows
+        for pair in windows.windows(2) {
+            assert_eq!(pair[1].0, pair[0].1 + 1);
+        }
+    }
+
+    #[test]
+    fn test_recommended_fidelity_mappings() {
+        use crate::api::recommended_fidelity;
+
+        assert_eq!(recommended_fidelity("1h"), 1);
+        assert_eq!(recommended_fidelity("6h"), 5);
+        assert_eq!(recommended_fidelity("1d"), 60);
+        assert_eq!(recommended_fidelity("1w"), 360);
+        assert_eq!(recommended_fidelity("max"), 1440);
+        assert_eq!(recommended_fidelity("unknown"), 1440);
+    }
+
+    #[test]
+    fn test_recommended_fidelity_bounds_point_count() {
+        use crate::api::recommended_fidelity;
+
+        // (interval, span in minutes); "max" sized as a long-running three-year market
+        let spans = [("1h", 60), ("6h", 360), ("1d", 1_440), ("1w", 10_080), ("max", 3 * 525_600)];
+        for (interval, minutes) in spans {
+            let points = minutes / recommended_fidelity(interval);
+            assert!(points <= 1_100, "{interval} yields {points} points");
+            assert!(points >= 24, "{interval} yields only {points} points");
+        }
+    }
+
+    #[test]
+    fn test_price_history_windows_edge_cases() {
+        use crate::api::price_history_windows;
+
+        // Single-second range
+        assert_eq!(price_history_windows(100, 100, 60), vec![(100, 100)]);
+        // Range that divides evenly
+        assert_eq!(price_history_windows(0, 119, 60), vec![(0, 59), (60, 119)]);
+        // Empty or invalid inputs
+        assert!(price_history_windows(200, 100, 60).is_empty());
+        assert!(price_history_windows(0, 100, 0).is_empty());
+    }
+
     #[test]
     fn test_price_history_response_deserialization() {
         use crate::types::PriceHistoryResponse;
@@ -0,0 +1,40 @@
+// AIDEV-NOTE: Auth timestamps - pluggable clock plus an optional server offset
+// Polymarket rejects L1/L2 auth whose timestamp is too far from server time,
+// so skewed machines can opt into correcting by the CLOB /time offset.
+// Tests inject FixedClock so signed headers are reproducible.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// Source of wall-clock time for auth timestamps
+pub trait Clock: Send + Sync {
+    /// Current Unix time in seconds
+    fn now_unix(&self) -> u64;
+}
+
+/// Reads the system clock (the default)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SystemClock;
+
+impl Clock for SystemClock {
+    fn now_unix(&self) -> u64 {
+        SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs())
+            .unwrap_or(0)
+    }
+}
+
+/// Always returns the same Unix time - for deterministic signatures in tests
+#[derive(Debug, Clone, Copy)]
+pub struct FixedClock(pub u64);
+
+impl Clock for FixedClock {
+    fn now_unix(&self) -> u64 {
+        self.0
+    }
+}
+
+/// Current Unix time in seconds from `clock`, shifted by `offset_secs` (server - local)
+pub(crate) fn unix_timestamp(clock: &dyn Clock, offset_secs: i64) -> u64 {
+    clock.now_unix().saturating_add_signed(offset_secs)
+}
@@ -1,7 +1,11 @@
 // AIDEV-NOTE: API credentials for Polymarket authentication
 
+use base64::engine::general_purpose::{STANDARD, STANDARD_NO_PAD, URL_SAFE, URL_SAFE_NO_PAD};
+use base64::Engine;
 use serde::{Deserialize, Serialize};
 
+use crate::error::ApiError;
+
 /// API credentials returned from Polymarket auth endpoint
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -16,6 +20,50 @@ pub struct ApiCredentials {
     pub address: String,
 }
 
+impl ApiCredentials {
+    /// Quick structural check - see `validate` for the reason on failure
+    pub fn is_valid(&self) -> bool {
+        self.validate().is_ok()
+    }
+
+    /// Check the fields are well-formed before making API calls
+    /// AIDEV-NOTE: Structural only - says nothing about whether the server accepts them
+    pub fn validate(&self) -> Result<(), ApiError> {
+        if self.api_key.trim().is_empty() {
+            return Err(ApiError::Auth("Invalid credentials: api_key is empty".to_string()));
+        }
+        if let Err(e) = decode_secret(&self.api_secret) {
+            return Err(ApiError::Auth(format!("Invalid credentials: api_secret is not base64: {}", e)));
+        }
+        if self.api_passphrase.trim().is_empty() {
+            return Err(ApiError::Auth("Invalid credentials: api_passphrase is empty".to_string()));
+        }
+        if !is_address(&self.address) {
+            return Err(ApiError::Auth(format!(
+                "Invalid credentials: address {:?} is not 0x followed by 40 hex characters",
+                self.address
+            )));
+        }
+        Ok(())
+    }
+}
+
+/// Decode an API secret - Polymarket may use standard or URL-safe base64, with or without padding
+pub(crate) fn decode_secret(secret: &str) -> Result<Vec<u8>, base64::DecodeError> {
+    URL_SAFE_NO_PAD
+        .decode(secret)
+        .or_else(|_| URL_SAFE.decode(secret))
+        .or_else(|_| STANDARD.decode(secret))
+        .or_else(|_| STANDARD_NO_PAD.decode(secret))
+}
+
+/// `^0x[0-9a-fA-F]{40}$`
+fn is_address(address: &str) -> bool {
+    address
+        .strip_prefix("0x")
+        .is_some_and(|hex| hex.len() == 40 && hex.bytes().all(|b| b.is_ascii_hexdigit()))
+}
+
 /// Current authentication status
 #[derive(Debug, Clone, Default, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -45,6 +93,42 @@ mod tests {
         assert!(json.contains("\"apiKey\":\"test-key\""));
     }
 
+    fn valid_credentials() -> ApiCredentials {
+        ApiCredentials {
+            api_key: "test-key".to_string(),
+            api_secret: "dGVzdF9zZWNyZXQ=".to_string(),
+            api_passphrase: "test-pass".to_string(),
+            address: "0x1234567890abcdefABCDEF1234567890abcdef12".to_string(),
+        }
+    }
+
+    #[test]
+    fn test_credentials_validate() {
+        assert!(valid_credentials().is_valid());
+
+        let mut creds = valid_credentials();
+        creds.api_key = String::new();
+        assert!(creds.validate().unwrap_err().to_string().contains("api_key"));
+
+        let mut creds = valid_credentials();
+        creds.api_secret = "not base64!".to_string();
+        assert!(creds.validate().unwrap_err().to_string().contains("api_secret"));
+
+        let mut creds = valid_credentials();
+        creds.api_passphrase = " ".to_string();
+        assert!(creds.validate().unwrap_err().to_string().contains("api_passphrase"));
+    }
+
+    #[test]
+    fn test_credentials_malformed_address() {
+        for address in ["0x1234", "1234567890abcdefABCDEF1234567890abcdef1234", "0x1234567890abcdefABCDEF1234567890abcdefzz", ""] {
+            let creds = ApiCredentials { address: address.to_string(), ..valid_credentials() };
+            let err = creds.validate().unwrap_err();
+            assert!(matches!(&err, ApiError::Auth(msg) if msg.contains("address")), "{}: {:?}", address, err);
+            assert!(!creds.is_valid());
+        }
+    }
+
     #[test]
     fn test_auth_status_default() {
         let status = AuthStatus::default();
@@ -5,8 +5,11 @@
 use alloy_primitives::{keccak256, Address, U256};
 use alloy_signer::Signer;
 use alloy_signer_local::PrivateKeySigner;
-use std::str::FromStr;
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+use zeroize::Zeroizing;
 
+use crate::auth::clock::{unix_timestamp, Clock, SystemClock};
 use crate::error::ApiError;
 
 // Polymarket uses a specific EIP-712 domain
@@ -15,24 +18,93 @@ const POLYMARKET_DOMAIN_VERSION: &str = "1";
 const POLYMARKET_CHAIN_ID: u64 = 137; // Polygon mainnet
 const AUTH_MESSAGE: &str = "This message attests that I control the given wallet";
 
+/// BIP-44 path of the first MetaMask account (what Polymarket users normally sign with)
+#[cfg(feature = "mnemonic")]
+pub const DEFAULT_DERIVATION_PATH: &str = "m/44'/60'/0'/0/0";
+
+/// Parse a hex private key (optional 0x prefix, surrounding whitespace ignored)
+/// AIDEV-NOTE: Shared by PolymarketSigner and OrderSigner. Checks the shape up front -
+/// alloy's own errors are opaque and it accepts keys that aren't exactly 32 bytes.
+pub(crate) fn parse_private_key(private_key: &str) -> Result<PrivateKeySigner, ApiError> {
+    let trimmed = private_key.trim();
+    let key_hex = trimmed.strip_prefix("0x").unwrap_or(trimmed);
+
+    if key_hex.is_empty() {
+        return Err(ApiError::Signing("Private key is empty".to_string()));
+    }
+    if key_hex.len() != 64 {
+        return Err(ApiError::Signing(format!(
+            "Invalid private key: expected 64 hex characters, got {}",
+            key_hex.len()
+        )));
+    }
+    if !key_hex.chars().all(|c| c.is_ascii_hexdigit()) {
+        return Err(ApiError::Signing(
+            "Invalid private key: contains non-hex characters".to_string(),
+        ));
+    }
+
+    // Decoded bytes are wiped on drop; alloy's signing key zeroizes its own copy
+    let bytes = Zeroizing::new(
+        hex::decode(key_hex).map_err(|e| ApiError::Signing(format!("Invalid private key: {}", e)))?,
+    );
+    PrivateKeySigner::from_slice(&bytes)
+        .map_err(|e| ApiError::Signing(format!("Invalid private key: {}", e)))
+}
+
 /// Polymarket signer for authentication and order signing
 pub struct PolymarketSigner {
     signer: PrivateKeySigner,
     address: Address,
+    /// Seconds added to the local clock for L1 timestamps
+    time_offset: AtomicI64,
+    /// Time source for L1 timestamps
+    clock: Arc<dyn Clock>,
 }
 
 impl PolymarketSigner {
     /// Create a new signer from a private key hex string
     pub fn from_private_key(private_key: &str) -> Result<Self, ApiError> {
-        // Remove 0x prefix if present
-        let key_str = private_key.strip_prefix("0x").unwrap_or(private_key);
+        Ok(Self::from_signer(parse_private_key(private_key)?))
+    }
 
-        let signer = PrivateKeySigner::from_str(key_str)
-            .map_err(|e| ApiError::Signing(format!("Invalid private key: {}", e)))?;
+    /// Create a signer from a BIP-39 seed phrase (English wordlist)
+    /// An empty `derivation_path` means `DEFAULT_DERIVATION_PATH`.
+    /// AIDEV-NOTE: The phrase is only used to derive the key and is never kept.
+    /// Callers must not persist it either - store derived API credentials instead.
+    #[cfg(feature = "mnemonic")]
+    pub fn from_mnemonic(mnemonic: &str, derivation_path: &str) -> Result<Self, ApiError> {
+        use alloy_signer_local::{coins_bip39::English, MnemonicBuilder};
+
+        let path = match derivation_path.trim() {
+            "" => DEFAULT_DERIVATION_PATH,
+            path => path,
+        };
+
+        let signer = MnemonicBuilder::<English>::default()
+            .phrase(mnemonic.trim())
+            .derivation_path(path)
+            .map_err(|e| ApiError::Signing(format!("Invalid derivation path '{}': {}", path, e)))?
+            .build()
+            .map_err(|_| ApiError::Signing("Invalid mnemonic".to_string()))?;
+
+        Ok(Self::from_signer(signer))
+    }
 
+    fn from_signer(signer: PrivateKeySigner) -> Self {
         let address = signer.address();
+        Self { signer, address, time_offset: AtomicI64::new(0), clock: Arc::new(SystemClock) }
+    }
+
+    /// Use `clock` instead of the system clock (e.g. `FixedClock` for golden tests)
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
 
-        Ok(Self { signer, address })
+    /// Shift L1 timestamps by `offset_secs` (server time - local time)
+    pub fn set_time_offset(&self, offset_secs: i64) {
+        self.time_offset.store(offset_secs, Ordering::Relaxed);
     }
 
     /// Get the wallet address
@@ -92,10 +164,7 @@ impl PolymarketSigner {
     /// Sign authentication message for API key derivation
     /// Returns L1 headers needed for the API request
     pub async fn create_l1_headers(&self, nonce: u64) -> Result<L1Headers, ApiError> {
-        let timestamp = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs();
+        let timestamp = unix_timestamp(self.clock.as_ref(), self.time_offset.load(Ordering::Relaxed));
 
         let timestamp_str = timestamp.to_string();
 
@@ -2,10 +2,16 @@
 // Generates L2 authentication headers for authenticated requests
 // AIDEV-NOTE: API secret uses URL-safe base64 encoding (_- instead of +/)
 
-use base64::{engine::general_purpose::{STANDARD as BASE64, URL_SAFE_NO_PAD}, Engine};
+use std::sync::atomic::{AtomicI64, Ordering};
+use std::sync::Arc;
+
+use base64::Engine;
 use hmac::{Hmac, Mac};
 use sha2::Sha256;
+use zeroize::Zeroizing;
 
+use crate::auth::clock::{unix_timestamp, Clock, SystemClock};
+use crate::auth::credentials::decode_secret;
 use crate::auth::ApiCredentials;
 use crate::error::ApiError;
 
@@ -15,9 +21,14 @@ type HmacSha256 = Hmac<Sha256>;
 #[derive(Clone)]
 pub struct HmacAuth {
     api_key: String,
-    api_secret: String,
+    /// AIDEV-NOTE: Wiped on drop - never log it (length only)
+    api_secret: Zeroizing<String>,
     api_passphrase: String,
     address: String,
+    /// Seconds added to the local clock for POLY_TIMESTAMP (shared across clones)
+    time_offset: Arc<AtomicI64>,
+    /// Time source for POLY_TIMESTAMP
+    clock: Arc<dyn Clock>,
 }
 
 impl HmacAuth {
@@ -25,12 +36,30 @@ impl HmacAuth {
     pub fn new(credentials: &ApiCredentials) -> Self {
         Self {
             api_key: credentials.api_key.clone(),
-            api_secret: credentials.api_secret.clone(),
+            api_secret: Zeroizing::new(credentials.api_secret.clone()),
             api_passphrase: credentials.api_passphrase.clone(),
             address: credentials.address.clone(),
+            time_offset: Arc::new(AtomicI64::new(0)),
+            clock: Arc::new(SystemClock),
         }
     }
 
+    /// Use `clock` instead of the system clock (e.g. `FixedClock` for golden tests)
+    pub fn with_clock(mut self, clock: Arc<dyn Clock>) -> Self {
+        self.clock = clock;
+        self
+    }
+
+    /// Shift generated timestamps by `offset_secs` (server time - local time)
+    pub fn set_time_offset(&self, offset_secs: i64) {
+        self.time_offset.store(offset_secs, Ordering::Relaxed);
+    }
+
+    /// Current timestamp offset in seconds
+    pub fn time_offset(&self) -> i64 {
+        self.time_offset.load(Ordering::Relaxed)
+    }
+
     /// Generate authentication headers for a request
     ///
     /// Returns a tuple of headers: (api_key, signature, timestamp, passphrase)
@@ -40,11 +69,7 @@ impl HmacAuth {
         path: &str,
         body: Option<&str>,
     ) -> Result<AuthHeaders, ApiError> {
-        let timestamp = std::time::SystemTime::now()
-            .duration_since(std::time::UNIX_EPOCH)
-            .unwrap()
-            .as_secs()
-            .to_string();
+        let timestamp = unix_timestamp(self.clock.as_ref(), self.time_offset()).to_string();
 
         let signature = self.sign(&timestamp, method, path, body)?;
 
@@ -75,13 +100,9 @@ impl HmacAuth {
         path: &str,
         body: Option<&str>,
     ) -> Result<String, ApiError> {
-        // Decode the base64-encoded secret - try multiple formats
-        // Polymarket may use standard or URL-safe base64, with or without padding
-        let secret_bytes = URL_SAFE_NO_PAD
-            .decode(&self.api_secret)
-            .or_else(|_| base64::engine::general_purpose::URL_SAFE.decode(&self.api_secret))
-            .or_else(|_| BASE64.decode(&self.api_secret))
-            .or_else(|_| base64::engine::general_purpose::STANDARD_NO_PAD.decode(&self.api_secret))
+        // Decode the base64-encoded secret - accepts any padding/alphabet Polymarket uses
+        let secret_bytes = decode_secret(&self.api_secret)
+            .map(Zeroizing::new)
             .map_err(|e| {
                 tracing::error!("Failed to decode secret (len={}): {}", self.api_secret.len(), e);
                 ApiError::Auth(format!("Invalid API secret: {}", e))
@@ -178,4 +199,27 @@ mod tests {
 
         assert!(headers.is_ok());
     }
+
+    #[test]
+    fn test_api_secret_is_zeroizing() {
+        use zeroize::Zeroize;
+
+        let credentials = ApiCredentials {
+            api_key: "test-key".to_string(),
+            api_secret: "dGVzdC1zZWNyZXQ=".to_string(),
+            api_passphrase: "test-pass".to_string(),
+            address: "0x1234".to_string(),
+        };
+        let auth = HmacAuth::new(&credentials);
+
+        // Fails to compile if the field stops being wiped on drop
+        let secret: &Zeroizing<String> = &auth.api_secret;
+        assert_eq!(secret.as_str(), "dGVzdC1zZWNyZXQ=");
+
+        // Each clone owns (and wipes) its own copy
+        let mut clone = auth.clone();
+        clone.api_secret.zeroize();
+        assert!(clone.api_secret.is_empty());
+        assert_eq!(auth.api_secret.as_str(), "dGVzdC1zZWNyZXQ=");
+    }
 }
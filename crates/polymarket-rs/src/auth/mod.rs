@@ -1,6 +1,7 @@
 // AIDEV-NOTE: Auth module for Polymarket authentication (EIP-712, HMAC)
 // NOTE: keyring module stays in src-tauri (OS-specific credential storage)
 
+mod clock;
 mod credentials;
 mod eip712;
 mod hmac;
@@ -9,7 +10,11 @@ mod order_eip712;
 #[cfg(test)]
 mod tests;
 
+pub use clock::{Clock, FixedClock, SystemClock};
 pub use credentials::{ApiCredentials, AuthStatus};
 pub use eip712::{L1Headers, PolymarketSigner};
+#[cfg(feature = "mnemonic")]
+pub use eip712::DEFAULT_DERIVATION_PATH;
 pub use hmac::{AuthHeaders, HmacAuth};
 pub use order_eip712::OrderSigner;
+pub(crate) use order_eip712::{order_digest, order_struct_hash};
@@ -2,12 +2,13 @@
 // This uses a DIFFERENT domain than ClobAuth (which is for API key derivation)
 // Domain: name="Polymarket CTF Exchange", version="1", chainId=137, verifyingContract=0x4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E
 
-use alloy_primitives::{keccak256, Address, U256};
+use alloy_primitives::{keccak256, Address, B256, U256};
 use alloy_signer::Signer;
 use alloy_signer_local::PrivateKeySigner;
 use std::str::FromStr;
 
 use crate::api::order::{SignedOrder, UnsignedOrder};
+use crate::auth::eip712::parse_private_key;
 use crate::error::ApiError;
 
 // CTF Exchange domain constants (different from ClobAuth!)
@@ -29,11 +30,7 @@ pub struct OrderSigner {
 impl OrderSigner {
     /// Create a new order signer from a private key hex string
     pub fn from_private_key(private_key: &str) -> Result<Self, ApiError> {
-        let key_str = private_key.strip_prefix("0x").unwrap_or(private_key);
-
-        let signer = PrivateKeySigner::from_str(key_str)
-            .map_err(|e| ApiError::Signing(format!("Invalid private key: {}", e)))?;
-
+        let signer = parse_private_key(private_key)?;
         let address = signer.address();
 
         Ok(Self { signer, address })
@@ -51,16 +48,7 @@ impl OrderSigner {
 
     /// Sign an order using EIP-712 for CTF Exchange
     pub async fn sign_order(&self, order: &UnsignedOrder) -> Result<SignedOrder, ApiError> {
-        let domain_separator = self.build_domain_separator()?;
-        let struct_hash = self.build_order_struct_hash(order)?;
-
-        // EIP-712: \x19\x01 + domainSeparator + structHash
-        let mut message = Vec::with_capacity(66);
-        message.extend_from_slice(&[0x19, 0x01]);
-        message.extend_from_slice(&domain_separator);
-        message.extend_from_slice(&struct_hash);
-
-        let digest = keccak256(&message);
+        let digest = B256::from(order_digest(order)?);
 
         tracing::debug!("Order EIP-712 digest: 0x{}", hex::encode(digest));
 
@@ -83,99 +71,110 @@ impl OrderSigner {
             signature: sig_hex,
         })
     }
+}
 
-    /// Build EIP-712 domain separator for CTF Exchange
-    /// AIDEV-NOTE: This includes verifyingContract, unlike ClobAuth domain
-    fn build_domain_separator(&self) -> Result<[u8; 32], ApiError> {
-        // Domain type includes verifyingContract
-        let domain_type_hash = keccak256(
-            "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
-        );
-
-        let name_hash = keccak256(CTF_EXCHANGE_NAME);
-        let version_hash = keccak256(CTF_EXCHANGE_VERSION);
-
-        // Parse the verifying contract address
-        let contract_addr = Address::from_str(CTF_VERIFYING_CONTRACT)
-            .map_err(|e| ApiError::Signing(format!("Invalid contract address: {}", e)))?;
+/// Build EIP-712 domain separator for CTF Exchange
+/// AIDEV-NOTE: This includes verifyingContract, unlike ClobAuth domain
+pub(crate) fn domain_separator() -> Result<[u8; 32], ApiError> {
+    // Domain type includes verifyingContract
+    let domain_type_hash = keccak256(
+        "EIP712Domain(string name,string version,uint256 chainId,address verifyingContract)"
+    );
+
+    let name_hash = keccak256(CTF_EXCHANGE_NAME);
+    let version_hash = keccak256(CTF_EXCHANGE_VERSION);
+
+    // Parse the verifying contract address
+    let contract_addr = Address::from_str(CTF_VERIFYING_CONTRACT)
+        .map_err(|e| ApiError::Signing(format!("Invalid contract address: {}", e)))?;
+
+    // Encode: typeHash + nameHash + versionHash + chainId + verifyingContract
+    let mut encoded = Vec::with_capacity(160);
+    encoded.extend_from_slice(domain_type_hash.as_slice());
+    encoded.extend_from_slice(name_hash.as_slice());
+    encoded.extend_from_slice(version_hash.as_slice());
+    encoded.extend_from_slice(&U256::from(CTF_CHAIN_ID).to_be_bytes::<32>());
+    // Address is left-padded with zeros to 32 bytes
+    encoded.extend_from_slice(&[0u8; 12]);
+    encoded.extend_from_slice(contract_addr.as_slice());
+
+    Ok(*keccak256(&encoded))
+}
 
-        // Encode: typeHash + nameHash + versionHash + chainId + verifyingContract
-        let mut encoded = Vec::with_capacity(160);
-        encoded.extend_from_slice(domain_type_hash.as_slice());
-        encoded.extend_from_slice(name_hash.as_slice());
-        encoded.extend_from_slice(version_hash.as_slice());
-        encoded.extend_from_slice(&U256::from(CTF_CHAIN_ID).to_be_bytes::<32>());
-        // Address is left-padded with zeros to 32 bytes
-        encoded.extend_from_slice(&[0u8; 12]);
-        encoded.extend_from_slice(contract_addr.as_slice());
+/// Build EIP-712 struct hash for Order
+pub(crate) fn order_struct_hash(order: &UnsignedOrder) -> Result<[u8; 32], ApiError> {
+    let type_hash = keccak256(ORDER_TYPE_STRING);
 
-        Ok(*keccak256(&encoded))
-    }
+    // Parse all fields
+    let salt = parse_u256(&order.salt)?;
+    let maker = parse_address(&order.maker)?;
+    let signer = parse_address(&order.signer)?;
+    let taker = parse_address(&order.taker)?;
+    let token_id = parse_u256(&order.token_id)?;
+    let maker_amount = parse_u256(&order.maker_amount)?;
+    let taker_amount = parse_u256(&order.taker_amount)?;
+    let expiration = parse_u256(&order.expiration)?;
+    let nonce = parse_u256(&order.nonce)?;
+    let fee_rate_bps = parse_u256(&order.fee_rate_bps)?;
+    let side = U256::from(order.side.as_u8());
+    let sig_type = U256::from(order.signature_type.as_u8());
 
-    /// Build EIP-712 struct hash for Order
-    fn build_order_struct_hash(&self, order: &UnsignedOrder) -> Result<[u8; 32], ApiError> {
-        let type_hash = keccak256(ORDER_TYPE_STRING);
+    // Encode: typeHash + all 12 fields as 32 bytes each
+    // Total: 13 * 32 = 416 bytes
+    let mut encoded = Vec::with_capacity(416);
+    encoded.extend_from_slice(type_hash.as_slice());
 
-        // Parse all fields
-        let salt = parse_u256(&order.salt)?;
-        let maker = parse_address(&order.maker)?;
-        let signer = parse_address(&order.signer)?;
-        let taker = parse_address(&order.taker)?;
-        let token_id = parse_u256(&order.token_id)?;
-        let maker_amount = parse_u256(&order.maker_amount)?;
-        let taker_amount = parse_u256(&order.taker_amount)?;
-        let expiration = parse_u256(&order.expiration)?;
-        let nonce = parse_u256(&order.nonce)?;
-        let fee_rate_bps = parse_u256(&order.fee_rate_bps)?;
-        let side = U256::from(order.side.as_u8());
-        let sig_type = U256::from(order.signature_type.as_u8());
+    // uint256 salt
+    encoded.extend_from_slice(&salt.to_be_bytes::<32>());
 
-        // Encode: typeHash + all 12 fields as 32 bytes each
-        // Total: 13 * 32 = 416 bytes
-        let mut encoded = Vec::with_capacity(416);
-        encoded.extend_from_slice(type_hash.as_slice());
+    // address maker (left-padded to 32 bytes)
+    encoded.extend_from_slice(&[0u8; 12]);
+    encoded.extend_from_slice(maker.as_slice());
 
-        // uint256 salt
-        encoded.extend_from_slice(&salt.to_be_bytes::<32>());
+    // address signer
+    encoded.extend_from_slice(&[0u8; 12]);
+    encoded.extend_from_slice(signer.as_slice());
 
-        // address maker (left-padded to 32 bytes)
-        encoded.extend_from_slice(&[0u8; 12]);
-        encoded.extend_from_slice(maker.as_slice());
+    // address taker
+    encoded.extend_from_slice(&[0u8; 12]);
+    encoded.extend_from_slice(taker.as_slice());
 
-        // address signer
-        encoded.extend_from_slice(&[0u8; 12]);
-        encoded.extend_from_slice(signer.as_slice());
+    // uint256 tokenId
+    encoded.extend_from_slice(&token_id.to_be_bytes::<32>());
 
-        // address taker
-        encoded.extend_from_slice(&[0u8; 12]);
-        encoded.extend_from_slice(taker.as_slice());
+    // uint256 makerAmount
+    encoded.extend_from_slice(&maker_amount.to_be_bytes::<32>());
 
-        // uint256 tokenId
-        encoded.extend_from_slice(&token_id.to_be_bytes::<32>());
+    // uint256 takerAmount
+    encoded.extend_from_slice(&taker_amount.to_be_bytes::<32>());
 
-        // uint256 makerAmount
-        encoded.extend_from_slice(&maker_amount.to_be_bytes::<32>());
+    // uint256 expiration
+    encoded.extend_from_slice(&expiration.to_be_bytes::<32>());
 
-        // uint256 takerAmount
-        encoded.extend_from_slice(&taker_amount.to_be_bytes::<32>());
+    // uint256 nonce
+    encoded.extend_from_slice(&nonce.to_be_bytes::<32>());
 
-        // uint256 expiration
-        encoded.extend_from_slice(&expiration.to_be_bytes::<32>());
+    // uint256 feeRateBps
+    encoded.extend_from_slice(&fee_rate_bps.to_be_bytes::<32>());
 
-        // uint256 nonce
-        encoded.extend_from_slice(&nonce.to_be_bytes::<32>());
+    // uint8 side (stored as uint256)
+    encoded.extend_from_slice(&side.to_be_bytes::<32>());
 
-        // uint256 feeRateBps
-        encoded.extend_from_slice(&fee_rate_bps.to_be_bytes::<32>());
+    // uint8 signatureType (stored as uint256)
+    encoded.extend_from_slice(&sig_type.to_be_bytes::<32>());
 
-        // uint8 side (stored as uint256)
-        encoded.extend_from_slice(&side.to_be_bytes::<32>());
+    Ok(*keccak256(&encoded))
+}
 
-        // uint8 signatureType (stored as uint256)
-        encoded.extend_from_slice(&sig_type.to_be_bytes::<32>());
+/// EIP-712 signing digest for an order: keccak256(\x19\x01 + domainSeparator + structHash)
+/// AIDEV-NOTE: This is the digest sign_order signs - its hex form is the CLOB order ID
+pub(crate) fn order_digest(order: &UnsignedOrder) -> Result<[u8; 32], ApiError> {
+    let mut message = Vec::with_capacity(66);
+    message.extend_from_slice(&[0x19, 0x01]);
+    message.extend_from_slice(&domain_separator()?);
+    message.extend_from_slice(&order_struct_hash(order)?);
 
-        Ok(*keccak256(&encoded))
-    }
+    Ok(*keccak256(&message))
 }
 
 /// Parse a string to U256, supporting both decimal and hex formats
@@ -233,6 +232,78 @@ mod tests {
         assert_eq!(signed_order.signature.len(), 132); // 0x + 65 bytes = 0x + 130 hex chars
     }
 
+    /// Snapshot of one signed order: regression guard for any change to order signing
+    /// AIDEV-NOTE: The expected signature is this code's own output (Anvil account #0, RFC 6979
+    /// deterministic), not a vector from py-clob-client or clob-order-utils - it catches
+    /// accidental changes, not a signing scheme that was wrong from the start.
+    #[tokio::test]
+    async fn test_eip712_order_signature_snapshot() {
+        let test_key = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        let maker = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
+        let signer = OrderSigner::from_private_key(test_key).unwrap();
+        assert_eq!(signer.address_string(), maker);
+
+        let order = UnsignedOrder {
+            salt: "479249096354".to_string(),
+            maker: maker.to_string(),
+            signer: maker.to_string(),
+            taker: "0x0000000000000000000000000000000000000000".to_string(),
+            token_id: "1234".to_string(),
+            maker_amount: "100000000".to_string(),
+            taker_amount: "50000000".to_string(),
+            expiration: "0".to_string(),
+            nonce: "0".to_string(),
+            fee_rate_bps: "100".to_string(),
+            side: OrderSide::Buy,
+            signature_type: SignatureType::Eoa,
+        };
+
+        let signed = signer.sign_order(&order).await.unwrap();
+
+        assert_eq!(
+            signed.signature,
+            "0x6435b08e46470f66939b330ab21e67f599857708cfbcc084d60738369f53b813\
+             595926e177af917b73378f6b3c6ee854ff18037a26f2869b98a483089f60bea71b"
+        );
+    }
+
+    #[tokio::test]
+    async fn test_compute_hash_matches_signed_digest() {
+        let test_key = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+        let signer = OrderSigner::from_private_key(test_key).unwrap();
+        let order = UnsignedOrder {
+            salt: "479249096354".to_string(),
+            maker: signer.address_string(),
+            signer: signer.address_string(),
+            taker: "0x0000000000000000000000000000000000000000".to_string(),
+            token_id: "1234".to_string(),
+            maker_amount: "100000000".to_string(),
+            taker_amount: "50000000".to_string(),
+            expiration: "0".to_string(),
+            nonce: "0".to_string(),
+            fee_rate_bps: "100".to_string(),
+            side: OrderSide::Buy,
+            signature_type: SignatureType::Eoa,
+        };
+        let signed = signer.sign_order(&order).await.unwrap();
+
+        let hash = signed.compute_hash().unwrap();
+        assert!(hash.starts_with("0x"));
+        assert_eq!(hash.len(), 66);
+
+        // The signature recovers to the maker only over the exact digest that was signed
+        let sig_bytes = hex::decode(signed.signature.trim_start_matches("0x")).unwrap();
+        let signature = alloy_primitives::PrimitiveSignature::try_from(sig_bytes.as_slice()).unwrap();
+        let digest = B256::from_str(&hash).unwrap();
+        assert_eq!(signature.recover_address_from_prehash(&digest).unwrap(), signer.address());
+
+        // Struct hash is the primitive: unchanged by signing, sensitive to order fields
+        assert_eq!(order.compute_struct_hash().unwrap(), signed.order.compute_struct_hash().unwrap());
+        let mut other = order.clone();
+        other.salt = "1".to_string();
+        assert_ne!(order.compute_struct_hash().unwrap(), other.compute_struct_hash().unwrap());
+    }
+
     #[test]
     fn test_parse_u256() {
         assert!(parse_u256("12345").is_ok());
@@ -2,7 +2,11 @@
 
 #[cfg(test)]
 mod tests {
-    use crate::auth::{ApiCredentials, AuthStatus, HmacAuth, OrderSigner, PolymarketSigner};
+    use std::sync::Arc;
+
+    use crate::auth::{
+        ApiCredentials, AuthStatus, FixedClock, HmacAuth, OrderSigner, PolymarketSigner,
+    };
 
     // ==================== Credentials Tests ====================
 
@@ -116,12 +120,161 @@ mod tests {
         assert!(!headers.timestamp.is_empty());
     }
 
+    fn now_secs() -> i64 {
+        std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .unwrap()
+            .as_secs() as i64
+    }
+
+    #[test]
+    fn test_hmac_time_offset_shifts_timestamp() {
+        let creds = ApiCredentials {
+            api_key: "test_key".to_string(),
+            api_secret: "c2VjcmV0".to_string(),
+            api_passphrase: "test_pass".to_string(),
+            address: "0x1234567890123456789012345678901234567890".to_string(),
+        };
+        let auth = HmacAuth::new(&creds);
+
+        auth.set_time_offset(3600);
+        let shifted: i64 = auth.generate_headers("GET", "/balance", None).unwrap().timestamp.parse().unwrap();
+        assert!((shifted - now_secs() - 3600).abs() <= 2, "timestamp {} not shifted", shifted);
+
+        // Offset is shared with clones (ClobClient clones its HmacAuth)
+        let clone = auth.clone();
+        auth.set_time_offset(-120);
+        assert_eq!(clone.time_offset(), -120);
+    }
+
+    /// Golden values computed independently with Python's hmac/hashlib:
+    /// urlsafe_b64encode(hmac_sha256(b64decode(secret), timestamp + method + path + body))
+    #[test]
+    fn test_hmac_headers_golden_with_fixed_clock() {
+        let creds = ApiCredentials {
+            api_key: "test-key".to_string(),
+            api_secret: "dGVzdC1zZWNyZXQ=".to_string(),
+            api_passphrase: "test-pass".to_string(),
+            address: "0x1234".to_string(),
+        };
+        let auth = HmacAuth::new(&creds).with_clock(Arc::new(FixedClock(1_700_000_000)));
+
+        let headers = auth.generate_headers("GET", "/orders", None).unwrap();
+        assert_eq!(headers.timestamp, "1700000000");
+        assert_eq!(headers.signature, "vur8_1VpPxeQF-2yohlrmygcvhUDnqtH1vRiczbD1NY=");
+
+        let headers = auth.generate_headers("POST", "/order", Some(r#"{"order":"test"}"#)).unwrap();
+        assert_eq!(headers.signature, "ADkdIfIZVdgb4YoGFXgB1qiJiqKhBMCOkrfQ4woecww=");
+
+        // Offset still applies on top of the injected clock
+        auth.set_time_offset(-100);
+        assert_eq!(auth.generate_headers("GET", "/orders", None).unwrap().timestamp, "1699999900");
+    }
+
+    /// (secret, method, path, body, expected signature) at timestamp 1700000000
+    /// AIDEV-NOTE: Computed with Python's hmac/hashlib + base64.urlsafe_b64encode. The secret
+    /// decodes to bytes 0xfa..0xff, 0x00..0x19 so every base64 alphabet variant is exercised.
+    const HMAC_VECTORS: &[(&str, &str, &str, Option<&str>, &str)] = &[
+        // URL-safe secret with padding, query string in path
+        (
+            "-vv8_f7_AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBk=",
+            "GET",
+            "/data/orders?market=0xabc",
+            None,
+            "4AgIHW2ARJ3iOfCvT0GKDWne1Ub8SDVnFw_R8PSBZDY=",
+        ),
+        // URL-safe secret without padding, request body
+        (
+            "-vv8_f7_AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBk",
+            "DELETE",
+            "/order",
+            Some(r#"{"orderID":"0x1"}"#),
+            "810A2RsTF8ds0MDqBHnZVJgDelGCrka0NMyX8scw7Y4=",
+        ),
+        // Standard-alphabet secret, lowercase method is uppercased before signing
+        (
+            "+vv8/f7/AAECAwQFBgcICQoLDA0ODxAREhMUFRYXGBk=",
+            "get",
+            "/data/trades",
+            None,
+            "aQnZY85vxtvLv2ZEKBnSRC5sc7GfOdQCoxGXb2MYgAU=",
+        ),
+        // Zero-byte secret, body with spaces
+        (
+            "AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=",
+            "test-sign",
+            "/orders",
+            Some(r#"{"hash": "0x123"}"#),
+            "y6M4lk29NMrQoR4F6SFtfABk1rCc2CksGypBbxchkQ8=",
+        ),
+    ];
+
+    #[test]
+    fn test_hmac_known_answer_vectors() {
+        for (secret, method, path, body, expected) in HMAC_VECTORS {
+            let creds = ApiCredentials {
+                api_key: "test-key".to_string(),
+                api_secret: secret.to_string(),
+                api_passphrase: "test-pass".to_string(),
+                address: "0x1234".to_string(),
+            };
+            let auth = HmacAuth::new(&creds).with_clock(Arc::new(FixedClock(1_700_000_000)));
+            let headers = auth.generate_headers(method, path, *body).unwrap();
+
+            assert_eq!(headers.timestamp, "1700000000");
+            assert_eq!(&headers.signature, expected, "{} {} with secret {}", method, path, secret);
+        }
+    }
+
+    #[test]
+    fn test_hmac_signature_is_padded_url_safe_base64() {
+        use base64::{engine::general_purpose::URL_SAFE, Engine};
+
+        for (_, _, _, _, expected) in HMAC_VECTORS {
+            // SHA-256 digest is 32 bytes -> 44 base64 chars ending in one '=' pad
+            assert_eq!(expected.len(), 44);
+            assert!(expected.ends_with('='));
+            assert!(!expected.contains('+') && !expected.contains('/'));
+            assert_eq!(URL_SAFE.decode(expected).unwrap().len(), 32);
+        }
+    }
+
     // ==================== EIP-712 Signer Tests ====================
 
     // Known test private key (Anvil's first account - DO NOT USE IN PRODUCTION)
     const TEST_PRIVATE_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
     const TEST_ADDRESS: &str = "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266";
 
+    #[tokio::test]
+    async fn test_polymarket_signer_time_offset_shifts_l1_timestamp() {
+        let signer = PolymarketSigner::from_private_key(TEST_PRIVATE_KEY).unwrap();
+        signer.set_time_offset(-300);
+
+        let headers = signer.create_l1_headers(0).await.unwrap();
+        let timestamp: i64 = headers.timestamp.parse().unwrap();
+        assert!((timestamp - now_secs() + 300).abs() <= 2, "timestamp {} not shifted", timestamp);
+    }
+
+    /// Regression guard: L1 signatures are deterministic (RFC 6979) once time is fixed
+    #[tokio::test]
+    async fn test_l1_headers_golden_with_fixed_clock() {
+        let signer = PolymarketSigner::from_private_key(TEST_PRIVATE_KEY)
+            .unwrap()
+            .with_clock(Arc::new(FixedClock(1_700_000_000)));
+
+        let first = signer.create_l1_headers(0).await.unwrap();
+        let second = signer.create_l1_headers(0).await.unwrap();
+
+        assert_eq!(first.timestamp, "1700000000");
+        assert_eq!(first.address, TEST_ADDRESS);
+        assert_eq!(first.signature, second.signature);
+        assert_eq!(
+            first.signature,
+            "0x659ed4b28ae28e0f038fdf0023c00863c9559caacb9ebc83f44eea87059a099a\
+             36f1e1dee110e7faa1c4f65d17489b2da1333ebef78bbe2116d81207b975052d1c"
+        );
+    }
+
     #[test]
     fn test_polymarket_signer_from_private_key() {
         let signer = PolymarketSigner::from_private_key(TEST_PRIVATE_KEY).unwrap();
@@ -152,6 +305,56 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_private_key_validation_messages() {
+        let error = |key: &str| PolymarketSigner::from_private_key(key).err().unwrap().to_string();
+
+        assert!(error("").contains("Private key is empty"));
+        assert!(error("0x").contains("Private key is empty"));
+        assert!(error("0xabc").contains("expected 64 hex characters, got 3"));
+        // Odd length alloy would otherwise try to parse
+        assert!(error(&"a".repeat(63)).contains("got 63"));
+        assert!(error(&"g".repeat(64)).contains("non-hex"));
+
+        // Both signers share the same validation
+        let order_error = OrderSigner::from_private_key("0xabc").err().unwrap().to_string();
+        assert!(order_error.contains("expected 64 hex characters"));
+    }
+
+    #[test]
+    fn test_private_key_valid_with_whitespace() {
+        let padded = format!("  {}\n", TEST_PRIVATE_KEY);
+        let signer = PolymarketSigner::from_private_key(&padded).unwrap();
+        assert_eq!(signer.address_string().to_lowercase(), TEST_ADDRESS.to_lowercase());
+        assert!(OrderSigner::from_private_key(&padded).is_ok());
+    }
+
+    #[cfg(feature = "mnemonic")]
+    #[test]
+    fn test_polymarket_signer_from_mnemonic() {
+        // Well-known Hardhat/Anvil test mnemonic (DO NOT use in production)
+        let phrase = "test test test test test test test test test test test junk";
+
+        let signer = PolymarketSigner::from_mnemonic(phrase, crate::auth::DEFAULT_DERIVATION_PATH).unwrap();
+        assert_eq!(signer.address_string(), "0xf39Fd6e51aad88F6F4ce6aB8827279cffFb92266");
+
+        // Empty path falls back to the default; other indices derive other accounts
+        let default = PolymarketSigner::from_mnemonic(phrase, "").unwrap();
+        assert_eq!(default.address(), signer.address());
+        let second = PolymarketSigner::from_mnemonic(phrase, "m/44'/60'/0'/0/1").unwrap();
+        assert_eq!(second.address_string(), "0x70997970C51812dc3A010C7d01b50e0d17dc79C8");
+    }
+
+    #[cfg(feature = "mnemonic")]
+    #[test]
+    fn test_polymarket_signer_invalid_mnemonic() {
+        let err = PolymarketSigner::from_mnemonic("not a real seed phrase", "").err().unwrap();
+        assert_eq!(err.to_string(), crate::ApiError::Signing("Invalid mnemonic".to_string()).to_string());
+
+        let phrase = "test test test test test test test test test test test junk";
+        assert!(PolymarketSigner::from_mnemonic(phrase, "not/a/path").is_err());
+    }
+
     #[tokio::test]
     async fn test_polymarket_signer_l1_headers() {
         let signer = PolymarketSigner::from_private_key(TEST_PRIVATE_KEY).unwrap();
@@ -204,4 +407,100 @@ mod tests {
         assert_eq!(signed.order.salt, unsigned_order.salt);
         assert_eq!(signed.order.maker_amount, unsigned_order.maker_amount);
     }
+
+    /// Fixed order shared by the digest known-answer tests
+    fn digest_test_order() -> crate::api::order::UnsignedOrder {
+        use crate::api::order::{OrderSide, SignatureType, UnsignedOrder};
+
+        UnsignedOrder {
+            salt: "479249096354".to_string(),
+            maker: TEST_ADDRESS.to_string(),
+            signer: TEST_ADDRESS.to_string(),
+            taker: "0x0000000000000000000000000000000000000000".to_string(),
+            token_id: "71321045679252212594626385532706912750332728571942532289631379312455583992563".to_string(),
+            maker_amount: "100000000".to_string(),
+            taker_amount: "50000000".to_string(),
+            expiration: "0".to_string(),
+            nonce: "0".to_string(),
+            fee_rate_bps: "100".to_string(),
+            side: OrderSide::Sell,
+            signature_type: SignatureType::Proxy,
+        }
+    }
+
+    /// Pinned digest bytes - a field-ordering or encoding regression changes these
+    /// AIDEV-NOTE: Cross-checked below against alloy-sol-types' EIP-712 implementation
+    #[test]
+    fn test_order_digest_known_answer() {
+        use crate::auth::order_eip712::domain_separator;
+        use crate::auth::{order_digest, order_struct_hash};
+
+        let order = digest_test_order();
+
+        assert_eq!(
+            hex::encode(domain_separator().unwrap()),
+            "1a573e3617c78403b5b4b892827992f027b03d4eaf570048b8ee8cdd84d151be"
+        );
+        assert_eq!(
+            hex::encode(order_struct_hash(&order).unwrap()),
+            "53c03d3e59be6d52575b4401daec4cf69dfa80aed3ac653365199a932e695180"
+        );
+        assert_eq!(
+            hex::encode(order_digest(&order).unwrap()),
+            "c899885be11616358507b53621c92ce536d99da3e0b80e850c5c7f9e5ae83900"
+        );
+    }
+
+    #[test]
+    fn test_order_digest_matches_alloy_sol_types() {
+        use alloy_primitives::{address, U256};
+        use alloy_sol_types::{eip712_domain, sol, SolStruct};
+
+        sol! {
+            struct Order {
+                uint256 salt;
+                address maker;
+                address signer;
+                address taker;
+                uint256 tokenId;
+                uint256 makerAmount;
+                uint256 takerAmount;
+                uint256 expiration;
+                uint256 nonce;
+                uint256 feeRateBps;
+                uint8 side;
+                uint8 signatureType;
+            }
+        }
+
+        let domain = eip712_domain! {
+            name: "Polymarket CTF Exchange",
+            version: "1",
+            chain_id: 137,
+            verifying_contract: address!("4bFb41d5B3570DeFd03C39a9A4D8dE6Bd8B8982E"),
+        };
+
+        let order = digest_test_order();
+        let reference = Order {
+            salt: U256::from(479249096354u64),
+            maker: address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266"),
+            signer: address!("f39Fd6e51aad88F6F4ce6aB8827279cffFb92266"),
+            taker: address!("0000000000000000000000000000000000000000"),
+            tokenId: order.token_id.parse().unwrap(),
+            makerAmount: U256::from(100_000_000u64),
+            takerAmount: U256::from(50_000_000u64),
+            expiration: U256::ZERO,
+            nonce: U256::ZERO,
+            feeRateBps: U256::from(100u64),
+            side: 1,
+            signatureType: 1,
+        };
+
+        assert_eq!(*domain.separator(), crate::auth::order_eip712::domain_separator().unwrap());
+        assert_eq!(*reference.eip712_hash_struct(), order.compute_struct_hash().unwrap());
+        assert_eq!(
+            *reference.eip712_signing_hash(&domain),
+            crate::auth::order_digest(&order).unwrap()
+        );
+    }
 }
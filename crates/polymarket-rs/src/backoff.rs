@@ -0,0 +1,283 @@
+// AIDEV-NOTE: Exponential backoff shared by WebSocket reconnects and REST retries
+// Delay for attempt n (1-based) = initial_delay * multiplier^(n-1), capped at max_delay
+
+use std::str::FromStr;
+use std::time::Duration;
+
+use tracing::warn;
+
+/// Configuration for reconnection/retry backoff behavior
+#[derive(Debug, Clone)]
+pub struct ReconnectConfig {
+    /// Initial delay before first reconnect attempt
+    pub initial_delay: Duration,
+    /// Maximum delay between reconnect attempts
+    pub max_delay: Duration,
+    /// Multiplier for exponential backoff
+    pub multiplier: f64,
+    /// Maximum number of reconnect attempts (None = infinite)
+    pub max_attempts: Option<u32>,
+    /// How often a WebSocket ping is sent while connected
+    pub ping_interval: Duration,
+    /// Reconnect if a ping goes unanswered for this long
+    pub ping_timeout: Duration,
+    /// A WebSocket must stay connected this long before its reconnect attempts reset
+    pub stability_threshold: Duration,
+}
+
+impl Default for ReconnectConfig {
+    fn default() -> Self {
+        Self {
+            initial_delay: Duration::from_secs(1),
+            max_delay: Duration::from_secs(30),
+            multiplier: 2.0,
+            max_attempts: None, // Keep trying forever
+            ping_interval: Duration::from_secs(30),
+            ping_timeout: Duration::from_secs(10),
+            stability_threshold: Duration::from_secs(30),
+        }
+    }
+}
+
+impl ReconnectConfig {
+    /// Defaults, overridden by any of these environment variables that are set:
+    /// `WS_RECONNECT_INITIAL_DELAY_MS`, `WS_RECONNECT_MAX_DELAY_MS`,
+    /// `WS_RECONNECT_MULTIPLIER`, `WS_RECONNECT_MAX_ATTEMPTS`
+    /// AIDEV-NOTE: Lets headless/containerized deployments tune reconnects without a rebuild.
+    /// Unparseable or out-of-range values are logged and ignored: the multiplier must be
+    /// finite and >= 1, and a max delay below the initial delay resets both delays.
+    pub fn from_env() -> Self {
+        Self::from_lookup(|key| std::env::var(key).ok())
+    }
+
+    /// `from_env` with an arbitrary variable source
+    fn from_lookup(get: impl Fn(&str) -> Option<String>) -> Self {
+        let defaults = Self::default();
+
+        // NaN/inf or a shrinking multiplier would make backoff_delay panic or spin
+        let multiplier = parse_env_value::<f64>(&get, "WS_RECONNECT_MULTIPLIER").filter(|multiplier| {
+            let valid = multiplier.is_finite() && *multiplier >= 1.0;
+            if !valid {
+                warn!("Ignoring WS_RECONNECT_MULTIPLIER={} (must be finite and >= 1), using default", multiplier);
+            }
+            valid
+        });
+
+        let mut config = Self {
+            initial_delay: parse_env_value(&get, "WS_RECONNECT_INITIAL_DELAY_MS")
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.initial_delay),
+            max_delay: parse_env_value(&get, "WS_RECONNECT_MAX_DELAY_MS")
+                .map(Duration::from_millis)
+                .unwrap_or(defaults.max_delay),
+            multiplier: multiplier.unwrap_or(defaults.multiplier),
+            max_attempts: parse_env_value(&get, "WS_RECONNECT_MAX_ATTEMPTS").or(defaults.max_attempts),
+            ..defaults.clone()
+        };
+
+        if config.max_delay < config.initial_delay {
+            warn!(
+                "Ignoring reconnect delays: max {:?} is below initial {:?}, using defaults",
+                config.max_delay, config.initial_delay
+            );
+            config.initial_delay = defaults.initial_delay;
+            config.max_delay = defaults.max_delay;
+        }
+
+        config
+    }
+}
+
+/// Parse one variable, warning (and returning None) if it is set but invalid
+fn parse_env_value<T: FromStr>(get: &impl Fn(&str) -> Option<String>, key: &str) -> Option<T> {
+    let raw = get(key)?;
+    let parsed = raw.trim().parse().ok();
+    if parsed.is_none() {
+        warn!("Ignoring invalid {}={:?}, using default", key, raw);
+    }
+    parsed
+}
+
+/// Delay before the given attempt (1-based; attempt 0 is treated as 1)
+pub fn backoff_delay(attempt: u32, config: &ReconnectConfig) -> Duration {
+    // Saturate instead of `as i32`, which wraps huge attempt counts to negative exponents
+    let exponent = i32::try_from(attempt.saturating_sub(1)).unwrap_or(i32::MAX);
+    let delay_secs = config.initial_delay.as_secs_f64() * config.multiplier.powi(exponent);
+    let capped_delay = delay_secs.min(config.max_delay.as_secs_f64());
+    Duration::from_secs_f64(capped_delay)
+}
+
+/// Stateful backoff iterator - call `next()` before each retry, `reset()` on success
+#[derive(Debug, Clone)]
+pub struct Backoff {
+    config: ReconnectConfig,
+    attempt: u32,
+}
+
+impl Backoff {
+    pub fn new(config: ReconnectConfig) -> Self {
+        Self { config, attempt: 0 }
+    }
+
+    /// Number of delays handed out since the last reset
+    pub fn attempts(&self) -> u32 {
+        self.attempt
+    }
+
+    /// Start over from `initial_delay`
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+    }
+}
+
+impl Iterator for Backoff {
+    type Item = Duration;
+
+    /// Next delay, or None once `max_attempts` is exhausted
+    fn next(&mut self) -> Option<Duration> {
+        if self.config.max_attempts.is_some_and(|max| self.attempt >= max) {
+            return None;
+        }
+        self.attempt += 1;
+        Some(backoff_delay(self.attempt, &self.config))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+
+    /// Serializes tests that touch the real process environment
+    static ENV_LOCK: parking_lot::Mutex<()> = parking_lot::Mutex::new(());
+
+    #[test]
+    fn test_from_lookup_overrides_and_defaults() {
+        let vars = HashMap::from([
+            ("WS_RECONNECT_INITIAL_DELAY_MS", "250"),
+            ("WS_RECONNECT_MULTIPLIER", "1.5"),
+            ("WS_RECONNECT_MAX_ATTEMPTS", "5"),
+        ]);
+        let config = ReconnectConfig::from_lookup(|key| vars.get(key).map(|v| v.to_string()));
+
+        assert_eq!(config.initial_delay, Duration::from_millis(250));
+        assert_eq!(config.multiplier, 1.5);
+        assert_eq!(config.max_attempts, Some(5));
+        // Unset -> default
+        assert_eq!(config.max_delay, ReconnectConfig::default().max_delay);
+        assert_eq!(config.ping_interval, ReconnectConfig::default().ping_interval);
+    }
+
+    #[test]
+    fn test_from_lookup_ignores_invalid_values() {
+        let config = ReconnectConfig::from_lookup(|key| match key {
+            "WS_RECONNECT_MAX_DELAY_MS" => Some("soon".to_string()),
+            "WS_RECONNECT_MAX_ATTEMPTS" => Some("-1".to_string()),
+            _ => None,
+        });
+
+        let defaults = ReconnectConfig::default();
+        assert_eq!(config.max_delay, defaults.max_delay);
+        assert_eq!(config.max_attempts, defaults.max_attempts);
+    }
+
+    #[test]
+    fn test_from_lookup_rejects_bad_multipliers() {
+        for raw in ["-2", "0.5", "0", "NaN", "inf", "-inf"] {
+            let config = ReconnectConfig::from_lookup(|key| {
+                (key == "WS_RECONNECT_MULTIPLIER").then(|| raw.to_string())
+            });
+            assert_eq!(config.multiplier, ReconnectConfig::default().multiplier, "{}", raw);
+            // Still usable for the reconnect loop
+            assert_eq!(backoff_delay(u32::MAX, &config), config.max_delay);
+        }
+
+        // 1 is allowed (constant delay)
+        let config = ReconnectConfig::from_lookup(|key| (key == "WS_RECONNECT_MULTIPLIER").then(|| "1".to_string()));
+        assert_eq!(config.multiplier, 1.0);
+    }
+
+    #[test]
+    fn test_from_lookup_rejects_max_below_initial() {
+        let config = ReconnectConfig::from_lookup(|key| match key {
+            "WS_RECONNECT_INITIAL_DELAY_MS" => Some("60000".to_string()),
+            "WS_RECONNECT_MAX_DELAY_MS" => Some("5000".to_string()),
+            _ => None,
+        });
+
+        let defaults = ReconnectConfig::default();
+        assert_eq!(config.initial_delay, defaults.initial_delay);
+        assert_eq!(config.max_delay, defaults.max_delay);
+
+        // Equal is fine
+        let config = ReconnectConfig::from_lookup(|key| match key {
+            "WS_RECONNECT_INITIAL_DELAY_MS" | "WS_RECONNECT_MAX_DELAY_MS" => Some("5000".to_string()),
+            _ => None,
+        });
+        assert_eq!(config.initial_delay, Duration::from_secs(5));
+        assert_eq!(config.max_delay, Duration::from_secs(5));
+    }
+
+    #[test]
+    fn test_from_env() {
+        let _guard = ENV_LOCK.lock();
+
+        std::env::set_var("WS_RECONNECT_MAX_DELAY_MS", "5000");
+        std::env::set_var("WS_RECONNECT_MAX_ATTEMPTS", " 3 ");
+        let config = ReconnectConfig::from_env();
+        std::env::remove_var("WS_RECONNECT_MAX_DELAY_MS");
+        std::env::remove_var("WS_RECONNECT_MAX_ATTEMPTS");
+
+        assert_eq!(config.max_delay, Duration::from_secs(5));
+        assert_eq!(config.max_attempts, Some(3));
+        assert_eq!(config.initial_delay, ReconnectConfig::default().initial_delay);
+
+        let config = ReconnectConfig::from_env();
+        assert_eq!(config.max_attempts, None);
+    }
+
+    #[test]
+    fn test_default_stability_threshold() {
+        let config = ReconnectConfig::default();
+        assert_eq!(config.stability_threshold, Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_backoff_delay_doubles_and_caps() {
+        let config = ReconnectConfig::default();
+        assert_eq!(backoff_delay(1, &config), Duration::from_secs(1));
+        assert_eq!(backoff_delay(2, &config), Duration::from_secs(2));
+        assert_eq!(backoff_delay(5, &config), Duration::from_secs(16));
+        assert_eq!(backoff_delay(6, &config), Duration::from_secs(30));
+        assert_eq!(backoff_delay(u32::MAX, &config), Duration::from_secs(30));
+    }
+
+    #[test]
+    fn test_backoff_delay_attempt_zero_is_initial() {
+        let config = ReconnectConfig { initial_delay: Duration::from_millis(250), ..Default::default() };
+        assert_eq!(backoff_delay(0, &config), Duration::from_millis(250));
+    }
+
+    #[test]
+    fn test_backoff_iterator_respects_max_attempts() {
+        let config = ReconnectConfig {
+            initial_delay: Duration::from_millis(100),
+            max_delay: Duration::from_secs(1),
+            multiplier: 3.0,
+            max_attempts: Some(3),
+            ..Default::default()
+        };
+        let mut backoff = Backoff::new(config);
+
+        let delays: Vec<Duration> = backoff.by_ref().collect();
+        assert_eq!(
+            delays,
+            vec![Duration::from_millis(100), Duration::from_millis(300), Duration::from_millis(900)]
+        );
+        assert_eq!(backoff.attempts(), 3);
+
+        backoff.reset();
+        assert_eq!(backoff.next(), Some(Duration::from_millis(100)));
+    }
+}
@@ -5,20 +5,33 @@
 // - WebSocket clients (CLOB order book, RTDS live data)
 // - Authentication (EIP-712 signing, HMAC)
 // - Common types (Market, Order, Position, etc.)
+// - Polymarket URL parsing
+// - In-memory market/position sorting/filtering
 
 pub mod api;
 pub mod auth;
+pub mod backoff;
 pub mod error;
+pub mod market_list;
+pub mod position_list;
 pub mod types;
+pub mod url;
 pub mod ws;
 
 // Re-export main types for convenience
-pub use api::{ClobClient, GammaClient};
-pub use auth::{ApiCredentials, AuthHeaders, AuthStatus, HmacAuth, L1Headers, OrderSigner, PolymarketSigner};
+pub use api::{BalanceWatcher, ClobClient, ClobClientConfig, GammaClient, MarketFilter, OrderWatcher, ResolutionSource, ResolutionWatcher};
+pub use backoff::{backoff_delay, Backoff};
+pub use auth::{ApiCredentials, AuthHeaders, AuthStatus, Clock, FixedClock, HmacAuth, L1Headers, OrderSigner, PolymarketSigner, SystemClock};
 pub use error::{ApiError, ApiResult};
 pub use types::{
-    Balance, ClobTrade, ConnectionState, ConnectionStatus, Event, Market,
-    Order, OrderBookLevel, OrderBookSnapshot, Position, PricePoint,
-    PriceUpdate, RawMarket, Token,
+    AggregatedOrderBook, Balance, BookLevelChange, BookSpread, ClobTrade, ConnectionState, ConnectionStatus, Event, Holder, LeaderboardEntry,
+    Market, MarketCategory, MarketQuote, MarketStatus, MarketSummary, Order, OrderBook, OrderEvent, OrderEventKind, OrderBookLevel, OrderFill, OrderFillEvent, OrderBookSnapshot, PnlSummary, PortfolioSummary, Position, PricePoint,
+    PriceUpdate, RawMarket, TickSizeChange, Token, TokenQuote,
+};
+pub use market_list::{filter_markets, search_cached_markets, sort_markets_by, MarketSortKey};
+pub use position_list::{filter_positions, sort_positions_by, PositionSortKey};
+pub use url::{parse_market_url, MarketRef};
+pub use ws::{
+    parse_clob_message, parse_rtds_message, ClobCache, ClobWebSocket, ConnectionStatistics, EventEmitter, FrameRecorder,
+    NoOpEmitter, ReconnectConfig, RtdsClient, WebSocketManager, WsEvent,
 };
-pub use ws::{ClobWebSocket, EventEmitter, NoOpEmitter, ReconnectConfig, RtdsClient, WebSocketManager};
@@ -0,0 +1,321 @@
+// AIDEV-NOTE: In-memory sorting/filtering/search over already-fetched markets
+// Lets the UI re-order or narrow a cached list (offline, rate-limited) without refetching
+
+use chrono::{Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::cmp::{Ordering, Reverse};
+
+use crate::api::MarketFilter;
+use crate::types::Market;
+
+/// Sort order for `sort_markets_by`
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarketSortKey {
+    /// Highest volume first
+    Volume,
+    /// Highest liquidity first
+    Liquidity,
+    /// Tightest spread first
+    Spread,
+    /// Soonest end date first; markets without one go last
+    EndDate,
+}
+
+/// Sort markets in place (stable - ties keep their existing order)
+pub fn sort_markets_by(markets: &mut [Market], key: MarketSortKey) {
+    match key {
+        MarketSortKey::Volume => markets.sort_by(|a, b| b.volume_num.total_cmp(&a.volume_num)),
+        MarketSortKey::Liquidity => markets.sort_by(|a, b| b.liquidity_num.total_cmp(&a.liquidity_num)),
+        MarketSortKey::Spread => markets.sort_by(|a, b| a.spread.total_cmp(&b.spread)),
+        MarketSortKey::EndDate => markets.sort_by(|a, b| match (a.end_time(), b.end_time()) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            (Some(_), None) => Ordering::Less,
+            (None, Some(_)) => Ordering::Greater,
+            (None, None) => Ordering::Equal,
+        }),
+    }
+}
+
+/// Markets matching `predicate`, in their original order
+pub fn filter_markets(markets: &[Market], predicate: impl Fn(&Market) -> bool) -> Vec<Market> {
+    markets.iter().filter(|m| predicate(m)).cloned().collect()
+}
+
+/// Fuzzy search over loaded markets, best matches first
+/// Every query word must match the question or slug - as a substring, an in-order
+/// subsequence of one word ("btc" in "bitcoin") or a near-miss typo ("bitcon")
+/// AIDEV-NOTE: For instant as-you-type filtering; GammaClient::search_markets is still
+/// the source of truth for markets that aren't loaded yet
+pub fn search_cached_markets(markets: &[Market], query: &str) -> Vec<Market> {
+    let terms: Vec<String> = query.split_whitespace().map(str::to_lowercase).collect();
+    if terms.is_empty() {
+        return Vec::new();
+    }
+
+    let mut scored: Vec<(u32, &Market)> = markets
+        .iter()
+        .filter_map(|market| {
+            let question = market.question.to_lowercase();
+            let slug = market.market_slug.to_lowercase();
+            terms
+                .iter()
+                .map(|term| term_score(term, &question).max(term_score(term, &slug)))
+                .try_fold(0, |total, score| (score > 0).then_some(total + score))
+                .map(|total| (total, market))
+        })
+        .collect();
+
+    // Stable sort keeps the caller's order among equal scores
+    scored.sort_by_key(|(score, _)| Reverse(*score));
+    scored.into_iter().map(|(_, market)| market.clone()).collect()
+}
+
+/// How well one lowercase query term matches lowercase `text` (0 = no match)
+fn term_score(term: &str, text: &str) -> u32 {
+    if let Some(pos) = text.find(term) {
+        let word_start = pos == 0 || !text[..pos].ends_with(|c: char| c.is_alphanumeric());
+        return if word_start { 400 } else { 300 };
+    }
+
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .map(|word| {
+            if let Some(gaps) = subsequence_gaps(term, word) {
+                return 200u32.saturating_sub(gaps as u32 * 10).max(50);
+            }
+            // Allow roughly one typo per four characters
+            let max_distance = term.chars().count() / 4;
+            match levenshtein(term, word) {
+                d if d > 0 && d <= max_distance => 100 - 25 * d.min(3) as u32,
+                _ => 0,
+            }
+        })
+        .max()
+        .unwrap_or(0)
+}
+
+/// Characters skipped when `term` is an in-order subsequence of `word` that starts
+/// on the word's first character (None if it isn't)
+fn subsequence_gaps(term: &str, word: &str) -> Option<usize> {
+    let mut term_chars = term.chars().peekable();
+    if term_chars.peek() != word.chars().next().as_ref() {
+        return None;
+    }
+
+    let mut gaps = 0;
+    for c in word.chars() {
+        match term_chars.peek() {
+            Some(&t) if t == c => {
+                term_chars.next();
+            }
+            Some(_) => gaps += 1,
+            None => break,
+        }
+    }
+
+    term_chars.peek().is_none().then_some(gaps)
+}
+
+/// Edit distance between two strings (by char)
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut prev: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut row = vec![i + 1; b.len() + 1];
+        for (j, &cb) in b.iter().enumerate() {
+            let substitution = prev[j] + usize::from(ca != cb);
+            row[j + 1] = substitution.min(prev[j + 1] + 1).min(row[j] + 1);
+        }
+        prev = row;
+    }
+
+    prev[b.len()]
+}
+
+/// Common predicates for `filter_markets`
+pub mod predicates {
+    use super::*;
+
+    /// Open for trading right now (same check as `MarketFilter::accepting_orders_only`)
+    pub fn accepting_orders() -> impl Fn(&Market) -> bool {
+        let filter = MarketFilter::default().accepting_orders_only(true);
+        move |m| filter.matches(m)
+    }
+
+    /// Traded at least `volume` USDC
+    pub fn min_volume(volume: f64) -> impl Fn(&Market) -> bool {
+        move |m| m.volume_num >= volume
+    }
+
+    /// Spread no wider than `spread`
+    pub fn max_spread(spread: f64) -> impl Fn(&Market) -> bool {
+        move |m| m.spread <= spread
+    }
+
+    /// End date is in the future and no more than `within` away
+    pub fn ends_within(within: Duration) -> impl Fn(&Market) -> bool {
+        move |m| {
+            m.end_time()
+                .map(|end| end - Utc::now())
+                .is_some_and(|left| left > Duration::zero() && left <= within)
+        }
+    }
+
+    /// Question contains `text` (case-insensitive)
+    pub fn question_contains(text: &str) -> impl Fn(&Market) -> bool {
+        let needle = text.to_lowercase();
+        move |m| m.question.to_lowercase().contains(&needle)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::predicates::*;
+    use super::*;
+    use crate::types::RawMarket;
+
+    fn market(id: &str, volume: f64, liquidity: f64, spread: f64, end: &str) -> Market {
+        let json = format!(r#"{{"id": "{id}", "conditionId": "0x{id}", "question": "Market {id}?"}}"#);
+        let mut market: Market = serde_json::from_str::<RawMarket>(&json).unwrap().into();
+        market.volume_num = volume;
+        market.liquidity_num = liquidity;
+        market.spread = spread;
+        market.end_date_iso = end.to_string();
+        market
+    }
+
+    fn ids(markets: &[Market]) -> Vec<&str> {
+        markets.iter().map(|m| m.id.as_str()).collect()
+    }
+
+    fn sample() -> Vec<Market> {
+        vec![
+            market("a", 500.0, 20.0, 0.03, "2031-01-01"),
+            market("b", 1500.0, 5.0, 0.01, ""),
+            market("c", 100.0, 90.0, 0.10, "2030-06-01"),
+        ]
+    }
+
+    #[test]
+    fn test_sort_by_volume() {
+        let mut markets = sample();
+        sort_markets_by(&mut markets, MarketSortKey::Volume);
+        assert_eq!(ids(&markets), vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    fn test_sort_by_liquidity() {
+        let mut markets = sample();
+        sort_markets_by(&mut markets, MarketSortKey::Liquidity);
+        assert_eq!(ids(&markets), vec!["c", "a", "b"]);
+    }
+
+    #[test]
+    fn test_sort_by_spread() {
+        let mut markets = sample();
+        sort_markets_by(&mut markets, MarketSortKey::Spread);
+        assert_eq!(ids(&markets), vec!["b", "a", "c"]);
+    }
+
+    #[test]
+    fn test_sort_by_end_date_puts_missing_last() {
+        let mut markets = sample();
+        sort_markets_by(&mut markets, MarketSortKey::EndDate);
+        assert_eq!(ids(&markets), vec!["c", "a", "b"]);
+    }
+
+    #[test]
+    fn test_sort_key_wire_format() {
+        assert_eq!(serde_json::to_string(&MarketSortKey::EndDate).unwrap(), "\"end_date\"");
+    }
+
+    #[test]
+    fn test_filter_by_volume_and_spread() {
+        let markets = sample();
+        assert_eq!(ids(&filter_markets(&markets, min_volume(400.0))), vec!["a", "b"]);
+        assert_eq!(ids(&filter_markets(&markets, max_spread(0.03))), vec!["a", "b"]);
+
+        // Predicates compose with plain closures
+        let liquid = min_volume(400.0);
+        let tight = max_spread(0.02);
+        assert_eq!(ids(&filter_markets(&markets, |m| liquid(m) && tight(m))), vec!["b"]);
+    }
+
+    #[test]
+    fn test_filter_accepting_orders_and_text() {
+        let mut markets = sample();
+        markets[0].active = true;
+        markets[0].accepting_orders = true;
+        markets[1].active = true;
+        markets[1].accepting_orders = true;
+        markets[1].closed = true;
+
+        assert_eq!(ids(&filter_markets(&markets, accepting_orders())), vec!["a"]);
+        assert_eq!(ids(&filter_markets(&markets, question_contains("MARKET C"))), vec!["c"]);
+    }
+
+    fn titled(id: &str, question: &str, slug: &str) -> Market {
+        let mut market = market(id, 0.0, 0.0, 0.0, "");
+        market.question = question.to_string();
+        market.market_slug = slug.to_string();
+        market
+    }
+
+    fn search_sample() -> Vec<Market> {
+        vec![
+            titled("lakers", "Will the Lakers win the title?", "lakers-title"),
+            titled("btc", "Will Bitcoin reach $150k in 2025?", "bitcoin-150k-2025"),
+            titled("fed", "Fed cuts rates in March?", "fed-march-cut"),
+            titled("eth", "Will Ethereum hit $10k?", "ethereum-10k"),
+        ]
+    }
+
+    #[test]
+    fn test_search_btc_ranks_bitcoin_first() {
+        let results = search_cached_markets(&search_sample(), "btc");
+        assert_eq!(ids(&results), vec!["btc"]);
+
+        // Exact substring beats subsequence
+        let mut markets = search_sample();
+        markets.push(titled("etf", "BTC ETF inflows above $1B?", "btc-etf-inflows"));
+        assert_eq!(ids(&search_cached_markets(&markets, "btc")), vec!["etf", "btc"]);
+    }
+
+    #[test]
+    fn test_search_matches_typos_and_slug() {
+        assert_eq!(ids(&search_cached_markets(&search_sample(), "bitcon")), vec!["btc"]);
+        assert_eq!(ids(&search_cached_markets(&search_sample(), "march-cut")), vec!["fed"]);
+    }
+
+    #[test]
+    fn test_search_requires_every_term() {
+        let markets = search_sample();
+        // "10k" is exact in eth, only a subsequence of "150k" in btc; lakers lacks "10k"
+        assert_eq!(ids(&search_cached_markets(&markets, "will 10k")), vec!["eth", "btc"]);
+        assert!(search_cached_markets(&markets, "bitcoin lakers").is_empty());
+        assert!(search_cached_markets(&markets, "   ").is_empty());
+    }
+
+    #[test]
+    fn test_levenshtein() {
+        assert_eq!(levenshtein("bitcoin", "bitcoin"), 0);
+        assert_eq!(levenshtein("bitcon", "bitcoin"), 1);
+        assert_eq!(levenshtein("", "abc"), 3);
+    }
+
+    #[test]
+    fn test_filter_ends_within() {
+        let soon = (Utc::now() + Duration::days(2)).to_rfc3339();
+        let past = (Utc::now() - Duration::days(2)).to_rfc3339();
+        let markets = vec![
+            market("soon", 0.0, 0.0, 0.0, &soon),
+            market("past", 0.0, 0.0, 0.0, &past),
+            market("later", 0.0, 0.0, 0.0, "2099-01-01"),
+            market("none", 0.0, 0.0, 0.0, ""),
+        ];
+
+        assert_eq!(ids(&filter_markets(&markets, ends_within(Duration::days(7)))), vec!["soon"]);
+    }
+}
@@ -0,0 +1,110 @@
+// AIDEV-NOTE: In-memory sorting/filtering over already-fetched positions
+// The Data API's own sort/size params vary by endpoint version, so portfolio views
+// sort and drop dust client-side (see ClobClient::get_positions_filtered)
+
+use serde::{Deserialize, Serialize};
+
+use crate::types::Position;
+
+/// Sort order for `sort_positions_by` (largest first for every key)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PositionSortKey {
+    /// Unrealized P&L in USDC
+    CashPnl,
+    /// Unrealized P&L as a percentage of cost
+    PercentPnl,
+    /// Current market value
+    CurrentValue,
+    /// Number of shares held
+    Size,
+}
+
+/// Sort positions in place (stable - ties keep their existing order)
+pub fn sort_positions_by(positions: &mut [Position], key: PositionSortKey) {
+    match key {
+        PositionSortKey::CashPnl => positions.sort_by(|a, b| b.cash_pnl.total_cmp(&a.cash_pnl)),
+        PositionSortKey::PercentPnl => positions.sort_by(|a, b| b.percent_pnl.total_cmp(&a.percent_pnl)),
+        PositionSortKey::CurrentValue => positions.sort_by(|a, b| b.current_value.total_cmp(&a.current_value)),
+        PositionSortKey::Size => positions.sort_by(|a, b| b.size.total_cmp(&a.size)),
+    }
+}
+
+/// Drop positions smaller than `min_size` shares (dust), then sort by `sort_by`
+/// With neither set the positions come back unchanged.
+pub fn filter_positions(
+    mut positions: Vec<Position>,
+    sort_by: Option<PositionSortKey>,
+    min_size: Option<f64>,
+) -> Vec<Position> {
+    if let Some(min_size) = min_size {
+        positions.retain(|p| p.size >= min_size);
+    }
+    if let Some(key) = sort_by {
+        sort_positions_by(&mut positions, key);
+    }
+    positions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Data API shape: one winner, one loser, one dust leftover from a sold position
+    const POSITIONS: &str = r#"[
+        {"asset": "win", "conditionId": "0xa", "size": 120, "avgPrice": 0.4, "initialValue": 48,
+         "currentValue": 72, "cashPnl": 24, "percentPnl": 50, "curPrice": 0.6},
+        {"asset": "lose", "conditionId": "0xb", "size": 300, "avgPrice": 0.5, "initialValue": 150,
+         "currentValue": 90, "cashPnl": -60, "percentPnl": -40, "curPrice": 0.3},
+        {"asset": "dust", "conditionId": "0xc", "size": 0.004, "avgPrice": 0.1, "initialValue": 0.0004,
+         "currentValue": 0.0036, "cashPnl": 0.0032, "percentPnl": 800, "curPrice": 0.9}
+    ]"#;
+
+    fn sample() -> Vec<Position> {
+        serde_json::from_str(POSITIONS).unwrap()
+    }
+
+    fn assets(positions: &[Position]) -> Vec<&str> {
+        positions.iter().map(|p| p.asset.as_str()).collect()
+    }
+
+    #[test]
+    fn test_sort_by_each_key() {
+        let mut positions = sample();
+        sort_positions_by(&mut positions, PositionSortKey::CashPnl);
+        assert_eq!(assets(&positions), vec!["win", "dust", "lose"]);
+
+        sort_positions_by(&mut positions, PositionSortKey::PercentPnl);
+        assert_eq!(assets(&positions), vec!["dust", "win", "lose"]);
+
+        sort_positions_by(&mut positions, PositionSortKey::CurrentValue);
+        assert_eq!(assets(&positions), vec!["lose", "win", "dust"]);
+
+        sort_positions_by(&mut positions, PositionSortKey::Size);
+        assert_eq!(assets(&positions), vec!["lose", "win", "dust"]);
+    }
+
+    #[test]
+    fn test_filter_drops_dust() {
+        let positions = filter_positions(sample(), None, Some(0.01));
+        assert_eq!(assets(&positions), vec!["win", "lose"]);
+
+        // Threshold is inclusive
+        assert_eq!(filter_positions(sample(), None, Some(120.0)).len(), 2);
+    }
+
+    #[test]
+    fn test_filter_then_sort() {
+        let positions = filter_positions(sample(), Some(PositionSortKey::PercentPnl), Some(1.0));
+        assert_eq!(assets(&positions), vec!["win", "lose"]);
+
+        // No options: untouched
+        assert_eq!(assets(&filter_positions(sample(), None, None)), vec!["win", "lose", "dust"]);
+    }
+
+    #[test]
+    fn test_sort_key_wire_format() {
+        assert_eq!(serde_json::to_string(&PositionSortKey::CurrentValue).unwrap(), "\"current_value\"");
+        assert_eq!(serde_json::from_str::<PositionSortKey>("\"cash_pnl\"").unwrap(), PositionSortKey::CashPnl);
+    }
+}
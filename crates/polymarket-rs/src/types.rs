@@ -1,7 +1,10 @@
 // AIDEV-NOTE: Polymarket types - mirrors frontend types.ts, keep in sync
 
+use chrono::{DateTime, Duration, NaiveDate, Utc};
 use serde::{Deserialize, Serialize};
 
+use crate::api::order::OrderSide;
+
 /// Market token (outcome)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Token {
@@ -35,6 +38,22 @@ impl Token {
             })
             .collect()
     }
+
+    /// Set `winner` on tokens of a resolved market
+    /// AIDEV-NOTE: Gamma has no winner field - a settled market reports prices of
+    /// exactly "1" for the winning outcome and "0" for the rest. Anything else
+    /// (still settling, 50/50 split) leaves every winner as None.
+    pub fn mark_winners(tokens: &mut [Token]) {
+        const EPSILON: f64 = 1e-9;
+        let winners = tokens.iter().filter(|t| (t.price - 1.0).abs() < EPSILON).count();
+        let losers = tokens.iter().filter(|t| t.price.abs() < EPSILON).count();
+        if winners != 1 || winners + losers != tokens.len() {
+            return;
+        }
+        for token in tokens {
+            token.winner = Some((token.price - 1.0).abs() < EPSILON);
+        }
+    }
 }
 
 /// Market rewards configuration
@@ -92,9 +111,10 @@ pub struct RawMarket {
     #[serde(default)]
     pub spread: f64,
     // AIDEV-NOTE: minimum_order_size is usually 1.0 for most markets
-    #[serde(default = "default_min_order_size")]
+    // Gamma names these orderMinSize/orderPriceMinTickSize
+    #[serde(default = "default_min_order_size", alias = "orderMinSize")]
     pub minimum_order_size: f64,
-    #[serde(default = "default_min_tick_size")]
+    #[serde(default = "default_min_tick_size", alias = "orderPriceMinTickSize")]
     pub minimum_tick_size: f64,
     // Raw string fields from API
     #[serde(default)]
@@ -103,6 +123,9 @@ pub struct RawMarket {
     pub outcome_prices: String,
     #[serde(default)]
     pub clob_token_ids: String,
+    /// Oracle/source that resolves the market (e.g. UMA)
+    #[serde(default, rename = "resolutionSource")]
+    pub resolution_source: Option<String>,
 }
 
 /// Polymarket market (processed)
@@ -131,6 +154,19 @@ pub struct Market {
     pub spread: f64,
     pub minimum_order_size: f64,
     pub minimum_tick_size: f64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub resolution_source: Option<String>,
+}
+
+/// Lifecycle stage of a market, derived from Gamma's active/closed/archived flags
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MarketStatus {
+    Active,
+    Closed,
+    Archived,
+    /// Closed with a winning outcome settled
+    Resolved,
 }
 
 // Default values for optional API fields
@@ -139,11 +175,14 @@ fn default_min_tick_size() -> f64 { 0.01 }
 
 impl From<RawMarket> for Market {
     fn from(raw: RawMarket) -> Self {
-        let tokens = Token::from_api_strings(
+        let mut tokens = Token::from_api_strings(
             &raw.outcomes,
             &raw.outcome_prices,
             &raw.clob_token_ids,
         );
+        if raw.closed {
+            Token::mark_winners(&mut tokens);
+        }
 
         Self {
             id: raw.id,
@@ -166,10 +205,149 @@ impl From<RawMarket> for Market {
             spread: raw.spread,
             minimum_order_size: raw.minimum_order_size,
             minimum_tick_size: raw.minimum_tick_size,
+            resolution_source: raw.resolution_source.filter(|s| !s.is_empty()),
         }
     }
 }
 
+impl Market {
+    /// Collapse the overlapping boolean flags into one status
+    /// AIDEV-NOTE: archived wins over closed (archived markets are closed too), and a
+    /// market that is neither active nor closed is treated as Closed - not tradeable
+    pub fn status(&self) -> MarketStatus {
+        if self.archived {
+            MarketStatus::Archived
+        } else if self.closed && self.resolved_outcome().is_some() {
+            MarketStatus::Resolved
+        } else if self.active && !self.closed {
+            MarketStatus::Active
+        } else {
+            MarketStatus::Closed
+        }
+    }
+
+    /// Whether orders can be placed right now
+    pub fn is_tradeable(&self) -> bool {
+        self.status() == MarketStatus::Active && self.accepting_orders
+    }
+
+    /// Parse `end_date_iso` into a UTC timestamp (None if empty or malformed)
+    pub fn end_time(&self) -> Option<DateTime<Utc>> {
+        parse_end_date(&self.end_date_iso)
+    }
+
+    /// Time remaining until the market's end date (negative once it has passed)
+    pub fn time_to_resolution(&self) -> Option<Duration> {
+        self.end_time().map(|end| end - Utc::now())
+    }
+
+    /// Token for the named outcome ("Yes", "No", "Biden"), matched case-insensitively
+    pub fn token_for_outcome(&self, outcome: &str) -> Option<&Token> {
+        self.tokens.iter().find(|t| t.outcome.eq_ignore_ascii_case(outcome))
+    }
+
+    /// Price of the named outcome, matched case-insensitively
+    pub fn price_for_outcome(&self, outcome: &str) -> Option<f64> {
+        self.token_for_outcome(outcome).map(|t| t.price)
+    }
+
+    /// CLOB token ID of the named outcome, matched case-insensitively
+    pub fn token_id_for_outcome(&self, outcome: &str) -> Option<&str> {
+        self.token_for_outcome(outcome).map(|t| t.token_id.as_str())
+    }
+
+    /// Winning token of a resolved market (None while open or unresolved)
+    pub fn resolved_outcome(&self) -> Option<&Token> {
+        self.tokens.iter().find(|t| t.winner == Some(true))
+    }
+
+    /// Compact view for list UIs (no description or images)
+    pub fn to_quote(&self) -> MarketQuote {
+        MarketQuote {
+            id: self.id.clone(),
+            condition_id: self.condition_id.clone(),
+            question: self.question.clone(),
+            yes_price: self.outcome_price("Yes", 0),
+            no_price: self.outcome_price("No", 1),
+            volume: self.volume_num,
+            spread: self.spread,
+        }
+    }
+
+    /// Lightweight view for large lists - `best_price` is the highest outcome price
+    pub fn to_summary(&self) -> MarketSummary {
+        MarketSummary {
+            condition_id: self.condition_id.clone(),
+            question: self.question.clone(),
+            market_slug: self.market_slug.clone(),
+            end_date_iso: self.end_date_iso.clone(),
+            best_price: self.tokens.iter().map(|t| t.price).fold(0.0, f64::max),
+            volume_num: self.volume_num,
+            liquidity_num: self.liquidity_num,
+            active: self.active,
+            token_count: self.tokens.len(),
+        }
+    }
+
+    /// Price of the named outcome, falling back to the token at `index` for
+    /// markets whose outcomes aren't literally Yes/No
+    fn outcome_price(&self, outcome: &str, index: usize) -> Option<f64> {
+        self.token_for_outcome(outcome)
+            .or_else(|| self.tokens.get(index))
+            .map(|t| t.price)
+    }
+}
+
+/// Compact market summary for list views
+/// AIDEV-NOTE: Keeps IPC payloads small - full Market carries description and image URLs
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketQuote {
+    /// Gamma internal ID (what get_market takes)
+    pub id: String,
+    pub condition_id: String,
+    pub question: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub yes_price: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub no_price: Option<f64>,
+    pub volume: f64,
+    pub spread: f64,
+}
+
+/// Market summary without tokens, description or images
+/// AIDEV-NOTE: For browsing thousands of markets; fetch the full Market on selection
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketSummary {
+    pub condition_id: String,
+    pub question: String,
+    pub market_slug: String,
+    pub end_date_iso: String,
+    /// Highest token price (0.0 when the market has no tokens)
+    pub best_price: f64,
+    pub volume_num: f64,
+    pub liquidity_num: f64,
+    pub active: bool,
+    pub token_count: usize,
+}
+
+/// Parse a Gamma end date - full RFC 3339 or date-only (treated as midnight UTC)
+/// AIDEV-NOTE: Gamma's endDateIso is usually date-only ("2025-12-31"), endDate is RFC 3339
+fn parse_end_date(value: &str) -> Option<DateTime<Utc>> {
+    let value = value.trim();
+    if value.is_empty() {
+        return None;
+    }
+
+    if let Ok(dt) = DateTime::parse_from_rfc3339(value) {
+        return Some(dt.with_timezone(&Utc));
+    }
+
+    NaiveDate::parse_from_str(value, "%Y-%m-%d")
+        .ok()
+        .and_then(|date| date.and_hms_opt(0, 0, 0))
+        .map(|naive| naive.and_utc())
+}
+
 /// Polymarket event (collection of markets)
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Event {
@@ -181,9 +359,9 @@ pub struct Event {
     pub title: String,
     #[serde(default)]
     pub description: String,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, alias = "startDate", skip_serializing_if = "Option::is_none")]
     pub start_date: Option<String>,
-    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default, alias = "endDate", skip_serializing_if = "Option::is_none")]
     pub end_date: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub image: Option<String>,
@@ -201,14 +379,50 @@ pub struct Event {
     pub featured: bool,
     #[serde(default)]
     pub restricted: bool,
-    #[serde(default)]
+    #[serde(default, deserialize_with = "deserialize_event_markets")]
     pub markets: Vec<Market>,
-    #[serde(default)]
+    #[serde(default, alias = "volume")]
     pub total_volume: f64,
-    #[serde(default)]
+    #[serde(default, alias = "liquidity")]
     pub total_liquidity: f64,
 }
 
+/// Deserialize nested event markets from Gamma's raw format or our processed format
+/// AIDEV-NOTE: Gamma nests RawMarket-shaped objects (camelCase, JSON string fields).
+/// Entries matching neither shape are dropped so one odd market can't fail the event.
+fn deserialize_event_markets<'de, D>(deserializer: D) -> Result<Vec<Market>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let values = Option::<Vec<serde_json::Value>>::deserialize(deserializer)?.unwrap_or_default();
+
+    Ok(values
+        .into_iter()
+        .filter_map(|value| {
+            serde_json::from_value::<RawMarket>(value.clone())
+                .map(Market::from)
+                .or_else(|_| serde_json::from_value::<Market>(value))
+                .ok()
+        })
+        .collect())
+}
+
+impl Event {
+    /// Parse `end_date` into a UTC timestamp (None if missing or malformed)
+    pub fn end_time(&self) -> Option<DateTime<Utc>> {
+        self.end_date.as_deref().and_then(parse_end_date)
+    }
+}
+
+/// Market category from Gamma (/categories)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MarketCategory {
+    pub id: String,
+    pub label: String,
+    #[serde(default, alias = "marketCount", skip_serializing_if = "Option::is_none")]
+    pub market_count: Option<u32>,
+}
+
 // ============================================================================
 // WebSocket Event Types
 // ============================================================================
@@ -241,6 +455,33 @@ pub struct PriceUpdate {
     pub price: f64,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub timestamp: Option<i64>,
+    /// Size that accompanied the price change (RTDS `s`, CLOB `size`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volume: Option<f64>,
+}
+
+/// Minimum tick size change for a token, from the CLOB WebSocket
+/// AIDEV-NOTE: Sent when a price nears 0 or 1 - orders priced off the old grid get rejected
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TickSizeChange {
+    pub asset_id: String,
+    pub old_tick_size: f64,
+    pub new_tick_size: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<i64>,
+}
+
+/// One price level changed by a CLOB price_change event
+/// `size` is the new total resting at `price` - "0" removes the level
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BookLevelChange {
+    pub asset_id: String,
+    /// BUY changes a bid level, SELL an ask level
+    pub side: OrderSide,
+    pub price: String,
+    pub size: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<i64>,
 }
 
 /// Order book level
@@ -267,6 +508,219 @@ pub struct OrderBookSnapshot {
     pub last_trade_price: Option<String>,
 }
 
+impl OrderBookSnapshot {
+    /// Top-of-book volume imbalance in [-1, 1]: (bid_vol - ask_vol) / (bid_vol + ask_vol)
+    /// over the best `levels` per side. Positive means more resting bids.
+    /// Returns `None` for an empty book.
+    /// AIDEV-NOTE: Levels are sorted best-first here - the CLOB does not guarantee order
+    pub fn imbalance(&self, levels: usize) -> Option<f64> {
+        let bid_vol = Self::top_volume(&self.bids, levels, true);
+        let ask_vol = Self::top_volume(&self.asks, levels, false);
+        let total = bid_vol + ask_vol;
+
+        if total <= 0.0 {
+            return None;
+        }
+        Some((bid_vol - ask_vol) / total)
+    }
+
+    fn top_volume(raw: &[OrderBookLevel], levels: usize, descending: bool) -> f64 {
+        let mut parsed: Vec<(f64, f64)> = raw
+            .iter()
+            .filter_map(|level| Some((level.price.parse::<f64>().ok()?, level.size.parse::<f64>().ok()?)))
+            .filter(|(_, size)| *size > 0.0)
+            .collect();
+
+        parsed.sort_by(|a, b| a.0.total_cmp(&b.0));
+        if descending {
+            parsed.reverse();
+        }
+
+        parsed.iter().take(levels).map(|(_, size)| size).sum()
+    }
+}
+
+/// Local order book state for a single asset, built from CLOB snapshots
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct OrderBook {
+    pub asset_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub market: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub timestamp: Option<i64>,
+    pub bids: Vec<OrderBookLevel>,
+    pub asks: Vec<OrderBookLevel>,
+}
+
+/// Order book depth bucketed at a fixed price increment
+/// Levels are (price, total_size); bids descending, asks ascending
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct AggregatedOrderBook {
+    pub bids: Vec<(f64, f64)>,
+    pub asks: Vec<(f64, f64)>,
+}
+
+/// Top of book for a single token: best bid/ask, their spread and midpoint
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BookSpread {
+    pub token_id: String,
+    pub best_bid: f64,
+    pub best_ask: f64,
+    pub spread: f64,
+    pub mid_price: f64,
+}
+
+impl From<OrderBookSnapshot> for OrderBook {
+    fn from(snapshot: OrderBookSnapshot) -> Self {
+        Self {
+            asset_id: snapshot.asset_id,
+            market: snapshot.market,
+            timestamp: snapshot.timestamp,
+            bids: snapshot.bids,
+            asks: snapshot.asks,
+        }
+    }
+}
+
+impl OrderBook {
+    /// Best bid/ask with spread and midpoint; None unless both sides have a live level
+    /// AIDEV-NOTE: Scans for max bid / min ask rather than trusting level order
+    pub fn spread(&self) -> Option<BookSpread> {
+        let best_bid = Self::best_price(&self.bids, f64::max)?;
+        let best_ask = Self::best_price(&self.asks, f64::min)?;
+
+        Some(BookSpread {
+            token_id: self.asset_id.clone(),
+            best_bid,
+            best_ask,
+            spread: best_ask - best_bid,
+            mid_price: (best_bid + best_ask) / 2.0,
+        })
+    }
+
+    /// Apply a price_change delta: set the level's size, add it, or remove it on size 0
+    /// AIDEV-NOTE: Levels match by numeric price ("0.5" == "0.50"); unparseable changes are ignored
+    pub fn apply_level_change(&mut self, change: &BookLevelChange) {
+        let (Ok(price), Ok(size)) = (change.price.parse::<f64>(), change.size.parse::<f64>()) else {
+            return;
+        };
+        let levels = match change.side {
+            OrderSide::Buy => &mut self.bids,
+            OrderSide::Sell => &mut self.asks,
+        };
+
+        let existing = levels.iter().position(|level| level.price.parse::<f64>().ok() == Some(price));
+        match existing {
+            Some(index) if size <= 0.0 => {
+                levels.remove(index);
+            }
+            Some(index) => levels[index].size = change.size.clone(),
+            None if size > 0.0 => levels.push(OrderBookLevel {
+                price: change.price.clone(),
+                size: change.size.clone(),
+            }),
+            None => {}
+        }
+
+        if change.timestamp.is_some() {
+            self.timestamp = change.timestamp;
+        }
+    }
+
+    fn best_price(raw: &[OrderBookLevel], better: fn(f64, f64) -> f64) -> Option<f64> {
+        raw.iter()
+            .filter_map(|level| Some((level.price.parse::<f64>().ok()?, level.size.parse::<f64>().ok()?)))
+            .filter(|(_, size)| *size > 0.0)
+            .map(|(price, _)| price)
+            .reduce(better)
+    }
+
+    /// Aggregate depth into `tick_size` buckets, keeping the best `levels` per side
+    /// AIDEV-NOTE: Bids round down and asks round up so buckets never cross the spread
+    pub fn aggregate(&self, tick_size: f64, levels: usize) -> AggregatedOrderBook {
+        if tick_size <= 0.0 || !tick_size.is_finite() {
+            return AggregatedOrderBook::default();
+        }
+
+        let bids = Self::bucket_levels(&self.bids, tick_size, levels, f64::floor, true);
+        let asks = Self::bucket_levels(&self.asks, tick_size, levels, f64::ceil, false);
+
+        AggregatedOrderBook { bids, asks }
+    }
+
+    /// Volume-weighted average price to fill `size` shares against the book
+    /// BUY walks asks from the lowest price, SELL walks bids from the highest.
+    /// Returns None if the book can't fill the full size (or size <= 0).
+    pub fn estimate_fill_price(&self, side: OrderSide, size: f64) -> Option<f64> {
+        if size <= 0.0 || !size.is_finite() {
+            return None;
+        }
+
+        let raw = match side {
+            OrderSide::Buy => &self.asks,
+            OrderSide::Sell => &self.bids,
+        };
+        let mut levels: Vec<(f64, f64)> = raw
+            .iter()
+            .filter_map(|level| Some((level.price.parse::<f64>().ok()?, level.size.parse::<f64>().ok()?)))
+            .filter(|(_, level_size)| *level_size > 0.0)
+            .collect();
+        levels.sort_by(|a, b| a.0.total_cmp(&b.0));
+        if side == OrderSide::Sell {
+            levels.reverse();
+        }
+
+        let mut remaining = size;
+        let mut cost = 0.0;
+        for (price, level_size) in levels {
+            let take = remaining.min(level_size);
+            cost += take * price;
+            remaining -= take;
+            if remaining <= 1e-9 {
+                return Some(cost / size);
+            }
+        }
+
+        None
+    }
+
+    fn bucket_levels(
+        raw: &[OrderBookLevel],
+        tick_size: f64,
+        levels: usize,
+        round: fn(f64) -> f64,
+        descending: bool,
+    ) -> Vec<(f64, f64)> {
+        // Bucket index -> total size (integer keys avoid float comparison issues)
+        let mut buckets: std::collections::BTreeMap<i64, f64> = std::collections::BTreeMap::new();
+
+        for level in raw {
+            let (Ok(price), Ok(size)) = (level.price.parse::<f64>(), level.size.parse::<f64>()) else {
+                continue;
+            };
+            if size <= 0.0 {
+                continue;
+            }
+            // Small epsilon so prices already on a tick boundary stay in their own bucket
+            let ticks = price / tick_size;
+            let nudged = if descending { ticks + 1e-9 } else { ticks - 1e-9 };
+            *buckets.entry(round(nudged) as i64).or_insert(0.0) += size;
+        }
+
+        let to_level = |(index, size): (&i64, &f64)| {
+            // Round to 6 decimals (USDC precision) to strip float noise
+            let price = (*index as f64 * tick_size * 1_000_000.0).round() / 1_000_000.0;
+            (price, *size)
+        };
+
+        if descending {
+            buckets.iter().rev().take(levels).map(to_level).collect()
+        } else {
+            buckets.iter().take(levels).map(to_level).collect()
+        }
+    }
+}
+
 /// Trade event from CLOB
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ClobTrade {
@@ -314,7 +768,93 @@ pub struct Position {
     pub proxy_wallet: String,
 }
 
+/// Positions plus open orders for one account, fetched together
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PortfolioSummary {
+    pub positions: Vec<Position>,
+    pub open_orders: Vec<Order>,
+    /// Sum of `current_value` over all positions
+    pub total_value: f64,
+    /// Sum of `cash_pnl` over all positions
+    pub total_cash_pnl: f64,
+    /// Account-wide P&L from the Data API (None if that request failed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pnl: Option<PnlSummary>,
+}
+
+impl PortfolioSummary {
+    pub fn new(positions: Vec<Position>, open_orders: Vec<Order>) -> Self {
+        Self {
+            total_value: positions.iter().map(|p| p.current_value).sum(),
+            total_cash_pnl: positions.iter().map(|p| p.cash_pnl).sum(),
+            positions,
+            open_orders,
+            pnl: None,
+        }
+    }
+}
+
+/// Aggregate P&L for one address from Data API (/pnl)
+/// AIDEV-NOTE: Every field is required - a response of another shape is a parse error
+/// (and `PortfolioSummary::pnl` None) rather than a row of zeros
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PnlSummary {
+    pub total_pnl: f64,
+    pub realized_pnl: f64,
+    pub unrealized_pnl: f64,
+    pub total_volume: f64,
+}
+
+/// Leaderboard row from Data API (/v1/leaderboard)
+/// AIDEV-NOTE: rank arrives as a string ("1"), vol/proxyWallet are renamed here
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeaderboardEntry {
+    #[serde(alias = "proxyWallet")]
+    pub address: String,
+    #[serde(default)]
+    pub pnl: f64,
+    #[serde(default, alias = "vol")]
+    pub volume: f64,
+    #[serde(deserialize_with = "deserialize_rank")]
+    pub rank: u32,
+}
+
+/// Deserialize a leaderboard rank from either String or integer
+fn deserialize_rank<'de, D>(deserializer: D) -> Result<u32, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    use serde::de::Error;
+
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrInt {
+        String(String),
+        Int(u32),
+    }
+
+    match StringOrInt::deserialize(deserializer)? {
+        StringOrInt::String(s) => s.trim().parse().map_err(D::Error::custom),
+        StringOrInt::Int(i) => Ok(i),
+    }
+}
+
+/// Token holder from Data API (/holders)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Holder {
+    #[serde(alias = "proxyWallet")]
+    pub address: String,
+    #[serde(default)]
+    pub amount: f64,
+}
+
 /// Order from CLOB API
+/// AIDEV-NOTE: /data/orders sends snake_case keys, a numeric created_at and null for
+/// unset fields - the aliases/helpers accept that alongside our own camelCase form
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Order {
@@ -323,15 +863,124 @@ pub struct Order {
     #[serde(default, alias = "asset_id")]
     pub asset: String,
     pub side: String,
+    #[serde(alias = "original_size")]
     pub original_size: String,
+    #[serde(alias = "size_matched")]
     pub size_matched: String,
     pub price: String,
     pub status: String,
-    #[serde(default)]
+    #[serde(default, alias = "order_type", deserialize_with = "deserialize_null_default")]
     pub order_type: String,
+    #[serde(alias = "created_at", deserialize_with = "deserialize_string_or_number")]
     pub created_at: String,
 }
 
+/// Deserialize null as the type's default (serde's `default` only covers missing keys)
+fn deserialize_null_default<'de, D, T>(deserializer: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    Ok(Option::<T>::deserialize(deserializer)?.unwrap_or_default())
+}
+
+/// Deserialize a String from either a JSON string or number
+fn deserialize_string_or_number<'de, D>(deserializer: D) -> Result<String, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum StringOrNumber {
+        String(String),
+        Number(serde_json::Number),
+    }
+
+    Ok(match StringOrNumber::deserialize(deserializer)? {
+        StringOrNumber::String(s) => s,
+        StringOrNumber::Number(n) => n.to_string(),
+    })
+}
+
+/// What happened to an order between two polls (see `OrderWatcher`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OrderEventKind {
+    Filled,
+    PartiallyFilled,
+    Cancelled,
+}
+
+/// Fill/cancel notification for one of the user's orders
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct OrderEvent {
+    pub kind: OrderEventKind,
+    /// Latest known state of the order (size_matched includes this event's fills)
+    pub order: Order,
+    /// Shares matched since the previous poll
+    pub newly_matched: f64,
+}
+
+impl OrderEvent {
+    /// The fill this event represents, stamped with `timestamp` (Unix seconds)
+    /// None for cancels and events that matched nothing new
+    pub fn fill(&self, timestamp: i64) -> Option<OrderFillEvent> {
+        if self.kind == OrderEventKind::Cancelled || self.newly_matched <= 0.0 {
+            return None;
+        }
+        let original = self.order.original_size.parse::<f64>().unwrap_or(0.0);
+        let matched = self.order.size_matched.parse::<f64>().unwrap_or(0.0);
+        Some(OrderFillEvent {
+            order_id: self.order.id.clone(),
+            fill_size: self.newly_matched,
+            fill_price: self.order.price.parse().unwrap_or(0.0),
+            remaining_size: (original - matched).max(0.0),
+            timestamp,
+        })
+    }
+}
+
+/// Real-time notice that one of the user's orders (partially) filled
+/// AIDEV-NOTE: From the order poller, `fill_price` is the order's limit price - the
+/// per-trade price needs the authenticated user channel (not implemented yet)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct OrderFillEvent {
+    pub order_id: String,
+    /// Shares filled in this event
+    pub fill_size: f64,
+    pub fill_price: f64,
+    /// Shares still open after this fill
+    pub remaining_size: f64,
+    /// Unix seconds
+    pub timestamp: i64,
+}
+
+/// A single fill (trade) of one of the user's orders
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderFill {
+    pub trade_id: String,
+    pub order_id: String,
+    /// Shares filled for this order in this trade
+    pub size: f64,
+    pub price: f64,
+    /// Match time as reported by the CLOB (Unix seconds, as a string)
+    pub timestamp: String,
+    /// Fee in USDC (size * price * fee_rate_bps / 10_000)
+    pub fee: f64,
+}
+
+/// Current quote for a single token (midpoint and last trade)
+/// Fields are None when the CLOB has no book / no trades for the token
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TokenQuote {
+    pub token_id: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub midpoint: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub last_trade_price: Option<f64>,
+}
+
 /// Price history point
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PricePoint {
@@ -383,6 +1032,336 @@ mod tests {
         assert_eq!(market.tokens.len(), 2);
     }
 
+    #[test]
+    fn test_leaderboard_entry_deserialization() {
+        let json = r#"[
+            {"rank": "1", "proxyWallet": "0xaaa", "userName": "whale", "vol": 125000.5, "pnl": 4200.25},
+            {"rank": 2, "proxyWallet": "0xbbb", "vol": 900.0, "pnl": -15.5}
+        ]"#;
+
+        let entries: Vec<LeaderboardEntry> = serde_json::from_str(json).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].rank, 1);
+        assert_eq!(entries[0].address, "0xaaa");
+        assert_eq!(entries[0].volume, 125000.5);
+        assert_eq!(entries[0].pnl, 4200.25);
+        assert_eq!(entries[1].rank, 2);
+        assert_eq!(entries[1].pnl, -15.5);
+
+        // Serialized for the frontend with our field names
+        let out = serde_json::to_value(&entries[0]).unwrap();
+        assert_eq!(out["address"], "0xaaa");
+        assert_eq!(out["volume"], 125000.5);
+    }
+
+    fn market_with_prices(closed: bool, prices: &str) -> Market {
+        let json = format!(
+            r#"{{
+                "id": "1",
+                "conditionId": "0xabc",
+                "question": "Resolved?",
+                "closed": {closed},
+                "outcomes": "[\"Yes\",\"No\"]",
+                "outcomePrices": "{prices}",
+                "clobTokenIds": "[\"t1\",\"t2\"]"
+            }}"#
+        );
+        serde_json::from_str::<RawMarket>(&json).unwrap().into()
+    }
+
+    #[test]
+    fn test_resolved_market_winner() {
+        let market = market_with_prices(true, r#"[\"0\",\"1\"]"#);
+
+        assert_eq!(market.tokens[0].winner, Some(false));
+        assert_eq!(market.tokens[1].winner, Some(true));
+        assert_eq!(market.resolved_outcome().unwrap().outcome, "No");
+    }
+
+    #[test]
+    fn test_unresolved_market_has_no_winner() {
+        // Open market that happens to trade at 1/0
+        let market = market_with_prices(false, r#"[\"1\",\"0\"]"#);
+        assert!(market.tokens.iter().all(|t| t.winner.is_none()));
+        assert!(market.resolved_outcome().is_none());
+
+        // Closed but not yet settled
+        let market = market_with_prices(true, r#"[\"0.5\",\"0.5\"]"#);
+        assert!(market.tokens.iter().all(|t| t.winner.is_none()));
+        assert!(market.resolved_outcome().is_none());
+    }
+
+    #[test]
+    fn test_market_status() {
+        let mut market = market_with_prices(false, r#"[\"0.5\",\"0.5\"]"#);
+        market.active = true;
+        assert_eq!(market.status(), MarketStatus::Active);
+        assert!(!market.is_tradeable(), "not accepting orders");
+        market.accepting_orders = true;
+        assert!(market.is_tradeable());
+
+        // Inactive but not closed
+        market.active = false;
+        assert_eq!(market.status(), MarketStatus::Closed);
+
+        market.active = true;
+        market.closed = true;
+        assert_eq!(market.status(), MarketStatus::Closed);
+        assert!(!market.is_tradeable());
+
+        market.archived = true;
+        assert_eq!(market.status(), MarketStatus::Archived);
+    }
+
+    #[test]
+    fn test_market_status_resolved() {
+        let mut market = market_with_prices(true, r#"[\"0\",\"1\"]"#);
+        assert_eq!(market.status(), MarketStatus::Resolved);
+        assert!(!market.is_tradeable());
+
+        market.archived = true;
+        assert_eq!(market.status(), MarketStatus::Archived);
+    }
+
+    #[test]
+    fn test_market_to_quote() {
+        let mut market = market_with_prices(false, r#"[\"0.62\",\"0.38\"]"#);
+        market.volume_num = 1234.5;
+        market.spread = 0.02;
+        market.description = "Long description".to_string();
+
+        let quote = market.to_quote();
+        assert_eq!(quote.id, "1");
+        assert_eq!(quote.condition_id, "0xabc");
+        assert_eq!(quote.question, "Resolved?");
+        assert_eq!(quote.yes_price, Some(0.62));
+        assert_eq!(quote.no_price, Some(0.38));
+        assert_eq!(quote.volume, 1234.5);
+        assert_eq!(quote.spread, 0.02);
+
+        let json = serde_json::to_string(&quote).unwrap();
+        assert!(!json.contains("description"));
+    }
+
+    #[test]
+    fn test_market_to_quote_non_binary_outcomes() {
+        let json = r#"{
+            "id": "2",
+            "conditionId": "0xdef",
+            "question": "Who wins?",
+            "outcomes": "[\"Lakers\",\"Celtics\"]",
+            "outcomePrices": "[\"0.45\",\"0.55\"]",
+            "clobTokenIds": "[\"t1\",\"t2\"]"
+        }"#;
+        let market: Market = serde_json::from_str::<RawMarket>(json).unwrap().into();
+
+        // First outcome stands in for "Yes", second for "No"
+        let quote = market.to_quote();
+        assert_eq!(quote.yes_price, Some(0.45));
+        assert_eq!(quote.no_price, Some(0.55));
+
+        // No tokens at all
+        let mut market = market;
+        market.tokens.clear();
+        let quote = market.to_quote();
+        assert!(quote.yes_price.is_none() && quote.no_price.is_none());
+    }
+
+    #[test]
+    fn test_outcome_lookup_is_case_insensitive() {
+        let json = r#"{
+            "id": "3",
+            "conditionId": "0x123",
+            "question": "Shouting?",
+            "outcomes": "[\"YES\",\"NO\"]",
+            "outcomePrices": "[\"0.7\",\"0.3\"]",
+            "clobTokenIds": "[\"yes-token\",\"no-token\"]"
+        }"#;
+        let market: Market = serde_json::from_str::<RawMarket>(json).unwrap().into();
+
+        assert_eq!(market.price_for_outcome("yes"), Some(0.7));
+        assert_eq!(market.price_for_outcome("No"), Some(0.3));
+        assert_eq!(market.token_id_for_outcome("yes"), Some("yes-token"));
+        assert_eq!(market.token_id_for_outcome("NO"), Some("no-token"));
+
+        assert!(market.price_for_outcome("Maybe").is_none());
+        assert!(market.token_id_for_outcome("").is_none());
+    }
+
+    #[test]
+    fn test_market_to_summary() {
+        let mut market = market_with_prices(false, r#"[\"0.38\",\"0.62\"]"#);
+        market.volume_num = 1234.5;
+        market.liquidity_num = 300.0;
+
+        let summary = market.to_summary();
+        assert_eq!(summary.condition_id, "0xabc");
+        assert_eq!(summary.best_price, 0.62);
+        assert_eq!(summary.volume_num, 1234.5);
+        assert_eq!(summary.liquidity_num, 300.0);
+        assert_eq!(summary.token_count, 2);
+
+        market.tokens.clear();
+        let summary = market.to_summary();
+        assert_eq!(summary.best_price, 0.0);
+        assert_eq!(summary.token_count, 0);
+    }
+
+    fn market_ending(end_date_iso: &str) -> Market {
+        let json = r#"{"id": "1", "conditionId": "0xabc", "question": "Ends?"}"#;
+        let mut market: Market = serde_json::from_str::<RawMarket>(json).unwrap().into();
+        market.end_date_iso = end_date_iso.to_string();
+        market
+    }
+
+    #[test]
+    fn test_market_end_time_valid_iso() {
+        let market = market_ending("2999-01-15T12:00:00Z");
+        let end = market.end_time().unwrap();
+        assert_eq!(end.to_rfc3339(), "2999-01-15T12:00:00+00:00");
+        assert!(market.time_to_resolution().unwrap() > Duration::zero());
+
+        // Date-only values resolve to midnight UTC
+        let market = market_ending("2999-01-15");
+        assert_eq!(market.end_time().unwrap().to_rfc3339(), "2999-01-15T00:00:00+00:00");
+    }
+
+    #[test]
+    fn test_market_end_time_empty_or_malformed() {
+        assert!(market_ending("").end_time().is_none());
+        assert!(market_ending("   ").time_to_resolution().is_none());
+        assert!(market_ending("not-a-date").end_time().is_none());
+    }
+
+    #[test]
+    fn test_market_time_to_resolution_past_date() {
+        let market = market_ending("2020-11-03T00:00:00Z");
+        assert!(market.time_to_resolution().unwrap() < Duration::zero());
+    }
+
+    fn level(price: &str, size: &str) -> OrderBookLevel {
+        OrderBookLevel { price: price.to_string(), size: size.to_string() }
+    }
+
+    fn snapshot(bids: Vec<OrderBookLevel>, asks: Vec<OrderBookLevel>) -> OrderBookSnapshot {
+        OrderBookSnapshot {
+            event_type: Some("book".to_string()),
+            asset_id: "token1".to_string(),
+            market: None,
+            hash: None,
+            timestamp: None,
+            bids,
+            asks,
+            last_trade_price: None,
+        }
+    }
+
+    #[test]
+    fn test_order_book_imbalance_balanced() {
+        let book = snapshot(
+            vec![level("0.50", "10"), level("0.49", "20")],
+            vec![level("0.51", "10"), level("0.52", "20")],
+        );
+        assert_eq!(book.imbalance(2), Some(0.0));
+    }
+
+    #[test]
+    fn test_order_book_imbalance_skewed() {
+        // Bids listed worst-first to check best-level selection
+        let book = snapshot(
+            vec![level("0.40", "100"), level("0.48", "10"), level("0.49", "30")],
+            vec![level("0.51", "10"), level("0.60", "500")],
+        );
+        // Top 2: bids 30 + 10 = 40, asks 10 + 500 = 510
+        let imbalance = book.imbalance(2).unwrap();
+        assert!((imbalance - (40.0 - 510.0) / 550.0).abs() < 1e-12);
+        // Top 1: bids 30, asks 10
+        assert!((book.imbalance(1).unwrap() - 0.5).abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_order_book_imbalance_empty() {
+        assert!(snapshot(vec![], vec![]).imbalance(5).is_none());
+        assert!(snapshot(vec![level("0.5", "0")], vec![]).imbalance(5).is_none());
+        assert_eq!(snapshot(vec![level("0.5", "3")], vec![]).imbalance(5), Some(1.0));
+    }
+
+    #[test]
+    fn test_order_book_estimate_fill_price() {
+        let book = OrderBook {
+            asset_id: "token1".to_string(),
+            bids: vec![level("0.48", "50"), level("0.50", "100")],
+            asks: vec![level("0.60", "100"), level("0.55", "100")],
+            ..Default::default()
+        };
+
+        // Within the best level
+        assert_eq!(book.estimate_fill_price(OrderSide::Buy, 50.0), Some(0.55));
+        // Walks two ask levels: (100 * 0.55 + 50 * 0.60) / 150
+        let vwap = book.estimate_fill_price(OrderSide::Buy, 150.0).unwrap();
+        assert!((vwap - 85.0 / 150.0).abs() < 1e-12);
+        // Sells walk bids from the top: (100 * 0.50 + 25 * 0.48) / 125
+        let vwap = book.estimate_fill_price(OrderSide::Sell, 125.0).unwrap();
+        assert!((vwap - 62.0 / 125.0).abs() < 1e-12);
+        // Not enough depth
+        assert!(book.estimate_fill_price(OrderSide::Buy, 201.0).is_none());
+        assert!(book.estimate_fill_price(OrderSide::Sell, 0.0).is_none());
+    }
+
+    #[test]
+    fn test_order_book_spread() {
+        let book = OrderBook {
+            asset_id: "token1".to_string(),
+            // Unsorted, with an empty and an unparseable level that must be ignored
+            bids: vec![level("0.48", "50"), level("0.52", "0"), level("0.50", "100"), level("bad", "1")],
+            asks: vec![level("0.60", "100"), level("0.54", "100")],
+            ..Default::default()
+        };
+
+        let spread = book.spread().unwrap();
+        assert_eq!(spread.token_id, "token1");
+        assert_eq!((spread.best_bid, spread.best_ask), (0.50, 0.54));
+        assert!((spread.spread - 0.04).abs() < 1e-12);
+        assert!((spread.mid_price - 0.52).abs() < 1e-12);
+
+        let one_sided = OrderBook { asks: vec![], ..book };
+        assert!(one_sided.spread().is_none());
+    }
+
+    #[test]
+    fn test_order_book_aggregate() {
+        let book = OrderBook {
+            asset_id: "token1".to_string(),
+            bids: vec![level("0.555", "10"), level("0.55", "5"), level("0.549", "2"), level("0.53", "1")],
+            asks: vec![level("0.561", "4"), level("0.57", "6"), level("0.575", "3"), level("0.60", "8")],
+            ..Default::default()
+        };
+
+        let agg = book.aggregate(0.01, 2);
+
+        // Bids round down: 0.555 + 0.55 -> 0.55, 0.549 -> 0.54
+        assert_eq!(agg.bids, vec![(0.55, 15.0), (0.54, 2.0)]);
+        // Asks round up: 0.561 + 0.57 -> 0.57, 0.575 -> 0.58
+        assert_eq!(agg.asks, vec![(0.57, 10.0), (0.58, 3.0)]);
+    }
+
+    #[test]
+    fn test_order_book_aggregate_skips_invalid_levels() {
+        let book = OrderBook {
+            asset_id: "token1".to_string(),
+            bids: vec![level("bad", "10"), level("0.40", "0"), level("0.40", "7")],
+            asks: vec![],
+            ..Default::default()
+        };
+
+        let agg = book.aggregate(0.05, 10);
+        assert_eq!(agg.bids, vec![(0.4, 7.0)]);
+        assert!(agg.asks.is_empty());
+
+        // Non-positive tick size yields an empty book
+        assert_eq!(book.aggregate(0.0, 10), AggregatedOrderBook::default());
+    }
+
     #[test]
     fn test_connection_state_serialization() {
         let state = ConnectionState::Connected;
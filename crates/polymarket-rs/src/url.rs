@@ -0,0 +1,156 @@
+// AIDEV-NOTE: Polymarket URL parsing - supports "paste a link to trade" flows
+// Known shapes:
+//   https://polymarket.com/event/{event-slug}
+//   https://polymarket.com/event/{event-slug}/{market-slug}?tid={token_id}
+//   https://polymarket.com/market/{market-slug}
+// The most specific slug wins (market slug over event slug).
+
+use serde::{Deserialize, Serialize};
+
+/// Identifiers extracted from a Polymarket URL
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MarketRef {
+    /// Market slug, or the event slug for event-only links
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub slug: Option<String>,
+    /// On-chain condition ID (0x-prefixed hex), if present in the URL
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub condition_id: Option<String>,
+    /// CLOB token ID (from the `tid` query parameter)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub token_id: Option<String>,
+}
+
+/// Parse a Polymarket market or event URL into its identifiers
+///
+/// Returns `None` if the URL is not a polymarket.com link or contains no
+/// recognizable identifiers.
+pub fn parse_market_url(url: &str) -> Option<MarketRef> {
+    let url = url.trim();
+    let rest = url
+        .strip_prefix("https://")
+        .or_else(|| url.strip_prefix("http://"))
+        .unwrap_or(url);
+
+    // Drop fragment, then split host/path from the query string
+    let rest = rest.split('#').next().unwrap_or(rest);
+    let (host_and_path, query) = match rest.split_once('?') {
+        Some((hp, q)) => (hp, Some(q)),
+        None => (rest, None),
+    };
+
+    let (host, path) = host_and_path.split_once('/').unwrap_or((host_and_path, ""));
+    let host = host.to_lowercase();
+    if host != "polymarket.com" && !host.ends_with(".polymarket.com") {
+        return None;
+    }
+
+    let mut market_ref = MarketRef::default();
+
+    let segments: Vec<&str> = path.split('/').filter(|s| !s.is_empty()).collect();
+    match segments.as_slice() {
+        ["event", _, market_slug, ..] => market_ref.slug = Some(market_slug.to_string()),
+        ["event", event_slug] => market_ref.slug = Some(event_slug.to_string()),
+        ["market", market_slug, ..] => market_ref.slug = Some(market_slug.to_string()),
+        _ => {}
+    }
+
+    // A condition ID may appear directly as a path segment
+    if let Some(segment) = segments.iter().find(|s| is_condition_id(s)) {
+        market_ref.slug = market_ref.slug.filter(|slug| slug != segment);
+        market_ref.condition_id = Some(segment.to_string());
+    }
+
+    for pair in query.unwrap_or("").split('&') {
+        let Some((key, value)) = pair.split_once('=') else {
+            continue;
+        };
+        if value.is_empty() {
+            continue;
+        }
+        match key {
+            "tid" | "token_id" | "tokenId" => market_ref.token_id = Some(value.to_string()),
+            "conditionId" | "condition_id" if is_condition_id(value) => {
+                market_ref.condition_id = Some(value.to_string())
+            }
+            _ => {}
+        }
+    }
+
+    if market_ref == MarketRef::default() {
+        None
+    } else {
+        Some(market_ref)
+    }
+}
+
+/// Check whether a string looks like a condition ID (0x + 64 hex chars)
+fn is_condition_id(s: &str) -> bool {
+    s.strip_prefix("0x")
+        .is_some_and(|hex| hex.len() == 64 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const CONDITION_ID: &str = "0x7c6c69d91b21cbbea08a13d0ad51c0e96a956045aaadc77bce507c6b0475b66e";
+
+    #[test]
+    fn test_parse_event_url() {
+        let parsed = parse_market_url("https://polymarket.com/event/presidential-election-winner-2028").unwrap();
+        assert_eq!(parsed.slug.as_deref(), Some("presidential-election-winner-2028"));
+        assert!(parsed.condition_id.is_none());
+        assert!(parsed.token_id.is_none());
+    }
+
+    #[test]
+    fn test_parse_event_market_url_with_token() {
+        let url = "https://polymarket.com/event/fed-decision-in-december/fed-decreases-interest-rates-by-25-bps-after-december-2025-meeting?tid=71321045679252212594626385532706912750332728571942532289631379312455583992563";
+        let parsed = parse_market_url(url).unwrap();
+        assert_eq!(
+            parsed.slug.as_deref(),
+            Some("fed-decreases-interest-rates-by-25-bps-after-december-2025-meeting")
+        );
+        assert_eq!(
+            parsed.token_id.as_deref(),
+            Some("71321045679252212594626385532706912750332728571942532289631379312455583992563")
+        );
+    }
+
+    #[test]
+    fn test_parse_market_url() {
+        let parsed = parse_market_url("https://polymarket.com/market/will-bitcoin-reach-100k#comments").unwrap();
+        assert_eq!(parsed.slug.as_deref(), Some("will-bitcoin-reach-100k"));
+    }
+
+    #[test]
+    fn test_parse_url_without_scheme_or_with_www() {
+        let parsed = parse_market_url("www.polymarket.com/event/some-event/").unwrap();
+        assert_eq!(parsed.slug.as_deref(), Some("some-event"));
+
+        let parsed = parse_market_url("polymarket.com/market/some-market").unwrap();
+        assert_eq!(parsed.slug.as_deref(), Some("some-market"));
+    }
+
+    #[test]
+    fn test_parse_url_with_condition_id() {
+        let url = format!("https://polymarket.com/market/{}", CONDITION_ID);
+        let parsed = parse_market_url(&url).unwrap();
+        assert_eq!(parsed.condition_id.as_deref(), Some(CONDITION_ID));
+        assert!(parsed.slug.is_none());
+
+        let url = format!("https://polymarket.com/event/some-event?conditionId={}", CONDITION_ID);
+        let parsed = parse_market_url(&url).unwrap();
+        assert_eq!(parsed.slug.as_deref(), Some("some-event"));
+        assert_eq!(parsed.condition_id.as_deref(), Some(CONDITION_ID));
+    }
+
+    #[test]
+    fn test_parse_invalid_urls() {
+        assert!(parse_market_url("https://example.com/event/some-event").is_none());
+        assert!(parse_market_url("https://polymarket.com/").is_none());
+        assert!(parse_market_url("https://polymarket.com/portfolio").is_none());
+        assert!(parse_market_url("").is_none());
+    }
+}
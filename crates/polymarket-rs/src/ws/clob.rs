@@ -1,23 +1,60 @@
 // AIDEV-NOTE: CLOB WebSocket client for order book depth data
 // Connects to wss://ws-subscriptions-clob.polymarket.com
 
+use std::collections::HashMap;
+use std::io;
+use std::path::Path;
 use std::sync::Arc;
+use std::time::Duration;
 use futures_util::{SinkExt, StreamExt};
+use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 use tracing::{debug, error, info};
 
-use crate::types::{ClobTrade, ConnectionState, OrderBookLevel, OrderBookSnapshot, PriceUpdate};
-use super::events::EventEmitter;
+use crate::error::ApiError;
+use crate::api::body_preview;
+use crate::api::order::OrderSide;
+use crate::types::{
+    AggregatedOrderBook, BookLevelChange, ClobTrade, ConnectionState, OrderBook, OrderBookLevel, OrderBookSnapshot,
+    PriceUpdate, TickSizeChange,
+};
+use super::events::{EventEmitter, WsEvent};
 use super::manager::{ReconnectConfig, WebSocketManager};
+use super::replay::{read_frames, FrameRecorder};
 
 const CLOB_WS_URL: &str = "wss://ws-subscriptions-clob.polymarket.com/ws/market";
 
+/// Reconnect if no message arrives for this long while connected
+const HEARTBEAT_TIMEOUT: Duration = Duration::from_secs(30);
+/// How often the heartbeat timeout is checked
+const HEARTBEAT_CHECK_INTERVAL: Duration = Duration::from_secs(5);
+/// Payload of our keepalive pings (the server echoes it in the Pong)
+const PING_PAYLOAD: &[u8] = b"heartbeat";
+
+/// Where the connection task connects, and its reconnect/ping settings
+struct Endpoint {
+    url: String,
+    config: ReconnectConfig,
+}
+
 /// CLOB WebSocket client for order book data
 pub struct ClobWebSocket<E: EventEmitter> {
     manager: Arc<WebSocketManager<E>>,
     shutdown_tx: Option<mpsc::Sender<()>>,
+    /// Sends newly added token IDs to the running connection task
+    subscribe_tx: Option<mpsc::UnboundedSender<Vec<String>>>,
+    /// All subscribed token IDs - re-read on every (re)connect
+    token_ids: Arc<RwLock<Vec<String>>>,
+    /// Books and tick sizes kept from the feed
+    cache: Arc<ClobCache>,
+    /// Raw frame capture for offline replay (see `record_frames_to`)
+    recorder: Option<Arc<FrameRecorder>>,
+    /// WebSocket endpoint (CLOB_WS_URL unless overridden)
+    url: String,
+    /// Reconnect/ping settings (None = `ReconnectConfig::from_env` on connect)
+    config: Option<ReconnectConfig>,
 }
 
 impl<E: EventEmitter> ClobWebSocket<E> {
@@ -25,24 +62,131 @@ impl<E: EventEmitter> ClobWebSocket<E> {
         Self {
             manager,
             shutdown_tx: None,
+            subscribe_tx: None,
+            token_ids: Arc::new(RwLock::new(Vec::new())),
+            cache: Arc::new(ClobCache::default()),
+            recorder: None,
+            url: CLOB_WS_URL.to_string(),
+            config: None,
+        }
+    }
+
+    /// Connect to a different endpoint (local mock servers)
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = url.into();
+        self
+    }
+
+    /// Use `config` instead of reading it from the environment on connect
+    pub fn with_reconnect_config(mut self, config: ReconnectConfig) -> Self {
+        self.config = Some(config);
+        self
+    }
+
+    /// Record every received text frame to `path` (takes effect on the next `connect`)
+    pub fn record_frames_to(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.recorder = Some(Arc::new(FrameRecorder::create(path)?));
+        Ok(())
+    }
+
+    /// Feed a recording through `handle_message` as if it arrived live (emits events and
+    /// updates the local cache). Returns the number of frames replayed.
+    pub fn replay_from(&self, path: impl AsRef<Path>) -> io::Result<usize> {
+        let frames = read_frames(path)?;
+        for frame in &frames {
+            Self::handle_message(self.manager.emitter(), &self.cache, frame);
+        }
+        Ok(frames.len())
+    }
+
+    /// Get the latest local order book for an asset
+    pub fn order_book(&self, asset_id: &str) -> Option<OrderBook> {
+        self.cache.books.read().get(asset_id).cloned()
+    }
+
+    /// Current tick size for an asset, if a tick_size_change has been received for it
+    pub fn tick_size(&self, asset_id: &str) -> Option<f64> {
+        self.cache.tick_sizes.read().get(asset_id).copied()
+    }
+
+    /// Get aggregated depth for an asset at `tick_size` increments
+    pub fn aggregated_snapshot(
+        &self,
+        asset_id: &str,
+        tick_size: f64,
+        levels: usize,
+    ) -> Option<AggregatedOrderBook> {
+        self.cache
+            .books
+            .read()
+            .get(asset_id)
+            .map(|book| book.aggregate(tick_size, levels))
+    }
+
+    /// Token IDs currently subscribed (restored on every reconnect)
+    pub fn subscribed_tokens(&self) -> Vec<String> {
+        self.token_ids.read().clone()
+    }
+
+    /// Add token subscriptions, sending them on the live connection if there is one
+    /// Returns the token IDs that were not already subscribed, or an error (and nothing
+    /// added) if they would exceed the manager's CLOB subscription limit.
+    pub fn subscribe_tokens(&self, token_ids: Vec<String>) -> Result<Vec<String>, ApiError> {
+        let added: Vec<String> = {
+            let mut current = self.token_ids.write();
+            let mut added: Vec<String> = Vec::new();
+            for token_id in token_ids {
+                if !current.contains(&token_id) && !added.contains(&token_id) {
+                    added.push(token_id);
+                }
+            }
+            self.manager.reserve_clob_subscriptions(added.len())?;
+            current.extend(added.iter().cloned());
+            added
+        };
+
+        // AIDEV-NOTE: If the task is mid-reconnect the send still queues; either way
+        // the tokens are in `token_ids` and get subscribed on the next connect
+        if let Some(tx) = &self.subscribe_tx {
+            if !added.is_empty() {
+                let _ = tx.send(added.clone());
+            }
         }
+
+        Ok(added)
     }
 
     /// Start the CLOB WebSocket connection for specific token IDs
-    pub async fn connect(&mut self, token_ids: Vec<String>) {
+    /// Fails without connecting if `token_ids` exceed the CLOB subscription limit
+    /// or the reconnect config has a zero ping_interval.
+    pub async fn connect(&mut self, token_ids: Vec<String>) -> Result<(), ApiError> {
+        let config = self.config.clone().unwrap_or_else(ReconnectConfig::from_env);
+        // tokio's interval panics on a zero period
+        if config.ping_interval.is_zero() {
+            return Err(ApiError::WebSocket("Invalid reconnect config: ping_interval must be non-zero".to_string()));
+        }
+
+        self.release_tokens();
+        self.subscribe_tokens(token_ids)?;
+
         let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
         self.shutdown_tx = Some(shutdown_tx);
+        let (subscribe_tx, mut subscribe_rx) = mpsc::unbounded_channel::<Vec<String>>();
+        self.subscribe_tx = Some(subscribe_tx);
 
         let manager = self.manager.clone();
-        let token_ids = token_ids.clone();
+        let cache = self.cache.clone();
+        let token_ids = self.token_ids.clone();
+        let recorder = self.recorder.clone();
+        let endpoint = Endpoint { url: self.url.clone(), config };
 
         tokio::spawn(async move {
-            let config = ReconnectConfig::default();
+            let config = &endpoint.config;
 
             loop {
                 manager.set_clob_state(ConnectionState::Connecting);
 
-                match Self::connect_and_run(&manager, &token_ids, &mut shutdown_rx).await {
+                match Self::connect_and_run(&manager, &cache, &token_ids, &endpoint, recorder.as_deref(), &mut subscribe_rx, &mut shutdown_rx).await {
                     Ok(()) => {
                         info!("CLOB connection closed gracefully");
                         break;
@@ -50,19 +194,10 @@ impl<E: EventEmitter> ClobWebSocket<E> {
                     Err(e) => {
                         error!("CLOB connection error: {}", e);
 
-                        let attempts = manager.increment_clob_reconnect();
-
-                        if let Some(max) = config.max_attempts {
-                            if attempts >= max {
-                                manager.set_clob_state(ConnectionState::Failed);
-                                error!("CLOB max reconnect attempts ({}) reached", max);
-                                break;
-                            }
-                        }
-
-                        manager.set_clob_state(ConnectionState::Reconnecting);
-                        let delay = WebSocketManager::<E>::calculate_reconnect_delay(attempts, &config);
-                        info!("CLOB reconnecting in {:?} (attempt {})", delay, attempts);
+                        let Some(delay) = manager.begin_clob_reconnect(config) else {
+                            error!("CLOB max reconnect attempts ({:?}) reached", config.max_attempts);
+                            break;
+                        };
 
                         tokio::select! {
                             _ = tokio::time::sleep(delay) => continue,
@@ -77,47 +212,111 @@ impl<E: EventEmitter> ClobWebSocket<E> {
 
             manager.set_clob_state(ConnectionState::Disconnected);
         });
+
+        Ok(())
+    }
+
+    /// Forget all tokens and return them to the manager's subscription count
+    fn release_tokens(&self) {
+        let mut current = self.token_ids.write();
+        self.manager.release_clob_subscriptions(current.len());
+        current.clear();
     }
 
     async fn connect_and_run(
         manager: &Arc<WebSocketManager<E>>,
-        token_ids: &[String],
+        cache: &ClobCache,
+        token_ids: &RwLock<Vec<String>>,
+        endpoint: &Endpoint,
+        recorder: Option<&FrameRecorder>,
+        subscribe_rx: &mut mpsc::UnboundedReceiver<Vec<String>>,
         shutdown_rx: &mut mpsc::Receiver<()>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        info!("Connecting to CLOB WS: {}", CLOB_WS_URL);
+        let config = &endpoint.config;
+        info!("Connecting to CLOB WS: {}", endpoint.url);
 
-        let (ws_stream, _) = connect_async(CLOB_WS_URL).await?;
+        let (ws_stream, _) = connect_async(endpoint.url.as_str()).await?;
         let (mut write, mut read) = ws_stream.split();
 
         manager.set_clob_state(ConnectionState::Connected);
+        // Count the connect itself as activity so the heartbeat starts fresh
+        manager.record_clob_message();
         info!("CLOB WebSocket connected successfully");
 
-        // Subscribe to order books for each token
-        for token_id in token_ids {
-            let subscribe_msg = ClobSubscribe {
-                auth: None,
-                markets: vec![],
-                assets_ids: vec![token_id.clone()],
-                msg_type: "subscribe".to_string(),
-            };
+        // Drop queued additions - they are already in `token_ids`
+        while subscribe_rx.try_recv().is_ok() {}
 
-            let msg = serde_json::to_string(&subscribe_msg)?;
+        // Subscribe to order books for each token (includes any added since connect)
+        let current = token_ids.read().clone();
+        for token_id in &current {
+            let msg = Self::subscribe_message(token_id)?;
             write.send(Message::Text(msg)).await?;
             debug!("Subscribed to order book: {}", token_id);
         }
 
+        let mut heartbeat = tokio::time::interval(HEARTBEAT_CHECK_INTERVAL);
+
+        // AIDEV-NOTE: NAT timeouts can kill the TCP connection without a close frame, and
+        // a quiet market then looks identical to a dead socket. Ping on an interval and
+        // reconnect if the Pong doesn't come back within ping_timeout.
+        let mut ping = tokio::time::interval_at(
+            tokio::time::Instant::now() + config.ping_interval,
+            config.ping_interval,
+        );
+        let mut pong_deadline: Option<tokio::time::Instant> = None;
+        // Backoff only resets once the connection has proven stable
+        let mut stable_at = Some(tokio::time::Instant::now() + config.stability_threshold);
+
         // Handle incoming messages
         loop {
             tokio::select! {
+                added = subscribe_rx.recv() => {
+                    let Some(added) = added else {
+                        // Client dropped
+                        return Ok(());
+                    };
+                    for token_id in &added {
+                        let msg = Self::subscribe_message(token_id)?;
+                        write.send(Message::Text(msg)).await?;
+                        debug!("Subscribed to order book: {}", token_id);
+                    }
+                }
+                _ = ping.tick() => {
+                    write.send(Message::Ping(PING_PAYLOAD.to_vec())).await?;
+                    // Keep the earliest outstanding deadline
+                    pong_deadline.get_or_insert_with(|| tokio::time::Instant::now() + config.ping_timeout);
+                }
+                _ = tokio::time::sleep_until(pong_deadline.unwrap_or_else(tokio::time::Instant::now)), if pong_deadline.is_some() => {
+                    return Err(format!("no pong within {:?}", config.ping_timeout).into());
+                }
+                _ = tokio::time::sleep_until(stable_at.unwrap_or_else(tokio::time::Instant::now)), if stable_at.is_some() => {
+                    debug!("CLOB connection stable, resetting reconnect attempts");
+                    manager.reset_clob_reconnect();
+                    stable_at = None;
+                }
+                _ = heartbeat.tick() => {
+                    let idle = manager.clob_idle_time().unwrap_or_default();
+                    if manager.clob_state() == ConnectionState::Connected && idle > HEARTBEAT_TIMEOUT {
+                        return Err(format!("heartbeat timeout (no data for {:?})", idle).into());
+                    }
+                }
                 msg = read.next() => {
                     match msg {
                         Some(Ok(Message::Text(text))) => {
                             manager.record_clob_message();
-                            Self::handle_message(manager.emitter(), &text);
+                            if let Some(recorder) = recorder {
+                                recorder.record(&text);
+                            }
+                            Self::handle_message(manager.emitter(), cache, &text);
                         }
                         Some(Ok(Message::Ping(data))) => {
+                            manager.record_clob_message();
                             write.send(Message::Pong(data)).await?;
                         }
+                        Some(Ok(Message::Pong(_))) => {
+                            manager.record_clob_message();
+                            pong_deadline = None;
+                        }
                         Some(Ok(Message::Close(_))) => {
                             info!("CLOB server closed connection");
                             return Ok(());
@@ -140,109 +339,211 @@ impl<E: EventEmitter> ClobWebSocket<E> {
         }
     }
 
-    fn handle_message(emitter: &Arc<E>, text: &str) {
-        // AIDEV-NOTE: Log first message to debug format issues
-        let preview = if text.len() > 200 { &text[..200] } else { text };
-        debug!("CLOB raw message ({}): {}", text.len(), preview);
+    fn subscribe_message(token_id: &str) -> Result<String, serde_json::Error> {
+        serde_json::to_string(&ClobSubscribe {
+            auth: None,
+            markets: vec![],
+            assets_ids: vec![token_id.to_string()],
+            msg_type: "subscribe".to_string(),
+        })
+    }
 
-        // Try to parse as generic JSON to check event_type
-        let Ok(value) = serde_json::from_str::<serde_json::Value>(text) else {
-            debug!("Failed to parse CLOB message as JSON: {}", preview);
-            return;
-        };
+    /// Parse one text frame (see `parse_clob_message`), update `cache` and emit the events
+    /// Public so frames can be pushed through the exact live path offline
+    pub fn handle_message(emitter: &Arc<E>, cache: &ClobCache, text: &str) {
+        for event in parse_clob_message(text) {
+            cache.apply(&event);
+            event.emit_to(emitter.as_ref());
+        }
+    }
 
-        // Handle array of messages (initial snapshots)
-        // AIDEV-NOTE: Initial order book snapshots come as array without event_type field
-        if let Some(arr) = value.as_array() {
-            for item in arr {
-                // Check if it has order book fields (bids, asks, asset_id)
-                if item.get("bids").is_some() && item.get("asks").is_some() {
-                    if let Ok(raw) = serde_json::from_value::<RawOrderBookSnapshot>(item.clone()) {
-                        let snapshot = Self::convert_snapshot(raw);
-                        debug!("Order book snapshot for {} ({} bids, {} asks)",
-                               snapshot.asset_id, snapshot.bids.len(), snapshot.asks.len());
-                        emitter.emit_orderbook_snapshot(&snapshot);
-                    } else {
-                        debug!("Failed to parse order book from array item: {:?}", item);
-                    }
-                } else if let Some(event_type) = item.get("event_type").and_then(|v| v.as_str()) {
-                    // Handle typed events within arrays
-                    if event_type == "book" {
-                        if let Ok(raw) = serde_json::from_value::<RawOrderBookSnapshot>(item.clone()) {
-                            let snapshot = Self::convert_snapshot(raw);
-                            debug!("Order book snapshot for {}", snapshot.asset_id);
-                            emitter.emit_orderbook_snapshot(&snapshot);
-                        }
-                    }
-                }
-            }
-            return;
+    /// Disconnect from CLOB WebSocket
+    pub fn disconnect(&mut self) {
+        if let Some(tx) = self.shutdown_tx.take() {
+            let _ = tx.try_send(());
         }
+        self.subscribe_tx = None;
+    }
+}
+
+// AIDEV-NOTE: Subscriptions count against the shared manager until the client is gone
+// (a disconnected client keeps its tokens so `connect` could be called again)
+impl<E: EventEmitter> Drop for ClobWebSocket<E> {
+    fn drop(&mut self) {
+        self.release_tokens();
+    }
+}
 
-        // Handle single message
-        let event_type = value.get("event_type").and_then(|v| v.as_str());
+/// Per-asset state kept from the CLOB feed
+#[derive(Debug, Default)]
+pub struct ClobCache {
+    /// Latest order book per asset_id: replaced on each snapshot, updated by price_change deltas
+    pub books: RwLock<HashMap<String, OrderBook>>,
+    /// Current tick size per asset_id, from tick_size_change events
+    /// AIDEV-NOTE: Tick sizes change mid-life (price near 0 or 1); never assume the
+    /// market's initial tick size still holds once one of these has arrived
+    pub tick_sizes: RwLock<HashMap<String, f64>>,
+}
 
-        match event_type {
-            Some("book") => {
-                if let Ok(raw) = serde_json::from_value::<RawOrderBookSnapshot>(value) {
-                    let snapshot = Self::convert_snapshot(raw);
-                    debug!("Order book snapshot for {}", snapshot.asset_id);
-                    emitter.emit_orderbook_snapshot(&snapshot);
-                }
+impl ClobCache {
+    /// Fold one parsed event into the cache (other event kinds are ignored)
+    pub fn apply(&self, event: &WsEvent) {
+        match event {
+            WsEvent::OrderBook(snapshot) => {
+                self.books
+                    .write()
+                    .insert(snapshot.asset_id.clone(), OrderBook::from(snapshot.clone()));
             }
-            Some("price_change") => {
-                // AIDEV-NOTE: price_change has price_changes array with best_bid/best_ask
-                if let Ok(price_event) = serde_json::from_value::<ClobPriceChangeEvent>(value) {
-                    for change in &price_event.price_changes {
-                        // Emit price update using best_bid as the price
-                        if let Ok(price) = change.best_bid.parse::<f64>() {
-                            let update = PriceUpdate {
-                                market: price_event.market.clone(),
-                                asset_id: change.asset_id.clone(),
-                                price,
-                                timestamp: price_event.timestamp,
-                            };
-                            debug!("Price update: {} -> {}", change.asset_id, price);
-                            emitter.emit_price_update(&update);
-                        }
-                    }
-                }
+            WsEvent::TickSizeChange(change) => {
+                self.tick_sizes.write().insert(change.asset_id.clone(), change.new_tick_size);
             }
-            Some("trade") => {
-                if let Ok(trade) = serde_json::from_value::<ClobTrade>(value) {
-                    debug!("CLOB trade: {:?}", trade);
-                    emitter.emit_trade(&trade);
+            // AIDEV-NOTE: Deltas for an asset with no snapshot yet are dropped - the book
+            // arrives as a snapshot on subscribe
+            WsEvent::BookLevelChange(change) => {
+                if let Some(book) = self.books.write().get_mut(&change.asset_id) {
+                    book.apply_level_change(change);
                 }
             }
-            _ => {
-                let preview = if text.len() > 100 { &text[..100] } else { text };
-                debug!("Unknown CLOB message: {}", preview);
-            }
+            _ => {}
         }
     }
+}
 
-    /// Convert raw snapshot (with String timestamp) to our OrderBookSnapshot
-    fn convert_snapshot(raw: RawOrderBookSnapshot) -> OrderBookSnapshot {
-        OrderBookSnapshot {
-            event_type: raw.event_type,
-            asset_id: raw.asset_id,
-            market: raw.market,
-            hash: raw.hash,
-            timestamp: raw.timestamp,
-            bids: raw.bids,
-            asks: raw.asks,
-            last_trade_price: raw.last_trade_price,
+/// Parse one CLOB text frame into events, without side effects
+/// Unknown or malformed frames yield no events.
+pub fn parse_clob_message(text: &str) -> Vec<WsEvent> {
+    // AIDEV-NOTE: Log first message to debug format issues. Frames are arbitrary input -
+    // always truncate with body_preview, never a byte slice that can split a character
+    let preview = body_preview(text);
+    debug!("CLOB raw message ({}): {}", text.len(), preview);
+
+    // Try to parse as generic JSON to check event_type
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(text) else {
+        debug!("Failed to parse CLOB message as JSON: {}", preview);
+        return Vec::new();
+    };
+
+    // Handle array of messages (initial snapshots)
+    // AIDEV-NOTE: Initial order book snapshots come as array without event_type field
+    if let Some(arr) = value.as_array() {
+        let mut events = Vec::new();
+        for item in arr {
+            let is_book = (item.get("bids").is_some() && item.get("asks").is_some())
+                || item.get("event_type").and_then(|v| v.as_str()) == Some("book");
+            if !is_book {
+                continue;
+            }
+            match serde_json::from_value::<RawOrderBookSnapshot>(item.clone()) {
+                Ok(raw) => {
+                    let snapshot = convert_snapshot(raw);
+                    debug!("Order book snapshot for {} ({} bids, {} asks)",
+                           snapshot.asset_id, snapshot.bids.len(), snapshot.asks.len());
+                    events.push(WsEvent::OrderBook(snapshot));
+                }
+                Err(_) => debug!("Failed to parse order book from array item: {:?}", item),
+            }
         }
+        return events;
     }
 
-    /// Disconnect from CLOB WebSocket
-    pub fn disconnect(&mut self) {
-        if let Some(tx) = self.shutdown_tx.take() {
-            let _ = tx.try_send(());
+    // Handle single message
+    let event_type = value.get("event_type").and_then(|v| v.as_str());
+
+    match event_type {
+        Some("book") => serde_json::from_value::<RawOrderBookSnapshot>(value)
+            .map(|raw| {
+                let snapshot = convert_snapshot(raw);
+                debug!("Order book snapshot for {}", snapshot.asset_id);
+                vec![WsEvent::OrderBook(snapshot)]
+            })
+            .unwrap_or_default(),
+        Some("price_change") => {
+            // AIDEV-NOTE: price_change has price_changes array with best_bid/best_ask.
+            // Each change yields its book level delta, then a price update
+            let Ok(price_event) = serde_json::from_value::<ClobPriceChangeEvent>(value) else {
+                return Vec::new();
+            };
+            let mut events = Vec::new();
+            for change in &price_event.price_changes {
+                // AIDEV-NOTE: Some frames only carry asset_id at the top level
+                let Some(asset_id) = change.asset_id.as_ref().or(price_event.asset_id.as_ref()) else {
+                    debug!("Price change without asset_id in market {}", price_event.market);
+                    continue;
+                };
+                if let Ok(side) = change.side.parse::<OrderSide>() {
+                    events.push(WsEvent::BookLevelChange(BookLevelChange {
+                        asset_id: asset_id.clone(),
+                        side,
+                        price: change.price.clone(),
+                        size: change.size.clone(),
+                        timestamp: price_event.timestamp,
+                    }));
+                }
+                // Emit price update using best_bid as the price
+                let Ok(price) = change.best_bid.parse::<f64>() else {
+                    continue;
+                };
+                debug!("Price update: {} -> {}", asset_id, price);
+                events.push(WsEvent::PriceUpdate(PriceUpdate {
+                    market: price_event.market.clone(),
+                    asset_id: asset_id.clone(),
+                    price,
+                    timestamp: price_event.timestamp,
+                    volume: change.size.parse().ok(),
+                }));
+            }
+            events
+        }
+        Some("trade") => serde_json::from_value::<ClobTrade>(value)
+            .map(|trade| {
+                debug!("CLOB trade: {:?}", trade);
+                vec![WsEvent::Trade(trade)]
+            })
+            .unwrap_or_default(),
+        // AIDEV-NOTE: Documented as tick_size_change; accept the plural form too
+        Some("tick_size_change" | "tick_size_changes") => {
+            match serde_json::from_value::<RawTickSizeChange>(value).map(convert_tick_size_change) {
+                Ok(Some(change)) => {
+                    info!("Tick size for {}: {} -> {}", change.asset_id, change.old_tick_size, change.new_tick_size);
+                    vec![WsEvent::TickSizeChange(change)]
+                }
+                _ => {
+                    debug!("Failed to parse tick size change: {}", preview);
+                    Vec::new()
+                }
+            }
+        }
+        _ => {
+            debug!("Unknown CLOB message: {}", preview);
+            Vec::new()
         }
     }
 }
 
+/// Convert raw snapshot (with String timestamp) to our OrderBookSnapshot
+fn convert_snapshot(raw: RawOrderBookSnapshot) -> OrderBookSnapshot {
+    OrderBookSnapshot {
+        event_type: raw.event_type,
+        asset_id: raw.asset_id,
+        market: raw.market,
+        hash: raw.hash,
+        timestamp: raw.timestamp,
+        bids: raw.bids,
+        asks: raw.asks,
+        last_trade_price: raw.last_trade_price,
+    }
+}
+
+/// Parse string tick sizes (None if either is not a number)
+fn convert_tick_size_change(raw: RawTickSizeChange) -> Option<TickSizeChange> {
+    Some(TickSizeChange {
+        asset_id: raw.asset_id,
+        old_tick_size: raw.old_tick_size.parse().ok()?,
+        new_tick_size: raw.new_tick_size.parse().ok()?,
+        timestamp: raw.timestamp,
+    })
+}
+
 // CLOB Message Types
 
 #[derive(Debug, Serialize)]
@@ -295,6 +596,16 @@ where
     }
 }
 
+/// Raw tick size change from CLOB (tick sizes and timestamp come as Strings)
+#[derive(Debug, Clone, Deserialize)]
+struct RawTickSizeChange {
+    asset_id: String,
+    old_tick_size: String,
+    new_tick_size: String,
+    #[serde(default, deserialize_with = "deserialize_timestamp")]
+    timestamp: Option<i64>,
+}
+
 /// Price change event from CLOB (contains array of price changes)
 #[derive(Debug, Clone, Deserialize)]
 struct ClobPriceChangeEvent {
@@ -302,6 +613,9 @@ struct ClobPriceChangeEvent {
     #[allow(dead_code)]
     event_type: Option<String>,
     market: String,
+    /// Set on frames whose changes all belong to one asset (and may omit their own)
+    #[serde(default)]
+    asset_id: Option<String>,
     price_changes: Vec<ClobPriceChange>,
     timestamp: Option<i64>,
 }
@@ -309,12 +623,10 @@ struct ClobPriceChangeEvent {
 /// Individual price change within a price_change event
 #[derive(Debug, Clone, Deserialize)]
 struct ClobPriceChange {
-    asset_id: String,
-    #[allow(dead_code)]
+    #[serde(default)]
+    asset_id: Option<String>,
     price: String,
-    #[allow(dead_code)]
     size: String,
-    #[allow(dead_code)]
     side: String,
     best_bid: String,
     #[allow(dead_code)]
@@ -1,10 +1,20 @@
 // AIDEV-NOTE: EventEmitter trait - abstracts event emission for WebSocket clients
 // Allows polymarket-rs to be used without Tauri dependency
 
-use crate::types::{ConnectionStatus, ClobTrade, OrderBookSnapshot, PriceUpdate};
+use std::time::Duration;
+
+use crate::types::{
+    Balance, BookLevelChange, ConnectionStatus, ClobTrade, OrderBookSnapshot, OrderEvent, OrderFillEvent, PriceUpdate,
+    TickSizeChange, Token,
+};
 
 /// Trait for emitting WebSocket events
 /// Implement this trait to receive events from WebSocket clients
+///
+/// Stability: the five original methods (price update through connection status) are
+/// required. Every event kind added after them has an empty default body, so existing
+/// emitters keep compiling and only opt in to the events they care about.
+/// AIDEV-NOTE: New emit_* methods MUST ship with a default no-op - never add a required one
 pub trait EventEmitter: Send + Sync + 'static {
     /// Emit a price update event
     fn emit_price_update(&self, update: &PriceUpdate);
@@ -20,6 +30,30 @@ pub trait EventEmitter: Send + Sync + 'static {
 
     /// Emit connection status update
     fn emit_connection_status(&self, status: &ConnectionStatus);
+
+    // ----- Optional events (default no-op) -----
+
+    /// Emit a tick size change from the CLOB WebSocket
+    fn emit_tick_size_change(&self, _change: &TickSizeChange) {}
+
+    /// Emit one changed order book level from a CLOB price_change event
+    fn emit_book_level_change(&self, _change: &BookLevelChange) {}
+
+    /// Emit a fill/cancel of one of the user's orders (from `OrderWatcher`)
+    fn emit_order_update(&self, _event: &OrderEvent) {}
+
+    /// Emit a (partial) fill of one of the user's orders, alongside its `emit_order_update`
+    fn emit_fill(&self, _fill: &OrderFillEvent) {}
+
+    /// Emit a changed USDC balance (from `BalanceWatcher`)
+    fn emit_balance_update(&self, _balance: &Balance) {}
+
+    /// Emit a settled market (from `ResolutionWatcher`) with its winning outcome token
+    fn emit_market_resolved(&self, _condition_id: &str, _winning_token: &Token) {}
+
+    /// Emit a scheduled reconnect: `channel` is "clob" or "rtds", `attempt` is 1-based and
+    /// `next_delay` is how long the client waits before trying again
+    fn emit_reconnecting(&self, _channel: &str, _attempt: u32, _next_delay: Duration) {}
 }
 
 /// Trade from RTDS (different format than ClobTrade)
@@ -34,6 +68,31 @@ pub struct RtdsTrade {
     pub timestamp: Option<i64>,
 }
 
+/// One parsed WebSocket event (output of `parse_clob_message` / `parse_rtds_message`)
+#[derive(Debug, Clone)]
+pub enum WsEvent {
+    PriceUpdate(PriceUpdate),
+    OrderBook(OrderBookSnapshot),
+    Trade(ClobTrade),
+    TradeUpdate(RtdsTrade),
+    TickSizeChange(TickSizeChange),
+    BookLevelChange(BookLevelChange),
+}
+
+impl WsEvent {
+    /// Forward to the matching `EventEmitter` method
+    pub fn emit_to<E: EventEmitter + ?Sized>(&self, emitter: &E) {
+        match self {
+            WsEvent::PriceUpdate(update) => emitter.emit_price_update(update),
+            WsEvent::OrderBook(snapshot) => emitter.emit_orderbook_snapshot(snapshot),
+            WsEvent::Trade(trade) => emitter.emit_trade(trade),
+            WsEvent::TradeUpdate(trade) => emitter.emit_trade_update(trade),
+            WsEvent::TickSizeChange(change) => emitter.emit_tick_size_change(change),
+            WsEvent::BookLevelChange(change) => emitter.emit_book_level_change(change),
+        }
+    }
+}
+
 /// No-op event emitter for testing or headless operation
 pub struct NoOpEmitter;
 
@@ -111,6 +170,31 @@ mod tests {
         }
     }
 
+    /// Implements only the required methods - if this stops compiling, a new event
+    /// kind was added without a default body
+    struct MinimalEmitter;
+
+    impl EventEmitter for MinimalEmitter {
+        fn emit_price_update(&self, _update: &PriceUpdate) {}
+        fn emit_orderbook_snapshot(&self, _snapshot: &OrderBookSnapshot) {}
+        fn emit_trade(&self, _trade: &ClobTrade) {}
+        fn emit_trade_update(&self, _trade: &RtdsTrade) {}
+        fn emit_connection_status(&self, _status: &ConnectionStatus) {}
+    }
+
+    #[test]
+    fn test_minimal_emitter_accepts_optional_events() {
+        let emitter: Arc<dyn EventEmitter> = Arc::new(MinimalEmitter);
+
+        emitter.emit_balance_update(&Balance { balance: "1".to_string(), allowances: Default::default() });
+        emitter.emit_tick_size_change(&TickSizeChange {
+            asset_id: "123".to_string(),
+            old_tick_size: 0.01,
+            new_tick_size: 0.001,
+            timestamp: None,
+        });
+    }
+
     #[test]
     fn test_mock_emitter() {
         let emitter = MockEmitter::new();
@@ -120,6 +204,7 @@ mod tests {
             asset_id: "123".to_string(),
             price: 0.5,
             timestamp: None,
+            volume: None,
         };
 
         emitter.emit_price_update(&update);
@@ -1,35 +1,22 @@
 // AIDEV-NOTE: WebSocket manager - state machine with exponential backoff reconnection
 
-use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
 use std::time::Duration;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
+use tokio::task::JoinHandle;
+use tracing::{debug, info};
 
+use crate::error::ApiError;
 use crate::types::{ConnectionState, ConnectionStatus};
 use super::EventEmitter;
 
-/// Configuration for reconnection behavior
-#[derive(Debug, Clone)]
-pub struct ReconnectConfig {
-    /// Initial delay before first reconnect attempt
-    pub initial_delay: Duration,
-    /// Maximum delay between reconnect attempts
-    pub max_delay: Duration,
-    /// Multiplier for exponential backoff
-    pub multiplier: f64,
-    /// Maximum number of reconnect attempts (None = infinite)
-    pub max_attempts: Option<u32>,
-}
+pub use crate::backoff::ReconnectConfig;
 
-impl Default for ReconnectConfig {
-    fn default() -> Self {
-        Self {
-            initial_delay: Duration::from_secs(1),
-            max_delay: Duration::from_secs(30),
-            multiplier: 2.0,
-            max_attempts: None, // Keep trying forever
-        }
-    }
-}
+/// Default cap on RTDS market subscriptions across all clients
+pub const DEFAULT_MAX_RTDS_MARKETS: usize = 100;
+/// Default cap on CLOB token subscriptions across all clients
+pub const DEFAULT_MAX_CLOB_TOKENS: usize = 50;
 
 /// Shared state for a WebSocket connection
 pub struct WebSocketState {
@@ -48,12 +35,31 @@ impl Default for WebSocketState {
     }
 }
 
+/// Point-in-time view of both connections for dashboards
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ConnectionStatistics {
+    pub rtds: ConnectionState,
+    pub clob: ConnectionState,
+    pub rtds_reconnect_attempts: u32,
+    pub clob_reconnect_attempts: u32,
+    /// Time since the last RTDS message (None if nothing received yet)
+    pub rtds_idle: Option<Duration>,
+    /// Time since the last CLOB message (None if nothing received yet)
+    pub clob_idle: Option<Duration>,
+}
+
 /// Central manager for all WebSocket connections
 /// Generic over E: EventEmitter to allow Tauri or other event systems
 pub struct WebSocketManager<E: EventEmitter> {
     emitter: Arc<E>,
     rtds_state: Arc<RwLock<WebSocketState>>,
     clob_state: Arc<RwLock<WebSocketState>>,
+    stats_emitter_handle: Mutex<Option<JoinHandle<()>>>,
+    max_rtds_markets: usize,
+    max_clob_tokens: usize,
+    /// Live subscription counts, reserved/released by the clients
+    rtds_subscriptions: AtomicUsize,
+    clob_subscriptions: AtomicUsize,
 }
 
 impl<E: EventEmitter> WebSocketManager<E> {
@@ -62,9 +68,21 @@ impl<E: EventEmitter> WebSocketManager<E> {
             emitter,
             rtds_state: Arc::new(RwLock::new(WebSocketState::default())),
             clob_state: Arc::new(RwLock::new(WebSocketState::default())),
+            stats_emitter_handle: Mutex::new(None),
+            max_rtds_markets: DEFAULT_MAX_RTDS_MARKETS,
+            max_clob_tokens: DEFAULT_MAX_CLOB_TOKENS,
+            rtds_subscriptions: AtomicUsize::new(0),
+            clob_subscriptions: AtomicUsize::new(0),
         }
     }
 
+    /// Override the subscription caps (defaults: 100 RTDS markets, 50 CLOB tokens)
+    pub fn with_subscription_limits(mut self, max_rtds_markets: usize, max_clob_tokens: usize) -> Self {
+        self.max_rtds_markets = max_rtds_markets;
+        self.max_clob_tokens = max_clob_tokens;
+        self
+    }
+
     /// Get the event emitter
     pub fn emitter(&self) -> &Arc<E> {
         &self.emitter
@@ -81,29 +99,30 @@ impl<E: EventEmitter> WebSocketManager<E> {
     }
 
     /// Update RTDS connection state and emit event
+    /// AIDEV-NOTE: Connected does NOT reset reconnect attempts - a connection that drops
+    /// right away must keep backing off. The clients call reset_rtds_reconnect once the
+    /// connection has lasted `ReconnectConfig::stability_threshold`.
     pub fn set_rtds_state(&self, state: ConnectionState) {
-        {
-            let mut ws_state = self.rtds_state.write();
-            ws_state.state = state;
-            if state == ConnectionState::Connected {
-                ws_state.reconnect_attempts = 0;
-            }
-        }
+        self.rtds_state.write().state = state;
         self.emit_connection_status();
     }
 
-    /// Update CLOB connection state and emit event
+    /// Update CLOB connection state and emit event (see `set_rtds_state`)
     pub fn set_clob_state(&self, state: ConnectionState) {
-        {
-            let mut ws_state = self.clob_state.write();
-            ws_state.state = state;
-            if state == ConnectionState::Connected {
-                ws_state.reconnect_attempts = 0;
-            }
-        }
+        self.clob_state.write().state = state;
         self.emit_connection_status();
     }
 
+    /// Reset RTDS reconnect attempts after a stable connection
+    pub fn reset_rtds_reconnect(&self) {
+        self.rtds_state.write().reconnect_attempts = 0;
+    }
+
+    /// Reset CLOB reconnect attempts after a stable connection
+    pub fn reset_clob_reconnect(&self) {
+        self.clob_state.write().reconnect_attempts = 0;
+    }
+
     /// Increment reconnect attempts for RTDS and return current count
     pub fn increment_rtds_reconnect(&self) -> u32 {
         let mut state = self.rtds_state.write();
@@ -118,12 +137,70 @@ impl<E: EventEmitter> WebSocketManager<E> {
         state.reconnect_attempts
     }
 
+    /// Count a failed RTDS connection and schedule the next attempt
+    /// Returns the delay to sleep (after emitting `emit_reconnecting`), or None once
+    /// `max_attempts` is reached and the state is Failed
+    pub fn begin_rtds_reconnect(&self, config: &ReconnectConfig) -> Option<Duration> {
+        let attempts = self.increment_rtds_reconnect();
+        if config.max_attempts.is_some_and(|max| attempts >= max) {
+            self.set_rtds_state(ConnectionState::Failed);
+            return None;
+        }
+        self.set_rtds_state(ConnectionState::Reconnecting);
+        Some(self.announce_reconnect("rtds", attempts, config))
+    }
+
+    /// Count a failed CLOB connection and schedule the next attempt (see `begin_rtds_reconnect`)
+    pub fn begin_clob_reconnect(&self, config: &ReconnectConfig) -> Option<Duration> {
+        let attempts = self.increment_clob_reconnect();
+        if config.max_attempts.is_some_and(|max| attempts >= max) {
+            self.set_clob_state(ConnectionState::Failed);
+            return None;
+        }
+        self.set_clob_state(ConnectionState::Reconnecting);
+        Some(self.announce_reconnect("clob", attempts, config))
+    }
+
+    fn announce_reconnect(&self, channel: &str, attempts: u32, config: &ReconnectConfig) -> Duration {
+        let delay = Self::calculate_reconnect_delay(attempts, config);
+        info!("{} reconnecting in {:?} (attempt {})", channel.to_uppercase(), delay, attempts);
+        self.emitter.emit_reconnecting(channel, attempts, delay);
+        delay
+    }
+
+    /// Number of RTDS market subscriptions currently held by clients
+    pub fn current_rtds_subscription_count(&self) -> usize {
+        self.rtds_subscriptions.load(Ordering::SeqCst)
+    }
+
+    /// Number of CLOB token subscriptions currently held by clients
+    pub fn current_clob_subscription_count(&self) -> usize {
+        self.clob_subscriptions.load(Ordering::SeqCst)
+    }
+
+    /// Claim `count` more RTDS market subscriptions, or fail without claiming any
+    pub fn reserve_rtds_subscriptions(&self, count: usize) -> Result<(), ApiError> {
+        reserve(&self.rtds_subscriptions, self.max_rtds_markets, count)
+    }
+
+    /// Give back RTDS market subscriptions claimed with `reserve_rtds_subscriptions`
+    pub fn release_rtds_subscriptions(&self, count: usize) {
+        release(&self.rtds_subscriptions, count);
+    }
+
+    /// Claim `count` more CLOB token subscriptions, or fail without claiming any
+    pub fn reserve_clob_subscriptions(&self, count: usize) -> Result<(), ApiError> {
+        reserve(&self.clob_subscriptions, self.max_clob_tokens, count)
+    }
+
+    /// Give back CLOB token subscriptions claimed with `reserve_clob_subscriptions`
+    pub fn release_clob_subscriptions(&self, count: usize) {
+        release(&self.clob_subscriptions, count);
+    }
+
     /// Calculate delay for next reconnection attempt using exponential backoff
     pub fn calculate_reconnect_delay(attempts: u32, config: &ReconnectConfig) -> Duration {
-        let delay_secs = config.initial_delay.as_secs_f64()
-            * config.multiplier.powi(attempts.saturating_sub(1) as i32);
-        let capped_delay = delay_secs.min(config.max_delay.as_secs_f64());
-        Duration::from_secs_f64(capped_delay)
+        crate::backoff::backoff_delay(attempts, config)
     }
 
     /// Emit current connection status
@@ -145,6 +222,90 @@ impl<E: EventEmitter> WebSocketManager<E> {
         let mut state = self.clob_state.write();
         state.last_message_time = Some(std::time::Instant::now());
     }
+
+    /// Time since the last CLOB message (None if nothing received yet)
+    pub fn clob_idle_time(&self) -> Option<Duration> {
+        self.clob_state.read().last_message_time.map(|t| t.elapsed())
+    }
+
+    /// Snapshot of state, reconnect attempts and idle time for both connections
+    pub fn statistics(&self) -> ConnectionStatistics {
+        let rtds = self.rtds_state.read();
+        let clob = self.clob_state.read();
+        ConnectionStatistics {
+            rtds: rtds.state,
+            clob: clob.state,
+            rtds_reconnect_attempts: rtds.reconnect_attempts,
+            clob_reconnect_attempts: clob.reconnect_attempts,
+            rtds_idle: rtds.last_message_time.map(|t| t.elapsed()),
+            clob_idle: clob.last_message_time.map(|t| t.elapsed()),
+        }
+    }
+
+    /// Push connection status every `interval`, not just on state transitions.
+    /// Panics if `interval` is zero.
+    /// AIDEV-NOTE: Replaces any running emitter. The task holds a Weak reference,
+    /// so it ends on its own once the manager is dropped.
+    pub fn start_stats_emitter(self: &Arc<Self>, interval: Duration) {
+        let manager: Weak<Self> = Arc::downgrade(self);
+        let handle = tokio::spawn(async move {
+            let mut ticker = tokio::time::interval(interval);
+            ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+            loop {
+                ticker.tick().await;
+                let Some(manager) = manager.upgrade() else {
+                    break;
+                };
+                let stats = manager.statistics();
+                debug!(?stats, "Periodic connection stats");
+                manager.emitter.emit_connection_status(&ConnectionStatus {
+                    rtds: stats.rtds,
+                    clob: stats.clob,
+                });
+            }
+        });
+
+        if let Some(old) = self.stats_emitter_handle.lock().replace(handle) {
+            old.abort();
+        }
+    }
+
+    /// Stop the periodic stats emitter, if running
+    pub fn stop_stats_emitter(&self) {
+        if let Some(handle) = self.stats_emitter_handle.lock().take() {
+            handle.abort();
+        }
+    }
+
+    /// Whether the periodic stats emitter is running
+    pub fn stats_emitter_running(&self) -> bool {
+        self.stats_emitter_handle
+            .lock()
+            .as_ref()
+            .is_some_and(|h| !h.is_finished())
+    }
+}
+
+// AIDEV-NOTE: fetch_update so concurrent reservations can never overshoot the cap
+fn reserve(counter: &AtomicUsize, max: usize, count: usize) -> Result<(), ApiError> {
+    counter
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |current| {
+            current.checked_add(count).filter(|&total| total <= max)
+        })
+        .map(|_| ())
+        .map_err(|_| ApiError::WebSocket("subscription limit exceeded".to_string()))
+}
+
+fn release(counter: &AtomicUsize, count: usize) {
+    let _ = counter.fetch_update(Ordering::SeqCst, Ordering::SeqCst, |current| {
+        Some(current.saturating_sub(count))
+    });
+}
+
+impl<E: EventEmitter> Drop for WebSocketManager<E> {
+    fn drop(&mut self) {
+        self.stop_stats_emitter();
+    }
 }
 
 #[cfg(test)]
@@ -196,8 +357,42 @@ mod tests {
         assert_eq!(manager.increment_rtds_reconnect(), 1);
         assert_eq!(manager.increment_rtds_reconnect(), 2);
 
-        // Reset when connected
+        // Connecting alone doesn't reset - only a stable connection does
         manager.set_rtds_state(ConnectionState::Connected);
+        assert_eq!(manager.increment_rtds_reconnect(), 3);
+
+        manager.reset_rtds_reconnect();
         assert_eq!(manager.increment_rtds_reconnect(), 1);
+        assert_eq!(manager.increment_clob_reconnect(), 1);
+        manager.reset_clob_reconnect();
+        assert_eq!(manager.statistics().clob_reconnect_attempts, 0);
+    }
+
+    #[test]
+    fn test_subscription_limits() {
+        let manager = WebSocketManager::new(Arc::new(NoOpEmitter)).with_subscription_limits(3, 1);
+
+        manager.reserve_rtds_subscriptions(2).unwrap();
+        // A failed reservation claims nothing
+        let err = manager.reserve_rtds_subscriptions(2).unwrap_err();
+        assert!(err.to_string().contains("subscription limit exceeded"), "{}", err);
+        assert_eq!(manager.current_rtds_subscription_count(), 2);
+        manager.reserve_rtds_subscriptions(1).unwrap();
+
+        manager.release_rtds_subscriptions(5);
+        assert_eq!(manager.current_rtds_subscription_count(), 0);
+
+        manager.reserve_clob_subscriptions(1).unwrap();
+        assert!(manager.reserve_clob_subscriptions(1).is_err());
+        assert_eq!(manager.current_clob_subscription_count(), 1);
+    }
+
+    #[test]
+    fn test_default_subscription_limits() {
+        let manager = WebSocketManager::new(Arc::new(NoOpEmitter));
+        manager.reserve_rtds_subscriptions(DEFAULT_MAX_RTDS_MARKETS).unwrap();
+        manager.reserve_clob_subscriptions(DEFAULT_MAX_CLOB_TOKENS).unwrap();
+        assert!(manager.reserve_rtds_subscriptions(1).is_err());
+        assert!(manager.reserve_clob_subscriptions(1).is_err());
     }
 }
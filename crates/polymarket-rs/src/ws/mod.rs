@@ -4,11 +4,13 @@ mod events;
 mod manager;
 mod rtds;
 mod clob;
+mod replay;
 
 #[cfg(test)]
 mod tests;
 
-pub use events::{EventEmitter, NoOpEmitter, RtdsTrade};
-pub use manager::{WebSocketManager, WebSocketState, ReconnectConfig};
-pub use rtds::RtdsClient;
-pub use clob::ClobWebSocket;
+pub use events::{EventEmitter, NoOpEmitter, RtdsTrade, WsEvent};
+pub use manager::{ConnectionStatistics, WebSocketManager, WebSocketState, ReconnectConfig};
+pub use rtds::{parse_rtds_message, RtdsClient};
+pub use clob::{parse_clob_message, ClobCache, ClobWebSocket};
+pub use replay::{read_frames, FrameRecorder};
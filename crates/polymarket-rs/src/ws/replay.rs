@@ -0,0 +1,39 @@
+// AIDEV-NOTE: Frame recording/replay - capture raw WebSocket text frames from a live session,
+// then feed them back through the clients' handle_message offline to debug parsing
+
+use std::fs::File;
+use std::io::{self, BufRead, BufReader, LineWriter, Write};
+use std::path::Path;
+
+use parking_lot::Mutex;
+use tracing::warn;
+
+/// Appends every received text frame to a file, one frame per line
+/// AIDEV-NOTE: Line breaks inside a frame are written as spaces - JSON can't contain raw
+/// newlines inside strings, so the frame still parses the same way on replay
+pub struct FrameRecorder {
+    writer: Mutex<LineWriter<File>>,
+}
+
+impl FrameRecorder {
+    /// Start a new recording at `path` (truncates an existing file)
+    pub fn create(path: impl AsRef<Path>) -> io::Result<Self> {
+        Ok(Self { writer: Mutex::new(LineWriter::new(File::create(path)?)) })
+    }
+
+    /// Append one frame. Write errors are logged, never fatal to the connection.
+    pub fn record(&self, text: &str) {
+        let line = text.replace(['\r', '\n'], " ");
+        if let Err(e) = writeln!(self.writer.lock(), "{}", line) {
+            warn!("Failed to record WebSocket frame: {}", e);
+        }
+    }
+}
+
+/// Frames of a recording in order (blank lines skipped)
+pub fn read_frames(path: impl AsRef<Path>) -> io::Result<Vec<String>> {
+    BufReader::new(File::open(path)?)
+        .lines()
+        .filter(|line| !matches!(line, Ok(l) if l.trim().is_empty()))
+        .collect()
+}
@@ -2,16 +2,22 @@
 // Connects to wss://ws-live-data.polymarket.com (no /ws suffix!)
 // Subscription format: { action, subscriptions: [{ topic, type, filters }] }
 
+use std::io;
+use std::path::Path;
 use std::sync::Arc;
 use futures_util::{SinkExt, StreamExt};
+use parking_lot::RwLock;
 use serde::{Deserialize, Serialize};
 use tokio::sync::mpsc;
 use tokio_tungstenite::{connect_async, tungstenite::Message};
 use tracing::{debug, error, info};
 
+use crate::api::body_preview;
+use crate::error::ApiError;
 use crate::types::{ConnectionState, PriceUpdate};
-use super::events::{EventEmitter, RtdsTrade};
+use super::events::{EventEmitter, RtdsTrade, WsEvent};
 use super::manager::{ReconnectConfig, WebSocketManager};
+use super::replay::{read_frames, FrameRecorder};
 
 // AIDEV-NOTE: URL must NOT have /ws suffix - that returns 403
 const RTDS_URL: &str = "wss://ws-live-data.polymarket.com";
@@ -20,6 +26,15 @@ const RTDS_URL: &str = "wss://ws-live-data.polymarket.com";
 pub struct RtdsClient<E: EventEmitter> {
     manager: Arc<WebSocketManager<E>>,
     shutdown_tx: Option<mpsc::Sender<()>>,
+    /// Sends newly added markets to the running connection task
+    subscribe_tx: Option<mpsc::UnboundedSender<Vec<String>>>,
+    /// All subscribed markets, held against the manager's RTDS subscription limit -
+    /// re-read on every (re)connect
+    markets: Arc<RwLock<Vec<String>>>,
+    /// Raw frame capture for offline replay (see `record_frames_to`)
+    recorder: Option<Arc<FrameRecorder>>,
+    /// WebSocket endpoint (RTDS_URL unless overridden)
+    url: String,
 }
 
 impl<E: EventEmitter> RtdsClient<E> {
@@ -27,24 +42,58 @@ impl<E: EventEmitter> RtdsClient<E> {
         Self {
             manager,
             shutdown_tx: None,
+            subscribe_tx: None,
+            markets: Arc::new(RwLock::new(Vec::new())),
+            recorder: None,
+            url: RTDS_URL.to_string(),
         }
     }
 
+    /// Connect to a different endpoint (local mock servers)
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = url.into();
+        self
+    }
+
+    /// Record every received text frame to `path` (takes effect on the next `connect`)
+    pub fn record_frames_to(&mut self, path: impl AsRef<Path>) -> io::Result<()> {
+        self.recorder = Some(Arc::new(FrameRecorder::create(path)?));
+        Ok(())
+    }
+
+    /// Feed a recording through `handle_message` as if it arrived live
+    /// Returns the number of frames replayed.
+    pub fn replay_from(&self, path: impl AsRef<Path>) -> io::Result<usize> {
+        let frames = read_frames(path)?;
+        for frame in &frames {
+            Self::handle_message(self.manager.emitter(), frame);
+        }
+        Ok(frames.len())
+    }
+
     /// Start the RTDS WebSocket connection
-    pub async fn connect(&mut self, markets: Vec<String>) {
+    /// Fails without connecting if `markets` exceed the RTDS subscription limit.
+    pub async fn connect(&mut self, markets: Vec<String>) -> Result<(), ApiError> {
+        self.release_subscriptions();
+        self.add_markets(markets)?;
+
         let (shutdown_tx, mut shutdown_rx) = mpsc::channel::<()>(1);
         self.shutdown_tx = Some(shutdown_tx);
+        let (subscribe_tx, mut subscribe_rx) = mpsc::unbounded_channel::<Vec<String>>();
+        self.subscribe_tx = Some(subscribe_tx);
 
         let manager = self.manager.clone();
-        let markets = markets.clone();
+        let markets = self.markets.clone();
+        let recorder = self.recorder.clone();
+        let url = self.url.clone();
 
         tokio::spawn(async move {
-            let config = ReconnectConfig::default();
+            let config = ReconnectConfig::from_env();
 
             loop {
                 manager.set_rtds_state(ConnectionState::Connecting);
 
-                match Self::connect_and_run(&manager, &markets, &mut shutdown_rx).await {
+                match Self::connect_and_run(&manager, &url, &markets, &config, recorder.as_deref(), &mut subscribe_rx, &mut shutdown_rx).await {
                     Ok(()) => {
                         info!("RTDS connection closed gracefully");
                         break;
@@ -52,19 +101,10 @@ impl<E: EventEmitter> RtdsClient<E> {
                     Err(e) => {
                         error!("RTDS connection error: {}", e);
 
-                        let attempts = manager.increment_rtds_reconnect();
-
-                        if let Some(max) = config.max_attempts {
-                            if attempts >= max {
-                                manager.set_rtds_state(ConnectionState::Failed);
-                                error!("RTDS max reconnect attempts ({}) reached", max);
-                                break;
-                            }
-                        }
-
-                        manager.set_rtds_state(ConnectionState::Reconnecting);
-                        let delay = WebSocketManager::<E>::calculate_reconnect_delay(attempts, &config);
-                        info!("RTDS reconnecting in {:?} (attempt {})", delay, attempts);
+                        let Some(delay) = manager.begin_rtds_reconnect(&config) else {
+                            error!("RTDS max reconnect attempts ({:?}) reached", config.max_attempts);
+                            break;
+                        };
 
                         tokio::select! {
                             _ = tokio::time::sleep(delay) => continue,
@@ -79,47 +119,108 @@ impl<E: EventEmitter> RtdsClient<E> {
 
             manager.set_rtds_state(ConnectionState::Disconnected);
         });
+
+        Ok(())
+    }
+
+    /// Markets currently subscribed (restored on every reconnect)
+    pub fn subscribed_markets(&self) -> Vec<String> {
+        self.markets.read().clone()
+    }
+
+    /// Record markets not already subscribed, reserving them against the RTDS limit
+    /// Returns the added markets, or an error (and nothing added) if over the limit.
+    fn add_markets(&self, market_ids: Vec<String>) -> Result<Vec<String>, ApiError> {
+        let mut current = self.markets.write();
+        let mut added: Vec<String> = Vec::new();
+        for market_id in market_ids {
+            if !current.contains(&market_id) && !added.contains(&market_id) {
+                added.push(market_id);
+            }
+        }
+        self.manager.reserve_rtds_subscriptions(added.len())?;
+        current.extend(added.iter().cloned());
+        Ok(added)
+    }
+
+    /// Forget all markets and return them to the manager's subscription count
+    fn release_subscriptions(&self) {
+        let mut current = self.markets.write();
+        self.manager.release_rtds_subscriptions(current.len());
+        current.clear();
+    }
+
+    /// Subscribe message for `markets` (clob_market price changes)
+    // AIDEV-NOTE: filters is a JSON array string of token IDs
+    fn subscribe_message(markets: &[String]) -> Result<String, serde_json::Error> {
+        let subscribe_msg = RtdsSubscribe {
+            action: "subscribe".to_string(),
+            subscriptions: vec![RtdsSubscription {
+                topic: "clob_market".to_string(),
+                msg_type: "price_change".to_string(),
+                filters: serde_json::to_string(markets)?,
+            }],
+        };
+        serde_json::to_string(&subscribe_msg)
     }
 
     async fn connect_and_run(
         manager: &Arc<WebSocketManager<E>>,
-        markets: &[String],
+        url: &str,
+        markets: &RwLock<Vec<String>>,
+        config: &ReconnectConfig,
+        recorder: Option<&FrameRecorder>,
+        subscribe_rx: &mut mpsc::UnboundedReceiver<Vec<String>>,
         shutdown_rx: &mut mpsc::Receiver<()>,
     ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
-        info!("Connecting to RTDS: {}", RTDS_URL);
+        info!("Connecting to RTDS: {}", url);
 
-        let (ws_stream, _) = connect_async(RTDS_URL).await?;
+        let (ws_stream, _) = connect_async(url).await?;
         let (mut write, mut read) = ws_stream.split();
 
         manager.set_rtds_state(ConnectionState::Connected);
         info!("RTDS connected successfully");
 
-        // Subscribe to markets using token IDs
-        // AIDEV-NOTE: filters is a JSON array string of token IDs
-        if !markets.is_empty() {
-            let filters = serde_json::to_string(&markets)?;
-            let subscribe_msg = RtdsSubscribe {
-                action: "subscribe".to_string(),
-                subscriptions: vec![RtdsSubscription {
-                    topic: "clob_market".to_string(),
-                    msg_type: "price_change".to_string(),
-                    filters,
-                }],
-            };
+        // Drop queued additions - they are already in `markets`
+        while subscribe_rx.try_recv().is_ok() {}
 
-            let msg = serde_json::to_string(&subscribe_msg)?;
+        // Subscribe to markets using token IDs (includes any added since connect)
+        let current = markets.read().clone();
+        if !current.is_empty() {
+            let msg = Self::subscribe_message(&current)?;
             debug!("RTDS subscribe message: {}", msg);
             write.send(Message::Text(msg)).await?;
-            info!("Subscribed to {} markets", markets.len());
+            info!("Subscribed to {} markets", current.len());
         }
 
+        // Backoff only resets once the connection has proven stable
+        let mut stable_at = Some(tokio::time::Instant::now() + config.stability_threshold);
+
         // Handle incoming messages
         loop {
             tokio::select! {
+                added = subscribe_rx.recv() => {
+                    let Some(added) = added else {
+                        // Client dropped
+                        return Ok(());
+                    };
+                    let msg = Self::subscribe_message(&added)?;
+                    debug!("RTDS subscribe message: {}", msg);
+                    write.send(Message::Text(msg)).await?;
+                    info!("Subscribed to {} more markets", added.len());
+                }
+                _ = tokio::time::sleep_until(stable_at.unwrap_or_else(tokio::time::Instant::now)), if stable_at.is_some() => {
+                    debug!("RTDS connection stable, resetting reconnect attempts");
+                    manager.reset_rtds_reconnect();
+                    stable_at = None;
+                }
                 msg = read.next() => {
                     match msg {
                         Some(Ok(Message::Text(text))) => {
                             manager.record_rtds_message();
+                            if let Some(recorder) = recorder {
+                                recorder.record(&text);
+                            }
                             Self::handle_message(manager.emitter(), &text);
                         }
                         Some(Ok(Message::Ping(data))) => {
@@ -147,103 +248,129 @@ impl<E: EventEmitter> RtdsClient<E> {
         }
     }
 
-    // AIDEV-NOTE: RTDS uses abbreviated field names: m=market, pc=price_changes, a=asset_id, etc.
-    fn handle_message(emitter: &Arc<E>, text: &str) {
-        // Skip empty messages (acknowledgments/heartbeats)
-        if text.is_empty() || text == "{}" {
-            return;
+    /// Parse one text frame (see `parse_rtds_message`) and emit the events
+    /// Public so frames can be pushed through the exact live path offline
+    pub fn handle_message(emitter: &Arc<E>, text: &str) {
+        for event in parse_rtds_message(text) {
+            event.emit_to(emitter.as_ref());
         }
+    }
 
-        // Try to parse as wrapped RTDS message with abbreviated fields
-        // Format: { connection_id, payload: { m: market, pc: [{ a, p, s, b, k, h }] } }
-        match serde_json::from_str::<RtdsMessageWrapper>(text) {
-            Ok(wrapper) => {
-                if let Some(payload) = wrapper.payload {
-                    let market = payload.m;
-                    for change in payload.pc {
-                        // Try to get price from best_bid (b), fall back to price (p)
-                        let price_str = change.b.as_ref().or(change.p.as_ref());
-                        if let Some(price_str) = price_str {
-                            if let Ok(price) = price_str.parse::<f64>() {
-                                let update = PriceUpdate {
-                                    market: market.clone(),
-                                    asset_id: change.a.clone(),
-                                    price,
-                                    timestamp: None, // RTDS doesn't include timestamp in this format
-                                };
-                                debug!("RTDS price update: {} -> {:.4}", change.a, price);
-                                emitter.emit_price_update(&update);
-                            }
-                        }
-                    }
-                }
-                return;
-            }
-            Err(e) => {
-                // Log parsing error for debugging with more detail
-                let preview = if text.len() > 500 { &text[..500] } else { text };
-                debug!("RTDS wrapper parse failed: {} - msg: {}", e, preview);
+    /// Add market subscriptions, sending them on the live connection if there is one
+    /// Returns the markets that were not already subscribed, or an error (and nothing
+    /// added) if they would exceed the manager's RTDS subscription limit.
+    pub async fn subscribe(&self, market_ids: Vec<String>) -> Result<Vec<String>, ApiError> {
+        let added = self.add_markets(market_ids)?;
+
+        // AIDEV-NOTE: If the task is mid-reconnect the send still queues; either way
+        // the markets are in `markets` and get subscribed on the next connect
+        if let Some(tx) = &self.subscribe_tx {
+            if !added.is_empty() {
+                let _ = tx.send(added.clone());
             }
         }
 
-        // Try to parse as generic JSON for other message types
-        if let Ok(value) = serde_json::from_str::<serde_json::Value>(text) {
-            // Check for array of price changes
-            if let Some(arr) = value.as_array() {
-                for item in arr {
-                    if let Ok(update) = serde_json::from_value::<RtdsPriceUpdate>(item.clone()) {
-                        debug!("Price update: {:?}", update);
-                        let price_update = PriceUpdate {
-                            market: update.market,
-                            asset_id: String::new(), // Legacy format doesn't have asset_id
-                            price: update.price,
-                            timestamp: update.timestamp,
-                        };
-                        emitter.emit_price_update(&price_update);
-                    }
-                }
-                return;
-            }
+        Ok(added)
+    }
 
-            // Single price update object
-            if let Ok(price_update) = serde_json::from_value::<RtdsPriceUpdate>(value.clone()) {
-                debug!("Price update: {:?}", price_update);
-                let update = PriceUpdate {
-                    market: price_update.market,
-                    asset_id: String::new(),
-                    price: price_update.price,
-                    timestamp: price_update.timestamp,
-                };
-                emitter.emit_price_update(&update);
-                return;
-            }
+    /// Disconnect from RTDS
+    pub fn disconnect(&mut self) {
+        if let Some(tx) = self.shutdown_tx.take() {
+            let _ = tx.try_send(());
+        }
+    }
+}
 
-            // Try to parse as trade
-            if let Ok(trade) = serde_json::from_value::<RtdsTrade>(value.clone()) {
-                debug!("Trade: {:?}", trade);
-                emitter.emit_trade_update(&trade);
-                return;
-            }
+// AIDEV-NOTE: Markets count against the shared manager until the client is gone
+impl<E: EventEmitter> Drop for RtdsClient<E> {
+    fn drop(&mut self) {
+        self.release_subscriptions();
+    }
+}
 
-            // Log unknown message structure (first 200 chars)
-            let preview = if text.len() > 200 { &text[..200] } else { text };
-            debug!("Unknown RTDS message structure: {}", preview);
-        } else {
-            debug!("Failed to parse RTDS message as JSON: {}", &text[..text.len().min(100)]);
+/// Parse one RTDS text frame into events, without side effects
+/// Acknowledgments, heartbeats and unknown frames yield no events.
+// AIDEV-NOTE: RTDS uses abbreviated field names: m=market, pc=price_changes, a=asset_id, etc.
+pub fn parse_rtds_message(text: &str) -> Vec<WsEvent> {
+    // Skip empty messages (acknowledgments/heartbeats)
+    if text.is_empty() || text == "{}" {
+        return Vec::new();
+    }
+
+    // Try to parse as wrapped RTDS message with abbreviated fields
+    // Format: { connection_id, payload: { m: market, pc: [{ a, p, s, b, k, h }] } }
+    match serde_json::from_str::<RtdsMessageWrapper>(text) {
+        Ok(wrapper) => {
+            let Some(payload) = wrapper.payload else {
+                return Vec::new();
+            };
+            let market = payload.m;
+            return payload
+                .pc
+                .into_iter()
+                .filter_map(|change| {
+                    // Try to get price from best_bid (b), fall back to price (p)
+                    let price = change.b.as_ref().or(change.p.as_ref())?.parse::<f64>().ok()?;
+                    debug!("RTDS price update: {} -> {:.4}", change.a, price);
+                    Some(WsEvent::PriceUpdate(PriceUpdate {
+                        market: market.clone(),
+                        asset_id: change.a,
+                        price,
+                        timestamp: None, // RTDS doesn't include timestamp in this format
+                        volume: change.s.as_ref().and_then(|s| s.parse().ok()),
+                    }))
+                })
+                .collect();
+        }
+        Err(e) => {
+            // Log parsing error for debugging with more detail
+            debug!("RTDS wrapper parse failed: {} - msg: {}", e, body_preview(text));
         }
     }
 
-    /// Subscribe to additional markets while connected
-    pub async fn subscribe(&self, _market_ids: Vec<String>) -> Result<(), String> {
-        // TODO: Implement runtime subscription - requires keeping the write half accessible
-        Ok(())
+    // Try to parse as generic JSON for other message types
+    let Ok(value) = serde_json::from_str::<serde_json::Value>(text) else {
+        debug!("Failed to parse RTDS message as JSON: {}", body_preview(text));
+        return Vec::new();
+    };
+
+    // Check for array of price changes
+    if let Some(arr) = value.as_array() {
+        return arr
+            .iter()
+            .filter_map(|item| serde_json::from_value::<RtdsPriceUpdate>(item.clone()).ok())
+            .map(|update| {
+                debug!("Price update: {:?}", update);
+                WsEvent::PriceUpdate(legacy_price_update(update))
+            })
+            .collect();
     }
 
-    /// Disconnect from RTDS
-    pub fn disconnect(&mut self) {
-        if let Some(tx) = self.shutdown_tx.take() {
-            let _ = tx.try_send(());
-        }
+    // Single price update object
+    if let Ok(update) = serde_json::from_value::<RtdsPriceUpdate>(value.clone()) {
+        debug!("Price update: {:?}", update);
+        return vec![WsEvent::PriceUpdate(legacy_price_update(update))];
+    }
+
+    // Try to parse as trade
+    if let Ok(trade) = serde_json::from_value::<RtdsTrade>(value) {
+        debug!("Trade: {:?}", trade);
+        return vec![WsEvent::TradeUpdate(trade)];
+    }
+
+    // Log unknown message structure
+    debug!("Unknown RTDS message structure: {}", body_preview(text));
+    Vec::new()
+}
+
+/// Legacy unwrapped price format - has no asset_id
+fn legacy_price_update(update: RtdsPriceUpdate) -> PriceUpdate {
+    PriceUpdate {
+        market: update.market,
+        asset_id: String::new(),
+        price: update.price,
+        timestamp: update.timestamp,
+        volume: None,
     }
 }
 
@@ -288,7 +415,6 @@ struct RtdsPayload {
 struct RtdsPriceChange {
     a: String,                      // asset_id (token_id) - always present
     p: Option<String>,              // price
-    #[allow(dead_code)]
     s: Option<String>,              // size
     b: Option<String>,              // best_bid
     #[allow(dead_code)]
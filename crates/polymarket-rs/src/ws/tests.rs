@@ -4,12 +4,20 @@
 mod tests {
     use std::sync::atomic::{AtomicUsize, Ordering};
     use std::sync::Arc;
+    use std::time::Duration;
 
+    use parking_lot::Mutex;
+
+    use crate::api::order::OrderSide;
     use crate::types::{
-        ClobTrade, ConnectionState, ConnectionStatus, OrderBookLevel, OrderBookSnapshot, PriceUpdate,
+        BookLevelChange, ClobTrade, ConnectionState, ConnectionStatus, OrderBookLevel, OrderBookSnapshot, PriceUpdate,
+        TickSizeChange,
     };
-    use crate::ws::events::{EventEmitter, RtdsTrade};
-    use crate::ws::manager::WebSocketManager;
+    use crate::ws::events::{EventEmitter, RtdsTrade, WsEvent};
+    use crate::ws::clob::{parse_clob_message, ClobCache, ClobWebSocket};
+    use crate::ws::manager::{ReconnectConfig, WebSocketManager};
+    use crate::ws::rtds::{parse_rtds_message, RtdsClient};
+    use crate::ws::replay::FrameRecorder;
 
     // ==================== Mock EventEmitter ====================
 
@@ -21,6 +29,9 @@ mod tests {
         trade_count: AtomicUsize,
         rtds_trade_count: AtomicUsize,
         connection_count: AtomicUsize,
+        last_price_update: Mutex<Option<PriceUpdate>>,
+        tick_size_changes: Mutex<Vec<TickSizeChange>>,
+        reconnects: Mutex<Vec<(String, u32, Duration)>>,
     }
 
     impl MockEmitter {
@@ -39,11 +50,24 @@ mod tests {
         fn trades(&self) -> usize {
             self.trade_count.load(Ordering::SeqCst)
         }
+
+        fn last_price_update(&self) -> Option<PriceUpdate> {
+            self.last_price_update.lock().clone()
+        }
+
+        fn tick_size_changes(&self) -> Vec<TickSizeChange> {
+            self.tick_size_changes.lock().clone()
+        }
+
+        fn reconnects(&self) -> Vec<(String, u32, Duration)> {
+            self.reconnects.lock().clone()
+        }
     }
 
     impl EventEmitter for MockEmitter {
-        fn emit_price_update(&self, _update: &PriceUpdate) {
+        fn emit_price_update(&self, update: &PriceUpdate) {
             self.price_update_count.fetch_add(1, Ordering::SeqCst);
+            *self.last_price_update.lock() = Some(update.clone());
         }
 
         fn emit_orderbook_snapshot(&self, _snapshot: &OrderBookSnapshot) {
@@ -61,6 +85,14 @@ mod tests {
         fn emit_connection_status(&self, _status: &ConnectionStatus) {
             self.connection_count.fetch_add(1, Ordering::SeqCst);
         }
+
+        fn emit_tick_size_change(&self, change: &TickSizeChange) {
+            self.tick_size_changes.lock().push(change.clone());
+        }
+
+        fn emit_reconnecting(&self, channel: &str, attempt: u32, next_delay: Duration) {
+            self.reconnects.lock().push((channel.to_string(), attempt, next_delay));
+        }
     }
 
     // ==================== Type Parsing Tests ====================
@@ -92,6 +124,318 @@ mod tests {
         assert_eq!(update.timestamp, Some(1704067200));
     }
 
+    #[test]
+    fn test_price_update_volume_is_optional() {
+        let json = r#"{"market": "0xmarket", "asset_id": "token123", "price": 0.65}"#;
+        let update: PriceUpdate = serde_json::from_str(json).unwrap();
+        assert_eq!(update.volume, None);
+        assert!(!serde_json::to_string(&update).unwrap().contains("volume"));
+    }
+
+    #[test]
+    fn test_rtds_price_change_carries_volume() {
+        let emitter = Arc::new(MockEmitter::new());
+        let text = r#"{
+            "connection_id": "c1",
+            "payload": {"m": "0xmarket", "pc": [{"a": "token123", "p": "0.42", "s": "150.5", "b": "0.41"}]}
+        }"#;
+
+        RtdsClient::handle_message(&emitter, text);
+
+        let update = emitter.last_price_update().unwrap();
+        assert_eq!(update.asset_id, "token123");
+        assert_eq!(update.price, 0.41);
+        assert_eq!(update.volume, Some(150.5));
+    }
+
+    #[test]
+    fn test_clob_price_change_carries_volume() {
+        let emitter = Arc::new(MockEmitter::new());
+        let cache = ClobCache::default();
+        let text = r#"{
+            "event_type": "price_change",
+            "market": "0xmarket",
+            "timestamp": 1704067200,
+            "price_changes": [{
+                "asset_id": "token123",
+                "price": "0.42",
+                "size": "80",
+                "side": "BUY",
+                "best_bid": "0.42",
+                "best_ask": "0.44"
+            }]
+        }"#;
+
+        ClobWebSocket::handle_message(&emitter, &cache, text);
+
+        let update = emitter.last_price_update().unwrap();
+        assert_eq!(update.price, 0.42);
+        assert_eq!(update.timestamp, Some(1704067200));
+        assert_eq!(update.volume, Some(80.0));
+    }
+
+    #[test]
+    fn test_clob_tick_size_change() {
+        let emitter = Arc::new(MockEmitter::new());
+        let cache = ClobCache::default();
+        let text = r#"{
+            "event_type": "tick_size_change",
+            "asset_id": "token123",
+            "market": "0xmarket",
+            "old_tick_size": "0.01",
+            "new_tick_size": "0.001",
+            "side": "BUY",
+            "timestamp": "1704067200000"
+        }"#;
+
+        ClobWebSocket::handle_message(&emitter, &cache, text);
+
+        assert_eq!(
+            emitter.tick_size_changes(),
+            vec![TickSizeChange {
+                asset_id: "token123".to_string(),
+                old_tick_size: 0.01,
+                new_tick_size: 0.001,
+                timestamp: Some(1704067200000),
+            }]
+        );
+        // Not mistaken for a price or book update
+        assert_eq!(emitter.price_updates(), 0);
+        assert_eq!(emitter.orderbook_updates(), 0);
+        // Cached for later order pricing
+        assert_eq!(cache.tick_sizes.read().get("token123"), Some(&0.001));
+    }
+
+    #[test]
+    fn test_clob_tick_size_change_plural_and_invalid() {
+        let emitter = Arc::new(MockEmitter::new());
+        let cache = ClobCache::default();
+
+        let plural = r#"{"event_type": "tick_size_changes", "asset_id": "a", "old_tick_size": "0.001", "new_tick_size": "0.01"}"#;
+        ClobWebSocket::handle_message(&emitter, &cache, plural);
+        let invalid = r#"{"event_type": "tick_size_change", "asset_id": "b", "old_tick_size": "x", "new_tick_size": "0.01"}"#;
+        ClobWebSocket::handle_message(&emitter, &cache, invalid);
+
+        let changes = emitter.tick_size_changes();
+        assert_eq!(changes.len(), 1);
+        // An unparseable change leaves the cache alone
+        assert!(!cache.tick_sizes.read().contains_key("b"));
+        assert_eq!(changes[0].asset_id, "a");
+        assert_eq!(changes[0].timestamp, None);
+    }
+
+    // ==================== Replay Tests ====================
+
+    #[test]
+    fn test_clob_replay_captured_frames() {
+        let emitter = Arc::new(MockEmitter::new());
+        let client = ClobWebSocket::new(Arc::new(WebSocketManager::new(emitter.clone())));
+        let path = concat!(env!("CARGO_MANIFEST_DIR"), "/src/ws/testdata/clob_frames.jsonl");
+
+        // Blank line skipped; unknown and non-JSON frames are replayed but emit nothing
+        assert_eq!(client.replay_from(path).unwrap(), 6);
+        assert_eq!(emitter.orderbook_updates(), 2);
+        assert_eq!(emitter.price_updates(), 2);
+        assert_eq!(emitter.trades(), 1);
+        assert_eq!(emitter.tick_size_changes().len(), 1);
+        assert!(client.order_book("token2").is_some());
+        assert_eq!(client.tick_size("token1"), Some(0.001));
+    }
+
+    #[test]
+    fn test_recorded_frames_replay_through_rtds() {
+        let path = std::env::temp_dir().join(format!("rtds_frames_{}.jsonl", std::process::id()));
+        let recorder = FrameRecorder::create(&path).unwrap();
+        // Pretty-printed frame: line breaks must not split it on replay
+        recorder.record("{\n  \"payload\": {\"m\": \"0xm\", \"pc\": [{\"a\": \"t1\", \"b\": \"0.41\"}]}\n}");
+        recorder.record("{}");
+        drop(recorder);
+
+        let emitter = Arc::new(MockEmitter::new());
+        let client = RtdsClient::new(Arc::new(WebSocketManager::new(emitter.clone())));
+        let replayed = client.replay_from(&path);
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(replayed.unwrap(), 2);
+        assert_eq!(emitter.price_updates(), 1);
+        assert_eq!(emitter.last_price_update().unwrap().price, 0.41);
+    }
+
+    #[test]
+    fn test_parse_clob_book() {
+        let events = parse_clob_message(r#"{"event_type":"book","asset_id":"t1","market":"0xm","bids":[{"price":"0.40","size":"10"}],"asks":[],"timestamp":"1700000000000"}"#);
+        assert_eq!(events.len(), 1);
+        let WsEvent::OrderBook(snapshot) = &events[0] else { panic!("expected book: {:?}", events) };
+        assert_eq!(snapshot.asset_id, "t1");
+        assert_eq!(snapshot.bids.len(), 1);
+    }
+
+    #[test]
+    fn test_parse_clob_array_snapshot() {
+        // Initial snapshots arrive as an array without event_type; non-book items are skipped
+        let events = parse_clob_message(r#"[
+            {"asset_id":"t1","market":"0xm","bids":[],"asks":[]},
+            {"asset_id":"t2","market":"0xm","bids":[],"asks":[{"price":"0.60","size":"5"}]},
+            {"something":"else"}
+        ]"#);
+        let ids: Vec<_> = events
+            .iter()
+            .map(|event| match event {
+                WsEvent::OrderBook(snapshot) => snapshot.asset_id.as_str(),
+                other => panic!("expected book: {:?}", other),
+            })
+            .collect();
+        assert_eq!(ids, ["t1", "t2"]);
+    }
+
+    #[test]
+    fn test_parse_clob_price_change() {
+        let events = parse_clob_message(r#"{"event_type":"price_change","market":"0xm","timestamp":1700000000000,"price_changes":[
+            {"asset_id":"t1","price":"0.5","size":"20","side":"BUY","best_bid":"0.49","best_ask":"0.51"},
+            {"asset_id":"t2","price":"0.5","size":"20","side":"SELL","best_bid":"bad","best_ask":"0.51"}
+        ]}"#);
+        // Unparseable best_bid drops the price update, not the level delta
+        assert_eq!(events.len(), 3);
+        let WsEvent::PriceUpdate(update) = &events[1] else { panic!("expected price: {:?}", events) };
+        assert_eq!(update.asset_id, "t1");
+        assert_eq!(update.price, 0.49);
+        assert_eq!(update.volume, Some(20.0));
+    }
+
+    #[test]
+    fn test_parse_clob_price_change_top_level_asset_id() {
+        let events = parse_clob_message(r#"{"event_type":"price_change","market":"0xm","asset_id":"t9","price_changes":[
+            {"price":"0.5","size":"1","side":"BUY","best_bid":"0.48","best_ask":"0.52"},
+            {"asset_id":"t1","price":"0.5","size":"1","side":"BUY","best_bid":"0.47","best_ask":"0.52"}
+        ]}"#);
+        let updates: Vec<_> = events
+            .iter()
+            .filter_map(|event| match event {
+                WsEvent::PriceUpdate(update) => Some((update.asset_id.as_str(), update.price)),
+                WsEvent::BookLevelChange(_) => None,
+                other => panic!("expected price: {:?}", other),
+            })
+            .collect();
+        // Per-change asset_id wins over the top-level one
+        assert_eq!(updates, [("t9", 0.48), ("t1", 0.47)]);
+
+        // No asset_id anywhere: the change is dropped, not the whole frame
+        let events = parse_clob_message(r#"{"event_type":"price_change","market":"0xm","price_changes":[
+            {"price":"0.5","size":"1","side":"BUY","best_bid":"0.48","best_ask":"0.52"}
+        ]}"#);
+        assert!(events.is_empty());
+    }
+
+    #[test]
+    fn test_parse_clob_price_change_level_deltas() {
+        let events = parse_clob_message(r#"{"event_type":"price_change","market":"0xm","timestamp":1700000000000,"price_changes":[
+            {"asset_id":"t1","price":"0.5","size":"20","side":"BUY","best_bid":"0.5","best_ask":"0.51"},
+            {"asset_id":"t1","price":"0.52","size":"0","side":"SELL","best_bid":"0.5","best_ask":"0.53"},
+            {"asset_id":"t1","price":"0.6","size":"1","side":"SIDEWAYS","best_bid":"0.5","best_ask":"0.53"}
+        ]}"#);
+        let deltas: Vec<_> = events
+            .iter()
+            .filter_map(|event| match event {
+                WsEvent::BookLevelChange(change) => Some(change.clone()),
+                _ => None,
+            })
+            .collect();
+
+        // Unknown side: no delta, but the price update still goes out
+        assert_eq!(deltas.len(), 2);
+        assert_eq!(events.len(), 5);
+        assert_eq!(
+            deltas[1],
+            BookLevelChange {
+                asset_id: "t1".to_string(),
+                side: OrderSide::Sell,
+                price: "0.52".to_string(),
+                size: "0".to_string(),
+                timestamp: Some(1700000000000),
+            }
+        );
+    }
+
+    #[test]
+    fn test_clob_cache_applies_price_change_deltas() {
+        let emitter = Arc::new(MockEmitter::new());
+        let cache = ClobCache::default();
+        let snapshot = r#"{"event_type":"book","asset_id":"t1","market":"0xm","timestamp":"1700000000000",
+            "bids":[{"price":"0.48","size":"100"},{"price":"0.47","size":"50"}],
+            "asks":[{"price":"0.52","size":"80"},{"price":"0.53","size":"40"}]}"#;
+        ClobWebSocket::handle_message(&emitter, &cache, snapshot);
+
+        // Resize 0.48, add 0.49, remove the 0.52 ask; "0.470" is the same level as "0.47"
+        let delta = r#"{"event_type":"price_change","market":"0xm","timestamp":1700000001000,"price_changes":[
+            {"asset_id":"t1","price":"0.48","size":"30","side":"BUY","best_bid":"0.49","best_ask":"0.53"},
+            {"asset_id":"t1","price":"0.49","size":"10","side":"BUY","best_bid":"0.49","best_ask":"0.53"},
+            {"asset_id":"t1","price":"0.52","size":"0","side":"SELL","best_bid":"0.49","best_ask":"0.53"},
+            {"asset_id":"t1","price":"0.470","size":"5","side":"BUY","best_bid":"0.49","best_ask":"0.53"}
+        ]}"#;
+        ClobWebSocket::handle_message(&emitter, &cache, delta);
+
+        let book = cache.books.read().get("t1").cloned().unwrap();
+        assert_eq!(book.timestamp, Some(1700000001000));
+        let aggregated = book.aggregate(0.01, 10);
+        assert_eq!(aggregated.bids, vec![(0.49, 10.0), (0.48, 30.0), (0.47, 5.0)]);
+        assert_eq!(aggregated.asks, vec![(0.53, 40.0)]);
+        let spread = book.spread().unwrap();
+        assert_eq!((spread.best_bid, spread.best_ask), (0.49, 0.53));
+
+        // Deltas for an asset without a snapshot don't invent a book
+        let unknown = r#"{"event_type":"price_change","market":"0xm","price_changes":[
+            {"asset_id":"t2","price":"0.5","size":"1","side":"BUY","best_bid":"0.5","best_ask":"0.6"}]}"#;
+        ClobWebSocket::handle_message(&emitter, &cache, unknown);
+        assert!(!cache.books.read().contains_key("t2"));
+    }
+
+    #[test]
+    fn test_parse_clob_trade_and_unknown() {
+        let events = parse_clob_message(r#"{"event_type":"trade","asset_id":"t1","market":"0xm","price":"0.5","size":"3","side":"BUY"}"#);
+        assert!(matches!(events.as_slice(), [WsEvent::Trade(trade)] if trade.asset_id == "t1"), "{:?}", events);
+
+        assert!(parse_clob_message(r#"{"event_type":"mystery"}"#).is_empty());
+        assert!(parse_clob_message("not json").is_empty());
+    }
+
+    #[test]
+    fn test_parse_non_ascii_frames() {
+        // 3-byte characters guarantee the old 100/200/500-byte previews cut one in half
+        let note = "€".repeat(400);
+        let unknown = format!(r#"{{"event_type":"mystery","note":"{}"}}"#, note);
+        let not_json = format!("€{}", note);
+        let wrapped = format!(r#"{{"payload":{{"m":"{}"}}}}"#, note);
+
+        // Previews are built inside debug!, so only an enabled subscriber runs them
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_test_writer()
+            .finish();
+        tracing::subscriber::with_default(subscriber, || {
+            for frame in [&unknown, &not_json, &wrapped] {
+                assert!(parse_clob_message(frame).is_empty());
+                assert!(parse_rtds_message(frame).is_empty());
+            }
+
+            // Live path too
+            let emitter = Arc::new(MockEmitter::new());
+            ClobWebSocket::handle_message(&emitter, &ClobCache::default(), &unknown);
+            RtdsClient::handle_message(&emitter, &not_json);
+            assert_eq!(emitter.price_updates(), 0);
+        });
+    }
+
+    #[test]
+    fn test_parse_rtds_message() {
+        let events = parse_rtds_message(r#"{"payload":{"m":"0xm","pc":[{"a":"t1","b":"0.41","s":"7"},{"a":"t2"}]}}"#);
+        assert_eq!(events.len(), 1);
+        let WsEvent::PriceUpdate(update) = &events[0] else { panic!("expected price: {:?}", events) };
+        assert_eq!((update.asset_id.as_str(), update.price, update.volume), ("t1", 0.41, Some(7.0)));
+
+        assert!(parse_rtds_message("{}").is_empty());
+        assert!(parse_rtds_message("").is_empty());
+    }
+
     #[test]
     fn test_orderbook_snapshot_deserialization() {
         let json = r#"{
@@ -183,6 +527,7 @@ mod tests {
             asset_id: "test".to_string(),
             price: 0.5,
             timestamp: Some(1000),
+            volume: None,
         };
         emitter.emit_price_update(&price);
         emitter.emit_price_update(&price);
@@ -227,6 +572,7 @@ mod tests {
             asset_id: "test".to_string(),
             price: 0.65,
             timestamp: Some(1000),
+            volume: Some(25.0),
         };
 
         // Access emitter through manager and emit
@@ -267,6 +613,169 @@ mod tests {
         assert_eq!(count3, 1); // Separate counter for clob
     }
 
+    #[test]
+    fn test_manager_emits_reconnecting_with_increasing_attempts() {
+        let emitter = Arc::new(MockEmitter::new());
+        let manager = WebSocketManager::new(emitter.clone());
+        let config = ReconnectConfig { max_attempts: Some(4), ..Default::default() };
+
+        let delays: Vec<_> = (0..3).map(|_| manager.begin_clob_reconnect(&config).unwrap()).collect();
+        manager.begin_rtds_reconnect(&config).unwrap();
+        assert_eq!(manager.clob_state(), ConnectionState::Reconnecting);
+
+        let clob: Vec<_> = emitter.reconnects().into_iter().filter(|(channel, ..)| channel == "clob").collect();
+        assert_eq!(clob.iter().map(|(_, attempt, _)| *attempt).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(clob.iter().map(|(.., delay)| *delay).collect::<Vec<_>>(), delays);
+        assert!(delays.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(emitter.reconnects().contains(&("rtds".to_string(), 1, Duration::from_secs(1))));
+
+        // The attempt that hits max_attempts fails instead of scheduling another
+        assert!(manager.begin_clob_reconnect(&config).is_none());
+        assert_eq!(manager.clob_state(), ConnectionState::Failed);
+        assert_eq!(emitter.reconnects().len(), 4);
+    }
+
+    #[test]
+    fn test_manager_clob_idle_time() {
+        let manager = WebSocketManager::new(Arc::new(MockEmitter::new()));
+        assert!(manager.clob_idle_time().is_none());
+
+        manager.record_clob_message();
+        assert!(manager.clob_idle_time().unwrap() < std::time::Duration::from_secs(1));
+    }
+
+    #[test]
+    fn test_manager_statistics() {
+        let manager = WebSocketManager::new(Arc::new(MockEmitter::new()));
+        manager.set_clob_state(ConnectionState::Connected);
+        manager.increment_rtds_reconnect();
+        manager.record_clob_message();
+
+        let stats = manager.statistics();
+        assert_eq!(stats.clob, ConnectionState::Connected);
+        assert_eq!(stats.rtds, ConnectionState::Disconnected);
+        assert_eq!(stats.rtds_reconnect_attempts, 1);
+        assert_eq!(stats.clob_reconnect_attempts, 0);
+        assert!(stats.rtds_idle.is_none());
+        assert!(stats.clob_idle.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_manager_periodic_stats_emitter() {
+        let emitter = Arc::new(MockEmitter::new());
+        let manager = Arc::new(WebSocketManager::new(emitter.clone()));
+
+        manager.start_stats_emitter(std::time::Duration::from_millis(10));
+        assert!(manager.stats_emitter_running());
+        tokio::time::sleep(std::time::Duration::from_millis(55)).await;
+        manager.stop_stats_emitter();
+        assert!(!manager.stats_emitter_running());
+
+        // Emits without any state transitions
+        let emitted = emitter.connection_count.load(Ordering::SeqCst);
+        assert!(emitted >= 2, "expected periodic emits, got {emitted}");
+
+        tokio::time::sleep(std::time::Duration::from_millis(30)).await;
+        assert_eq!(emitter.connection_count.load(Ordering::SeqCst), emitted);
+    }
+
+    // ==================== CLOB Subscription Tests ====================
+
+    #[test]
+    fn test_clob_subscribe_tokens_before_connect() {
+        let manager = Arc::new(WebSocketManager::new(Arc::new(MockEmitter::new())));
+        let client = ClobWebSocket::new(manager);
+
+        let added = client.subscribe_tokens(vec!["111".to_string(), "222".to_string()]).unwrap();
+        assert_eq!(added, vec!["111", "222"]);
+
+        // Duplicates are ignored, new tokens appended
+        let added = client.subscribe_tokens(vec!["222".to_string(), "333".to_string()]).unwrap();
+        assert_eq!(added, vec!["333"]);
+        assert_eq!(client.subscribed_tokens(), vec!["111", "222", "333"]);
+    }
+
+    #[test]
+    fn test_clob_subscription_limit() {
+        let manager = Arc::new(WebSocketManager::new(Arc::new(MockEmitter::new())).with_subscription_limits(10, 2));
+        let client = ClobWebSocket::new(manager.clone());
+
+        // Duplicates don't count against the limit
+        client.subscribe_tokens(vec!["1".to_string(), "1".to_string(), "2".to_string()]).unwrap();
+        let err = client.subscribe_tokens(vec!["3".to_string()]).unwrap_err();
+        assert!(err.to_string().contains("subscription limit exceeded"), "{}", err);
+        assert_eq!(client.subscribed_tokens(), vec!["1", "2"]);
+        assert_eq!(manager.current_clob_subscription_count(), 2);
+
+        // Dropping the client frees its subscriptions
+        drop(client);
+        assert_eq!(manager.current_clob_subscription_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_rtds_subscription_limit() {
+        let manager = Arc::new(WebSocketManager::new(Arc::new(MockEmitter::new())).with_subscription_limits(2, 10));
+        let mut client = RtdsClient::new(manager.clone());
+
+        // Over the limit: rejected before any connection is attempted
+        let markets = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        assert!(client.connect(markets).await.is_err());
+        assert_eq!(manager.rtds_state(), ConnectionState::Disconnected);
+
+        client.subscribe(vec!["a".to_string(), "b".to_string()]).await.unwrap();
+        assert!(client.subscribe(vec!["c".to_string()]).await.is_err());
+        assert_eq!(manager.current_rtds_subscription_count(), 2);
+
+        // Already subscribed: nothing added, no slots used
+        let added = client.subscribe(vec!["b".to_string(), "a".to_string()]).await.unwrap();
+        assert!(added.is_empty());
+        assert_eq!(client.subscribed_markets(), vec!["a", "b"]);
+        assert_eq!(manager.current_rtds_subscription_count(), 2);
+
+        drop(client);
+        assert_eq!(manager.current_rtds_subscription_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_rtds_subscribe_sends_on_live_connection() {
+        use futures_util::StreamExt;
+        use tokio_tungstenite::tungstenite::Message;
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let (frames_tx, mut frames_rx) = tokio::sync::mpsc::unbounded_channel::<String>();
+        tokio::spawn(async move {
+            let (socket, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+            while let Some(Ok(msg)) = ws.next().await {
+                if let Message::Text(text) = msg {
+                    let _ = frames_tx.send(text.to_string());
+                }
+            }
+        });
+
+        let manager = Arc::new(WebSocketManager::new(Arc::new(MockEmitter::new())));
+        let mut client = RtdsClient::new(manager.clone()).with_url(url);
+        client.connect(vec!["a".to_string()]).await.unwrap();
+
+        let timeout = Duration::from_secs(5);
+        let filters = |frame: String| -> String {
+            let value: serde_json::Value = serde_json::from_str(&frame).unwrap();
+            value["subscriptions"][0]["filters"].as_str().unwrap().to_string()
+        };
+        let first = tokio::time::timeout(timeout, frames_rx.recv()).await.unwrap().unwrap();
+        assert_eq!(filters(first), r#"["a"]"#);
+
+        // Only the new market goes out
+        let added = client.subscribe(vec!["a".to_string(), "b".to_string()]).await.unwrap();
+        assert_eq!(added, vec!["b"]);
+        let second = tokio::time::timeout(timeout, frames_rx.recv()).await.unwrap().unwrap();
+        assert_eq!(filters(second), r#"["b"]"#);
+        assert_eq!(manager.current_rtds_subscription_count(), 2);
+
+        client.disconnect();
+    }
+
     // ==================== Connection State Tests ====================
 
     #[test]
@@ -294,8 +803,70 @@ mod tests {
         );
     }
 
+    // ==================== CLOB Keepalive Tests ====================
+
+    #[tokio::test]
+    async fn test_clob_rejects_zero_ping_interval() {
+        let manager = Arc::new(WebSocketManager::new(Arc::new(MockEmitter::new())));
+        let config = ReconnectConfig { ping_interval: Duration::ZERO, ..Default::default() };
+        let mut client = ClobWebSocket::new(manager.clone()).with_url("ws://127.0.0.1:1").with_reconnect_config(config);
+
+        let err = client.connect(vec!["111".to_string()]).await.unwrap_err();
+
+        assert!(err.to_string().contains("ping_interval"), "{}", err);
+        assert!(client.subscribed_tokens().is_empty());
+        assert_eq!(manager.current_clob_subscription_count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_clob_reconnects_when_server_never_pongs() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        let (accepted_tx, mut accepted_rx) = tokio::sync::mpsc::unbounded_channel();
+        tokio::spawn(async move {
+            let mut held = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                // Complete the handshake, then never read - so our pings are never answered
+                let ws = tokio_tungstenite::accept_async(socket).await.unwrap();
+                held.push(ws);
+                let _ = accepted_tx.send(());
+            }
+        });
+
+        let emitter = Arc::new(MockEmitter::new());
+        let manager = Arc::new(WebSocketManager::new(emitter.clone()));
+        let config = ReconnectConfig {
+            initial_delay: Duration::from_millis(10),
+            ping_interval: Duration::from_millis(50),
+            ping_timeout: Duration::from_millis(100),
+            ..Default::default()
+        };
+        let mut client = ClobWebSocket::new(manager.clone()).with_url(url).with_reconnect_config(config);
+        client.connect(vec!["111".to_string()]).await.unwrap();
+
+        let timeout = Duration::from_secs(5);
+        tokio::time::timeout(timeout, accepted_rx.recv()).await.unwrap().unwrap();
+        // Reconnected after the first ping went unanswered
+        tokio::time::timeout(timeout, accepted_rx.recv()).await.unwrap().unwrap();
+        client.disconnect();
+
+        let reconnects = emitter.reconnects();
+        assert_eq!(reconnects.first().map(|(channel, attempt, _)| (channel.as_str(), *attempt)), Some(("clob", 1)));
+    }
+
     // ==================== Reconnect Config Tests ====================
 
+    #[test]
+    fn test_reconnect_config_ping_defaults() {
+        use crate::ws::manager::ReconnectConfig;
+        use std::time::Duration;
+
+        let config = ReconnectConfig::default();
+        assert_eq!(config.ping_interval, Duration::from_secs(30));
+        assert_eq!(config.ping_timeout, Duration::from_secs(10));
+        assert!(config.ping_timeout < config.ping_interval);
+    }
+
     #[test]
     fn test_reconnect_delay_calculation() {
         use crate::ws::manager::ReconnectConfig;
@@ -306,6 +877,7 @@ mod tests {
             max_delay: Duration::from_secs(60),
             multiplier: 2.0,
             max_attempts: Some(10),
+            ..Default::default()
         };
 
         // First attempt: 1s
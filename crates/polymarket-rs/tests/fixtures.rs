@@ -0,0 +1,165 @@
+// AIDEV-NOTE: Deserialization tests against API response fixtures (tests/fixtures/)
+// The fixtures are hand-assembled from the documented response shapes, not recorded from
+// the live APIs - they keep the extra keys, nulls and numeric strings those responses carry,
+// but IDs and values are illustrative. When an endpoint's shape changes, update its fixture
+// from a real response rather than editing the test.
+
+use polymarket_rs::types::PriceHistoryResponse;
+use polymarket_rs::{Event, Market, MarketStatus, Order, Position, RawMarket};
+use serde::Deserialize;
+
+/// /data/orders page envelope
+#[derive(Deserialize)]
+struct OrdersPage {
+    data: Vec<Order>,
+    next_cursor: Option<String>,
+}
+
+fn markets(json: &str) -> Vec<Market> {
+    let raw: Vec<RawMarket> = serde_json::from_str(json).expect("markets fixture should parse");
+    raw.into_iter().map(Market::from).collect()
+}
+
+#[test]
+fn test_gamma_market_fixture() {
+    let raw: RawMarket = serde_json::from_str(include_str!("fixtures/gamma_market.json")).unwrap();
+    let market = Market::from(raw);
+
+    assert_eq!(market.id, "253591");
+    assert_eq!(market.condition_id, "0x9c1a953fe92c8357f1b646ba25d983aa83e90c525992db14fb726fa895cb5763");
+    assert_eq!(market.question_id, "0x6a0d2a5ee4e1ab0a36e2ffd3c0c8a6b1e1c2b3f2a8d5e7b9c1d2e3f4a5b6c7d8");
+    assert_eq!(market.question, "Will the Fed cut rates in December 2025?");
+    assert_eq!(market.market_slug, "will-the-fed-cut-rates-in-december-2025");
+    assert_eq!(market.end_date_iso, "2025-12-10");
+    assert!(market.description.starts_with("This market will resolve to \"Yes\""));
+    assert!(market.icon.as_deref().unwrap().ends_with("fed-rates.png"));
+    assert_eq!(
+        market.resolution_source.as_deref(),
+        Some("https://www.federalreserve.gov/monetarypolicy/openmarket.htm")
+    );
+    assert!(market.active && !market.closed && !market.archived && market.accepting_orders);
+    assert_eq!(market.status(), MarketStatus::Active);
+    assert_eq!(market.volume_num, 18273645.92);
+    assert_eq!(market.liquidity_num, 412873.1288);
+    assert_eq!(market.spread, 0.01);
+    assert_eq!(market.minimum_order_size, 5.0);
+    assert_eq!(market.minimum_tick_size, 0.001);
+
+    assert_eq!(market.tokens.len(), 2);
+    assert_eq!(market.tokens[0].outcome, "Yes");
+    assert_eq!(market.tokens[0].price, 0.835);
+    assert_eq!(
+        market.tokens[0].token_id,
+        "87769991026114894163580777793845523168226980076553814689875238288185044414090"
+    );
+    assert_eq!(market.tokens[1].outcome, "No");
+    assert_eq!(market.tokens[1].price, 0.165);
+    assert!(market.tokens.iter().all(|t| t.winner.is_none()));
+}
+
+#[test]
+fn test_market_without_clob_token_ids_fixture() {
+    let markets = markets(include_str!("fixtures/gamma_markets_edge_cases.json"));
+    let pending = &markets[0];
+
+    // No clobTokenIds/outcomePrices yet - no tokens rather than a parse failure
+    assert!(pending.tokens.is_empty());
+    assert!(pending.icon.is_none() && pending.image.is_none());
+    assert!(pending.resolution_source.is_none(), "empty resolutionSource is dropped");
+    assert_eq!(pending.question_id, "");
+    assert_eq!(pending.spread, 0.0);
+    assert!(!pending.is_tradeable());
+}
+
+#[test]
+fn test_resolved_market_fixture() {
+    let markets = markets(include_str!("fixtures/gamma_markets_edge_cases.json"));
+    let resolved = &markets[1];
+
+    assert!(resolved.closed);
+    assert_eq!(resolved.status(), MarketStatus::Resolved);
+    assert_eq!(resolved.resolved_outcome().map(|t| t.outcome.as_str()), Some("No"));
+    assert_eq!(resolved.tokens[0].winner, Some(false));
+    assert_eq!(resolved.spread, 1.0);
+}
+
+#[test]
+fn test_event_with_empty_markets_fixture() {
+    let events: Vec<Event> = serde_json::from_str(include_str!("fixtures/gamma_event_empty_markets.json")).unwrap();
+    assert_eq!(events.len(), 2);
+
+    let upcoming = &events[0];
+    assert_eq!(upcoming.id, "16085");
+    assert_eq!(upcoming.ticker, "fed-decision-in-january");
+    assert_eq!(upcoming.title, "Fed decision in January?");
+    assert!(upcoming.markets.is_empty());
+    assert!(upcoming.new && upcoming.restricted && !upcoming.featured);
+    assert_eq!(upcoming.start_date.as_deref(), Some("2025-11-18T17:04:11.123Z"));
+    assert_eq!(upcoming.end_time().unwrap().to_rfc3339(), "2026-01-28T12:00:00+00:00");
+    assert_eq!(upcoming.total_volume, 0.0);
+
+    // "markets": null, and no description/dates at all
+    let archived = &events[1];
+    assert!(archived.markets.is_empty());
+    assert!(archived.archived && archived.closed);
+    assert_eq!(archived.description, "");
+    assert!(archived.end_time().is_none());
+    assert_eq!(archived.total_volume, 1523.25);
+}
+
+#[test]
+fn test_orders_fixture() {
+    let page: OrdersPage = serde_json::from_str(include_str!("fixtures/clob_orders.json")).unwrap();
+    assert_eq!(page.next_cursor.as_deref(), Some("LTE="));
+    assert_eq!(page.data.len(), 2);
+
+    let partial = &page.data[0];
+    assert_eq!(partial.id, "0x5fe9e6fdcc3e4c1f6d3e4b0e97d0f7a1e4b6c6a9d2f1e0b3c4d5e6f7a8b9c0d1");
+    assert_eq!(partial.market, "0x9c1a953fe92c8357f1b646ba25d983aa83e90c525992db14fb726fa895cb5763");
+    assert_eq!(partial.asset, "87769991026114894163580777793845523168226980076553814689875238288185044414090");
+    assert_eq!(partial.side, "BUY");
+    assert_eq!(partial.original_size, "100");
+    assert_eq!(partial.size_matched, "25.5");
+    assert_eq!(partial.price, "0.82");
+    assert_eq!(partial.status, "LIVE");
+    assert_eq!(partial.order_type, "GTC");
+    assert_eq!(partial.created_at, "1732101234");
+
+    // Nulls in unset fields
+    let untouched = &page.data[1];
+    assert_eq!(untouched.side, "SELL");
+    assert_eq!(untouched.order_type, "");
+    assert_eq!(untouched.created_at, "1732105678");
+}
+
+#[test]
+fn test_price_history_with_duplicate_timestamps_fixture() {
+    let response: PriceHistoryResponse =
+        serde_json::from_str(include_str!("fixtures/price_history_duplicates.json")).unwrap();
+
+    // Duplicates are passed through as sent, in order
+    let timestamps: Vec<i64> = response.history.iter().map(|point| point.t).collect();
+    assert_eq!(timestamps, vec![1732060800, 1732064400, 1732064400, 1732068000, 1732071600]);
+    assert_eq!(response.history[1].p, response.history[2].p);
+    assert_eq!(response.history[4].p, 0.835);
+}
+
+#[test]
+fn test_positions_fixture() {
+    let positions: Vec<Position> = serde_json::from_str(include_str!("fixtures/data_positions.json")).unwrap();
+    assert_eq!(positions.len(), 1);
+
+    let position = &positions[0];
+    assert_eq!(position.asset, "87769991026114894163580777793845523168226980076553814689875238288185044414090");
+    assert_eq!(position.condition_id, "0x9c1a953fe92c8357f1b646ba25d983aa83e90c525992db14fb726fa895cb5763");
+    assert_eq!(position.size, 250.5);
+    assert_eq!(position.avg_price, 0.72);
+    assert_eq!(position.initial_value, 180.36);
+    assert_eq!(position.current_value, 209.1675);
+    assert_eq!(position.cash_pnl, 28.8075);
+    assert_eq!(position.percent_pnl, 15.9722);
+    assert_eq!(position.cur_price, 0.835);
+    assert_eq!(position.title, "Will the Fed cut rates in December 2025?");
+    assert_eq!(position.outcome, "Yes");
+    assert_eq!(position.proxy_wallet, "0x3b5c8a1fd1bc3b5e4f0b1c2d3e4f5a6b7c8d9e0f");
+}
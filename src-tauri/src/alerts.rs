@@ -0,0 +1,99 @@
+// AIDEV-NOTE: Active price alerts held in memory - they are checked on every price_update,
+// so the check must not query SQLite. The alert commands reload this after each change.
+
+use std::collections::HashMap;
+
+use parking_lot::RwLock;
+
+use crate::db::{Database, PriceAlert};
+use crate::error::AppError;
+
+/// Untriggered alerts keyed by token ID
+#[derive(Default)]
+pub struct AlertCache {
+    alerts: RwLock<HashMap<String, Vec<PriceAlert>>>,
+}
+
+impl AlertCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the cache with the database's active alerts
+    pub fn reload(&self, database: &Database) -> Result<(), AppError> {
+        let mut alerts: HashMap<String, Vec<PriceAlert>> = HashMap::new();
+        for alert in database.get_active_alerts(None)? {
+            alerts.entry(alert.token_id.clone()).or_default().push(alert);
+        }
+        *self.alerts.write() = alerts;
+        Ok(())
+    }
+
+    /// Remove and return the alerts on `asset_id` that `price` has crossed, oldest first
+    pub fn take_crossed(&self, asset_id: &str, price: f64) -> Vec<PriceAlert> {
+        // Most updates cross nothing - check under the read lock first
+        let any_crossed = self.alerts.read().get(asset_id).is_some_and(|alerts| {
+            alerts.iter().any(|alert| alert.direction.is_crossed(price, alert.threshold))
+        });
+        if !any_crossed {
+            return Vec::new();
+        }
+
+        let mut alerts = self.alerts.write();
+        let Some(token_alerts) = alerts.get_mut(asset_id) else {
+            return Vec::new();
+        };
+        let crossed: Vec<PriceAlert> = token_alerts
+            .extract_if(.., |alert| alert.direction.is_crossed(price, alert.threshold))
+            .collect();
+        if token_alerts.is_empty() {
+            alerts.remove(asset_id);
+        }
+        crossed
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::AlertDirection;
+
+    #[test]
+    fn test_take_crossed_fires_each_alert_once() {
+        let database = Database::open_in_memory();
+        let above = database.add_price_alert("t1", 0.6, AlertDirection::Above).unwrap();
+        let below = database.add_price_alert("t1", 0.4, AlertDirection::Below).unwrap();
+        database.add_price_alert("t2", 0.6, AlertDirection::Above).unwrap();
+        let cache = AlertCache::new();
+        cache.reload(&database).unwrap();
+
+        assert!(cache.take_crossed("t1", 0.5).is_empty());
+        assert!(cache.take_crossed("unknown", 0.9).is_empty());
+
+        let crossed = cache.take_crossed("t1", 0.6);
+        assert_eq!(crossed.iter().map(|a| a.id).collect::<Vec<_>>(), [above.id]);
+        assert!(cache.take_crossed("t1", 0.7).is_empty());
+
+        assert_eq!(cache.take_crossed("t1", 0.1)[0].id, below.id);
+        assert_eq!(cache.take_crossed("t2", 0.9).len(), 1);
+    }
+
+    #[test]
+    fn test_reload_picks_up_created_and_deleted_alerts() {
+        let database = Database::open_in_memory();
+        let cache = AlertCache::new();
+        cache.reload(&database).unwrap();
+
+        let alert = database.add_price_alert("t1", 0.6, AlertDirection::Above).unwrap();
+        assert!(cache.take_crossed("t1", 0.9).is_empty(), "not loaded yet");
+
+        cache.reload(&database).unwrap();
+        database.delete_price_alert(alert.id).unwrap();
+        cache.reload(&database).unwrap();
+        assert!(cache.take_crossed("t1", 0.9).is_empty());
+
+        database.add_price_alert("t1", 0.6, AlertDirection::Above).unwrap();
+        cache.reload(&database).unwrap();
+        assert_eq!(cache.take_crossed("t1", 0.9).len(), 1);
+    }
+}
@@ -1,19 +1,90 @@
 // AIDEV-NOTE: Secure credential storage using OS keyring
-// Stores API credentials in macOS Keychain / Windows Credential Manager / Linux Secret Service
+// Stores secrets in macOS Keychain / Windows Credential Manager / Linux Secret Service
+// AIDEV-NOTE: keyring is built with apple-native/windows-native/sync-secret-service only.
+// Elsewhere it falls back to its mock, which keeps a secret only inside the one Entry
+// object - so PrivateKeyStore refuses to open there instead of silently losing the key
 
 use keyring::Entry;
+use zeroize::Zeroizing;
 
-use polymarket_rs::ApiCredentials;
+use polymarket_rs::{ApiCredentials, PolymarketSigner};
 use crate::error::AppError;
 
 const SERVICE_NAME: &str = "plgui-polymarket";
 const CREDENTIALS_KEY: &str = "api-credentials";
+const PRIVATE_KEY_KEY: &str = "private-key";
+
+/// Whether keyring has a native backend on this platform (see the Cargo.toml features)
+const NATIVE_KEYRING: bool = cfg!(any(target_os = "macos", target_os = "windows", target_os = "linux"));
+
+/// Wallet private key kept in the OS keyring, so order signing doesn't need it sent per call
+/// AIDEV-NOTE: Created once and shared through AuthState::private_key_store
+pub struct PrivateKeyStore {
+    entry: Entry,
+}
+
+impl PrivateKeyStore {
+    /// Open the keyring entry, or an error if this platform has no OS keyring
+    pub fn new() -> Result<Self, AppError> {
+        if !NATIVE_KEYRING {
+            return Err(AppError::Internal("No OS keyring on this platform".to_string()));
+        }
+
+        let entry = Entry::new(SERVICE_NAME, PRIVATE_KEY_KEY)
+            .map_err(|e| AppError::Internal(format!("Failed to access keyring: {}", e)))?;
+
+        Ok(Self::with_entry(entry))
+    }
+
+    fn with_entry(entry: Entry) -> Self {
+        Self { entry }
+    }
+
+    /// Store the key (validated first, so a typo fails here rather than at the first order)
+    pub fn store(&self, private_key: &str) -> Result<(), AppError> {
+        PolymarketSigner::from_private_key(private_key)?;
+
+        self.entry
+            .set_password(private_key.trim())
+            .map_err(|e| AppError::Internal(format!("Failed to store private key: {}", e)))?;
+
+        tracing::info!("Private key stored securely in keyring");
+        Ok(())
+    }
+
+    /// Load the stored key, or an error if none is stored
+    pub fn load(&self) -> Result<Zeroizing<String>, AppError> {
+        match self.entry.get_password() {
+            Ok(private_key) => Ok(Zeroizing::new(private_key)),
+            Err(keyring::Error::NoEntry) => {
+                Err(AppError::Internal("No private key stored in keyring".to_string()))
+            }
+            Err(e) => Err(AppError::Internal(format!("Failed to load private key: {}", e))),
+        }
+    }
+
+    /// Remove the stored key (no-op if none is stored)
+    pub fn delete(&self) -> Result<(), AppError> {
+        match self.entry.delete_credential() {
+            Ok(()) | Err(keyring::Error::NoEntry) => Ok(()),
+            Err(e) => Err(AppError::Internal(format!("Failed to delete private key: {}", e))),
+        }
+    }
+
+    /// Whether a key is stored
+    pub fn has_key(&self) -> bool {
+        self.entry.get_password().is_ok()
+    }
+}
 
 /// Secure credential storage using the OS keyring
+/// AIDEV-NOTE: Not wired up - API credentials persist in SQLite via db.rs
+#[allow(dead_code)]
 pub struct CredentialStore {
     entry: Entry,
 }
 
+#[allow(dead_code)]
 impl CredentialStore {
     /// Create a new credential store
     pub fn new() -> Result<Self, AppError> {
@@ -82,3 +153,50 @@ impl Default for CredentialStore {
         Self::new().expect("Failed to create credential store")
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use keyring::mock::MockCredential;
+
+    const TEST_PRIVATE_KEY: &str = "0xac0974bec39a17e36ba4a6b4d238ff944bacb478cbed5efcae784d7bf4f2ff80";
+
+    fn mock_store() -> PrivateKeyStore {
+        PrivateKeyStore::with_entry(Entry::new_with_credential(Box::new(MockCredential::default())))
+    }
+
+    #[test]
+    fn test_store_and_load() {
+        let store = mock_store();
+        assert!(!store.has_key());
+        assert!(store.load().is_err());
+
+        // Surrounding whitespace from a paste is not part of the key
+        store.store(&format!("  {}\n", TEST_PRIVATE_KEY)).unwrap();
+        assert!(store.has_key());
+        assert_eq!(store.load().unwrap().as_str(), TEST_PRIVATE_KEY);
+    }
+
+    #[test]
+    fn test_store_rejects_invalid_key() {
+        let store = mock_store();
+        assert!(store.store("not-a-key").is_err());
+        assert!(!store.has_key());
+    }
+
+    #[test]
+    fn test_delete() {
+        let store = mock_store();
+        store.store(TEST_PRIVATE_KEY).unwrap();
+
+        store.delete().unwrap();
+        assert!(!store.has_key());
+        // Deleting again is a no-op
+        store.delete().unwrap();
+    }
+
+    #[test]
+    fn test_new_requires_native_keyring() {
+        assert_eq!(PrivateKeyStore::new().is_ok(), NATIVE_KEYRING);
+    }
+}
@@ -1,7 +1,6 @@
 // AIDEV-NOTE: Auth module - app-specific auth concerns
-// All auth types come from polymarket_rs; keyring.rs kept for future secure storage option
+// All auth types come from polymarket_rs; keyring.rs holds the OS-keyring private key store
 
-// NOTE: keyring.rs exists but is not currently used (we use SQLite via db.rs)
-// Uncomment when ready to use OS keychain for more secure credential storage:
-// mod keyring;
-// pub use keyring::CredentialStore;
+// NOTE: keyring.rs also has a CredentialStore, unused (API credentials live in SQLite via db.rs)
+mod keyring;
+pub use keyring::PrivateKeyStore;
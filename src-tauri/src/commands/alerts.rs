@@ -0,0 +1,46 @@
+// AIDEV-NOTE: Tauri commands for price alerts - stored in SQLite, fired from TauriEventEmitter
+// Every change reloads AuthState::price_alerts, the copy the price stream checks
+
+use tauri::State;
+
+use crate::db::{AlertDirection, PriceAlert};
+use crate::error::AppError;
+use crate::AuthState;
+
+/// Create an alert that fires once when the token's price crosses `threshold`
+#[tauri::command]
+pub async fn add_price_alert(
+    token_id: String,
+    threshold: f64,
+    direction: AlertDirection,
+    state: State<'_, AuthState>,
+) -> Result<PriceAlert, AppError> {
+    if !(0.0..=1.0).contains(&threshold) {
+        return Err(AppError::Internal(format!(
+            "Invalid threshold {}: must be between 0 and 1",
+            threshold
+        )));
+    }
+
+    tracing::debug!("Adding price alert: {} {} {}", token_id, direction.as_str(), threshold);
+    let alert = state.database.add_price_alert(&token_id, threshold, direction)?;
+    state.price_alerts.reload(&state.database)?;
+    Ok(alert)
+}
+
+/// List alerts that have not fired yet, optionally for a single token
+#[tauri::command]
+pub async fn get_price_alerts(
+    token_id: Option<String>,
+    state: State<'_, AuthState>,
+) -> Result<Vec<PriceAlert>, AppError> {
+    state.database.get_active_alerts(token_id.as_deref())
+}
+
+/// Delete an alert; returns false if it did not exist
+#[tauri::command]
+pub async fn delete_price_alert(id: i64, state: State<'_, AuthState>) -> Result<bool, AppError> {
+    let deleted = state.database.delete_price_alert(id)?;
+    state.price_alerts.reload(&state.database)?;
+    Ok(deleted)
+}
@@ -1,11 +1,44 @@
 // AIDEV-NOTE: Tauri commands for authentication - login/logout/status/portfolio
 // Uses SQLite database for credential persistence
 
-use tauri::State;
+use std::time::Duration;
 
-use polymarket_rs::{Balance, ClobClient, Order, PolymarketSigner, Position};
+use tauri::State;
+use zeroize::Zeroizing;
+
+use polymarket_rs::api::order::SignatureType;
+use polymarket_rs::{
+    ApiCredentials, Balance, ClobClient, GammaClient, LeaderboardEntry, Order, PnlSummary, PolymarketSigner, PortfolioSummary, Position,
+    PositionSortKey,
+};
+use crate::auth::PrivateKeyStore;
+use crate::db::Database;
 use crate::error::AppError;
-use crate::AuthState;
+use crate::{AuthState, WebSocketState};
+
+/// Max concurrent Gamma requests when filling in missing position titles
+const ENRICHMENT_CONCURRENCY: usize = 4;
+
+/// Settings key for the account type (stored as the numeric signature_type)
+const SIGNATURE_TYPE_SETTING: &str = "signature_type";
+
+/// Account type saved by `set_signature_type`, or None if never set (or invalid)
+pub fn stored_signature_type(database: &Database) -> Option<SignatureType> {
+    let stored = database.settings().get_string(SIGNATURE_TYPE_SETTING)?;
+    let signature_type = stored.parse::<u8>().ok().and_then(SignatureType::from_u8);
+    if signature_type.is_none() {
+        tracing::warn!("Invalid stored signature_type {}, ignoring", stored);
+    }
+    signature_type
+}
+
+/// Account type for the balance query: the stored one, or GnosisSafe if never set
+/// AIDEV-NOTE: Before account types were configurable the balance query always sent
+/// signature_type=2, so installs without a stored type keep that (orders keep Proxy,
+/// see order_account in trading.rs)
+pub fn balance_signature_type(database: &Database) -> SignatureType {
+    stored_signature_type(database).unwrap_or(SignatureType::GnosisSafe)
+}
 
 /// Extended auth status including polymarket address
 #[derive(Debug, Clone, serde::Serialize)]
@@ -39,20 +72,9 @@ pub async fn get_auth_status(state: State<'_, AuthState>) -> Result<ExtendedAuth
 pub async fn login(private_key: String, state: State<'_, AuthState>) -> Result<ExtendedAuthStatus, AppError> {
     tracing::info!("Starting login flow");
 
-    // Clean up the private key - trim whitespace
-    let clean_key = private_key.trim();
-
-    // Validate key format
-    let key_hex = clean_key.strip_prefix("0x").unwrap_or(clean_key);
-    if key_hex.len() != 64 {
-        return Err(AppError::Internal(format!(
-            "Invalid private key: expected 64 hex characters, got {}",
-            key_hex.len()
-        )));
-    }
-
-    // Create signer from private key
-    let signer = PolymarketSigner::from_private_key(clean_key)?;
+    // Create signer from private key (validates format, ignores surrounding whitespace)
+    let private_key = Zeroizing::new(private_key);
+    let signer = PolymarketSigner::from_private_key(&private_key)?;
     let address = signer.address_string();
 
     tracing::info!("Signing with address: {}", address);
@@ -66,6 +88,10 @@ pub async fn login(private_key: String, state: State<'_, AuthState>) -> Result<E
     // Get existing polymarket address if any
     let polymarket_address = state.polymarket_address.read().clone();
 
+    // Validate before anything is stored
+    let mut client = state.clob_client.read().clone();
+    client.set_credentials(&credentials)?;
+
     // Store in database
     state.database.store_credentials(&credentials, polymarket_address.as_deref())?;
 
@@ -74,11 +100,7 @@ pub async fn login(private_key: String, state: State<'_, AuthState>) -> Result<E
         let mut creds = state.credentials.write();
         *creds = Some(credentials.clone());
     }
-
-    {
-        let mut client = state.clob_client.write();
-        client.set_credentials(&credentials);
-    }
+    *state.clob_client.write() = client;
 
     tracing::info!("Login successful for {}", address);
 
@@ -89,11 +111,99 @@ pub async fn login(private_key: String, state: State<'_, AuthState>) -> Result<E
     })
 }
 
+/// Rotate API credentials - revoke the current key, then create and store a new one
+/// AIDEV-NOTE: Created, not derived - derive-api-key returns the existing key for the nonce.
+/// If creation fails after the delete, the revoked credentials are cleared (as on logout)
+/// and the user has to log in again.
+#[tauri::command]
+pub async fn rotate_credentials(private_key: String, state: State<'_, AuthState>) -> Result<ExtendedAuthStatus, AppError> {
+    tracing::info!("Rotating API credentials");
+
+    let private_key = Zeroizing::new(private_key);
+    let signer = PolymarketSigner::from_private_key(&private_key)?;
+    let address = signer.address_string();
+
+    let current_address = state.credentials.read().as_ref().map(|c| c.address.clone())
+        .ok_or_else(|| AppError::Internal("Not authenticated".to_string()))?;
+    if !current_address.eq_ignore_ascii_case(&address) {
+        return Err(AppError::Internal(format!(
+            "Private key is for {}, but logged in as {}", address, current_address
+        )));
+    }
+
+    // Revoke the current key
+    let client = state.clob_client.read().clone();
+    client.delete_api_key().await?;
+
+    tracing::info!("Old API key deleted");
+
+    // Watchers captured the old client - its key is revoked, so stop them until restarted
+    state.balance_watcher.stop();
+    state.order_watcher.stop();
+
+    let credentials = match client.create_api_key(&signer, 0).await {
+        Ok(credentials) => credentials,
+        Err(e) => {
+            tracing::error!("API key creation failed after revoking the old key: {}", e);
+            clear_session(&state)?;
+            return Err(e.into());
+        }
+    };
+
+    if let Err(e) = install_rotated_credentials(&state, &credentials) {
+        tracing::error!("New API key unusable after revoking the old key: {}", e);
+        clear_session(&state)?;
+        return Err(e);
+    }
+
+    tracing::info!("API credentials rotated for {}", address);
+
+    Ok(ExtendedAuthStatus {
+        is_authenticated: true,
+        address: Some(address),
+        polymarket_address: state.polymarket_address.read().clone(),
+    })
+}
+
+/// Store rotated credentials (keeps the polymarket address) and switch the client to them
+fn install_rotated_credentials(state: &AuthState, credentials: &ApiCredentials) -> Result<(), AppError> {
+    let mut client = state.clob_client.read().clone();
+    client.set_credentials(credentials)?;
+
+    state.database.update_credentials(credentials)?;
+
+    {
+        let mut creds = state.credentials.write();
+        *creds = Some(credentials.clone());
+    }
+    *state.clob_client.write() = client;
+
+    Ok(())
+}
+
 /// Logout - clear credentials from database and state
 #[tauri::command]
 pub async fn logout(state: State<'_, AuthState>) -> Result<ExtendedAuthStatus, AppError> {
     tracing::info!("Logging out");
 
+    clear_session(&state)?;
+
+    // The stored signing key belongs to the session too
+    if let Some(Err(e)) = state.private_key_store.as_ref().map(PrivateKeyStore::delete) {
+        tracing::warn!("Failed to clear stored private key: {}", e);
+    }
+
+    tracing::info!("Logout successful");
+
+    Ok(ExtendedAuthStatus {
+        is_authenticated: false,
+        address: None,
+        polymarket_address: None,
+    })
+}
+
+/// Drop the credentials from database and state, and stop everything that used them
+fn clear_session(state: &AuthState) -> Result<(), AppError> {
     // Delete from database
     state.database.delete_credentials()?;
 
@@ -104,8 +214,9 @@ pub async fn logout(state: State<'_, AuthState>) -> Result<ExtendedAuthStatus, A
     }
 
     {
+        // Account type is a device setting, not part of the credentials - keep it
         let mut client = state.clob_client.write();
-        *client = ClobClient::new();
+        *client = ClobClient::new().with_signature_type(client.signature_type());
     }
 
     {
@@ -113,15 +224,40 @@ pub async fn logout(state: State<'_, AuthState>) -> Result<ExtendedAuthStatus, A
         *poly_addr = None;
     }
 
-    tracing::info!("Logout successful");
+    // Polling with the old client would only produce auth errors
+    state.balance_watcher.stop();
+    state.order_watcher.stop();
+    state.position_tracker.clear();
 
-    Ok(ExtendedAuthStatus {
-        is_authenticated: false,
-        address: None,
-        polymarket_address: None,
+    Ok(())
+}
+
+/// The shared keyring entry, or an error if this platform has no OS keyring
+pub(crate) fn private_key_store(state: &AuthState) -> Result<&PrivateKeyStore, AppError> {
+    state.private_key_store.as_ref().ok_or_else(|| {
+        AppError::Internal("No OS keyring on this platform - enter the private key for each order".to_string())
     })
 }
 
+/// Save the private key to the OS keyring for `place_order_keyring`
+#[tauri::command]
+pub async fn store_private_key(private_key: String, state: State<'_, AuthState>) -> Result<(), AppError> {
+    let private_key = Zeroizing::new(private_key);
+    private_key_store(&state)?.store(&private_key)
+}
+
+/// Remove the private key from the OS keyring (no-op without an OS keyring)
+#[tauri::command]
+pub async fn clear_private_key(state: State<'_, AuthState>) -> Result<(), AppError> {
+    state.private_key_store.as_ref().map_or(Ok(()), PrivateKeyStore::delete)
+}
+
+/// Whether a private key is stored in the OS keyring
+#[tauri::command]
+pub async fn has_stored_private_key(state: State<'_, AuthState>) -> Result<bool, AppError> {
+    Ok(state.private_key_store.as_ref().is_some_and(PrivateKeyStore::has_key))
+}
+
 /// Set/update the Polymarket address (for fetching positions)
 #[tauri::command]
 pub async fn set_polymarket_address(address: String, state: State<'_, AuthState>) -> Result<(), AppError> {
@@ -141,6 +277,22 @@ pub async fn set_polymarket_address(address: String, state: State<'_, AuthState>
     Ok(())
 }
 
+/// Set the account type (EOA, proxy or Safe) used for balance queries and order signing
+#[tauri::command]
+pub async fn set_signature_type(
+    signature_type: SignatureType,
+    state: State<'_, AuthState>,
+) -> Result<(), AppError> {
+    tracing::info!("Setting signature type: {:?}", signature_type);
+
+    state.database.settings().set_u64(SIGNATURE_TYPE_SETTING, signature_type.as_u8() as u64)?;
+
+    let mut client = state.clob_client.write();
+    *client = client.clone().with_signature_type(signature_type);
+
+    Ok(())
+}
+
 /// Get user's USDC balance
 #[tauri::command]
 pub async fn get_balance(state: State<'_, AuthState>) -> Result<Balance, AppError> {
@@ -172,18 +324,235 @@ pub async fn get_balance(state: State<'_, AuthState>) -> Result<Balance, AppErro
     result.map_err(AppError::from)
 }
 
+/// Poll the balance every `interval_ms` and emit balance_update events when it changes
+/// AIDEV-NOTE: Polls with the client current at call time - call again after login
+/// or set_signature_type. Async so the task is spawned inside the Tokio runtime.
+#[tauri::command]
+pub async fn start_balance_polling(
+    state: State<'_, AuthState>,
+    ws_state: State<'_, WebSocketState>,
+    interval_ms: u64,
+) -> Result<(), AppError> {
+    if interval_ms == 0 {
+        return Err(AppError::Internal("Invalid interval: interval_ms must be greater than zero".to_string()));
+    }
+
+    let client = state.clob_client.read().clone();
+    let emitter = ws_state.manager.emitter().clone();
+    state.balance_watcher.start(client, emitter, Duration::from_millis(interval_ms));
+    Ok(())
+}
+
+/// Stop background balance polling
+#[tauri::command]
+pub fn stop_balance_polling(state: State<'_, AuthState>) {
+    state.balance_watcher.stop();
+}
+
 /// Get user's positions (requires Polymarket address, may differ from signing address)
+/// Optionally drops dust below `min_size` shares and sorts by `sort_by`
 #[tauri::command]
-pub async fn get_positions(address: String, state: State<'_, AuthState>) -> Result<Vec<Position>, AppError> {
+pub async fn get_positions(
+    address: String,
+    sort_by: Option<PositionSortKey>,
+    min_size: Option<f64>,
+    state: State<'_, AuthState>,
+    gamma_client: State<'_, GammaClient>,
+) -> Result<Vec<Position>, AppError> {
     // Clone the client to avoid holding the guard across await
     let client = state.clob_client.read().clone();
-    client.get_positions(&address).await.map_err(AppError::from)
+    let positions = client.get_positions_filtered(&address, sort_by, min_size).await?;
+    let positions = gamma_client.enrich_positions(positions, ENRICHMENT_CONCURRENCY).await;
+
+    // Price updates keep these current via "position_updated" until the next fetch
+    state.position_tracker.replace(&positions);
+    Ok(positions)
+}
+
+/// Aggregate P&L for a Polymarket address (no auth needed)
+#[tauri::command]
+pub async fn get_pnl_summary(address: String, state: State<'_, AuthState>) -> Result<PnlSummary, AppError> {
+    let client = state.clob_client.read().clone();
+    client.get_pnl_summary(&address).await.map_err(AppError::from)
 }
 
-/// Get user's open orders
+/// Positions, open orders and P&L in one round trip (fetched concurrently)
 #[tauri::command]
-pub async fn get_orders(state: State<'_, AuthState>) -> Result<Vec<Order>, AppError> {
+pub async fn get_portfolio_summary(
+    address: String,
+    state: State<'_, AuthState>,
+    gamma_client: State<'_, GammaClient>,
+) -> Result<PortfolioSummary, AppError> {
+    let client = state.clob_client.read().clone();
+    let mut summary = client.get_portfolio_summary(&address).await?;
+    summary.positions = gamma_client.enrich_positions(summary.positions, ENRICHMENT_CONCURRENCY).await;
+
+    state.position_tracker.replace(&summary.positions);
+    Ok(summary)
+}
+
+/// Get the leaderboard for a period ("day", "week", "month", "all")
+#[tauri::command]
+pub async fn get_leaderboard(
+    period: String,
+    state: State<'_, AuthState>,
+) -> Result<Vec<LeaderboardEntry>, AppError> {
+    let client = state.clob_client.read().clone();
+    client.get_leaderboard(&period).await.map_err(AppError::from)
+}
+
+/// Get the all-time rank for a Polymarket address (None if unranked)
+#[tauri::command]
+pub async fn get_user_rank(
+    address: String,
+    state: State<'_, AuthState>,
+) -> Result<Option<LeaderboardEntry>, AppError> {
+    let client = state.clob_client.read().clone();
+    client.get_user_rank(&address).await.map_err(AppError::from)
+}
+
+/// Get user's open orders, optionally filtered by market (condition ID) and status
+#[tauri::command]
+pub async fn get_orders(
+    state: State<'_, AuthState>,
+    market: Option<String>,
+    status: Option<String>,
+) -> Result<Vec<Order>, AppError> {
     // Clone the client to avoid holding the guard across await
     let client = state.clob_client.read().clone();
-    client.get_orders().await.map_err(AppError::from)
+    client
+        .get_orders(market.as_deref(), status.as_deref())
+        .await
+        .map_err(AppError::from)
+}
+
+/// Poll open orders every `interval_ms` and emit order_update events for fills and cancels
+/// AIDEV-NOTE: Same lifecycle as start_balance_polling - call again after login
+#[tauri::command]
+pub async fn start_order_polling(
+    state: State<'_, AuthState>,
+    ws_state: State<'_, WebSocketState>,
+    interval_ms: u64,
+) -> Result<(), AppError> {
+    if interval_ms == 0 {
+        return Err(AppError::Internal("Invalid interval: interval_ms must be greater than zero".to_string()));
+    }
+
+    let client = state.clob_client.read().clone();
+    let emitter = ws_state.manager.emitter().clone();
+    state.order_watcher.start(client, emitter, Duration::from_millis(interval_ms));
+    Ok(())
+}
+
+/// Stop background order polling
+#[tauri::command]
+pub fn stop_order_polling(state: State<'_, AuthState>) {
+    state.order_watcher.stop();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use parking_lot::RwLock;
+    use std::sync::Arc;
+
+    use polymarket_rs::{BalanceWatcher, OrderWatcher};
+    use crate::alerts::AlertCache;
+    use crate::positions::PositionTracker;
+
+    const POLYMARKET_ADDRESS: &str = "0xfunder";
+
+    fn credentials(api_key: &str) -> ApiCredentials {
+        ApiCredentials {
+            api_key: api_key.to_string(),
+            api_secret: "c2VjcmV0".to_string(),
+            api_passphrase: "pass".to_string(),
+            address: "0x0000000000000000000000000000000000000001".to_string(),
+        }
+    }
+
+    /// Logged-in state, as after `login` with a Polymarket address set
+    fn logged_in_state() -> AuthState {
+        let database = Arc::new(Database::open_in_memory());
+        let old = credentials("old-key");
+        database.store_credentials(&old, Some(POLYMARKET_ADDRESS)).unwrap();
+
+        let mut client = ClobClient::new();
+        client.set_credentials(&old).unwrap();
+        AuthState {
+            credentials: RwLock::new(Some(old)),
+            clob_client: RwLock::new(client),
+            database,
+            polymarket_address: RwLock::new(Some(POLYMARKET_ADDRESS.to_string())),
+            balance_watcher: BalanceWatcher::new(),
+            order_watcher: OrderWatcher::new(),
+            position_tracker: PositionTracker::new(),
+            price_alerts: AlertCache::new(),
+            private_key_store: None,
+        }
+    }
+
+    #[test]
+    fn test_install_rotated_credentials() {
+        let state = logged_in_state();
+
+        install_rotated_credentials(&state, &credentials("new-key")).unwrap();
+
+        assert_eq!(state.credentials.read().as_ref().unwrap().api_key, "new-key");
+        assert!(state.clob_client.read().is_authenticated());
+        let (stored, polymarket_address) = state.database.load_credentials().unwrap().unwrap();
+        assert_eq!(stored.api_key, "new-key");
+        assert_eq!(polymarket_address.as_deref(), Some(POLYMARKET_ADDRESS));
+    }
+
+    #[test]
+    fn test_install_rotated_credentials_rejects_malformed() {
+        let state = logged_in_state();
+        let malformed = ApiCredentials { api_secret: "not base64!".to_string(), ..credentials("new-key") };
+
+        assert!(install_rotated_credentials(&state, &malformed).is_err());
+
+        // Nothing was replaced
+        assert_eq!(state.credentials.read().as_ref().unwrap().api_key, "old-key");
+        assert_eq!(state.database.load_credentials().unwrap().unwrap().0.api_key, "old-key");
+    }
+
+    #[test]
+    fn test_failed_rotation_clears_revoked_credentials() {
+        let state = logged_in_state();
+
+        // What rotate_credentials does when creation fails after the delete
+        clear_session(&state).unwrap();
+
+        assert!(state.credentials.read().is_none());
+        assert!(!state.clob_client.read().is_authenticated());
+        assert!(state.polymarket_address.read().is_none());
+        assert!(state.database.load_credentials().unwrap().is_none());
+    }
+
+    #[test]
+    fn test_clear_session_keeps_account_type() {
+        let state = logged_in_state();
+        {
+            let mut client = state.clob_client.write();
+            *client = client.clone().with_signature_type(SignatureType::GnosisSafe);
+        }
+
+        clear_session(&state).unwrap();
+
+        assert_eq!(state.clob_client.read().signature_type(), SignatureType::GnosisSafe);
+    }
+
+    #[test]
+    fn test_balance_signature_type_without_setting_is_safe() {
+        let database = Database::open_in_memory();
+        let client = ClobClient::new().with_signature_type(balance_signature_type(&database));
+
+        // signature_type=2 on the balance query, as before account types were stored
+        assert_eq!(client.signature_type(), SignatureType::GnosisSafe);
+        assert_eq!(client.signature_type().as_u8(), 2);
+
+        database.settings().set_u64(SIGNATURE_TYPE_SETTING, SignatureType::Proxy.as_u8() as u64).unwrap();
+        assert_eq!(balance_signature_type(&database), SignatureType::Proxy);
+    }
 }
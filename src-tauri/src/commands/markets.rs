@@ -1,12 +1,17 @@
 // AIDEV-NOTE: Market commands - fetching market data from Gamma/CLOB APIs
 
+use std::collections::HashMap;
+use std::time::Duration;
+
 use serde::{Deserialize, Serialize};
 use tauri::State;
 use tracing::{debug, instrument};
 
-use polymarket_rs::{Event, GammaClient, Market, PricePoint};
+use polymarket_rs::api::recommended_fidelity;
+use polymarket_rs::{BookSpread, ClobTrade, Event, GammaClient, Holder, Market, MarketCategory, MarketFilter, MarketQuote, MarketSummary, PricePoint, ResolutionSource, TokenQuote};
+use crate::db::Database;
 use crate::error::AppError;
-use crate::AuthState;
+use crate::{AuthState, WebSocketState};
 
 // AIDEV-NOTE: Commands are invoked from frontend via invoke("command_name", { args })
 // Keep command signatures in sync with src/lib/tauri.ts
@@ -19,9 +24,88 @@ pub async fn get_markets(
     query: Option<String>,
     limit: Option<u32>,
     offset: Option<u32>,
+    accepting_orders_only: Option<bool>,
 ) -> Result<Vec<Market>, AppError> {
+    let filter = MarketFilter { query, limit, offset, ..Default::default() }
+        .accepting_orders_only(accepting_orders_only.unwrap_or(false));
     gamma_client
-        .get_markets(query.as_deref(), limit, offset)
+        .get_markets_filtered(&filter)
+        .await
+        .map_err(AppError::from)
+}
+
+/// Same as get_markets but returns compact quotes for list views
+#[tauri::command]
+#[instrument(skip(gamma_client))]
+pub async fn get_markets_lite(
+    gamma_client: State<'_, GammaClient>,
+    query: Option<String>,
+    limit: Option<u32>,
+    offset: Option<u32>,
+    accepting_orders_only: Option<bool>,
+) -> Result<Vec<MarketQuote>, AppError> {
+    let markets = get_markets(gamma_client, query, limit, offset, accepting_orders_only).await?;
+    Ok(markets.iter().map(Market::to_quote).collect())
+}
+
+/// Fetch markets as lightweight summaries (no tokens, description or images)
+#[tauri::command]
+#[instrument(skip(gamma_client))]
+pub async fn get_market_summaries(
+    gamma_client: State<'_, GammaClient>,
+    filter: Option<MarketFilter>,
+) -> Result<Vec<MarketSummary>, AppError> {
+    let markets = gamma_client.get_markets_filtered(&filter.unwrap_or_default()).await?;
+    Ok(markets.iter().map(Market::to_summary).collect())
+}
+
+/// Look up the market an outcome token (CLOB token ID) belongs to
+#[tauri::command]
+#[instrument(skip(gamma_client))]
+pub async fn get_market_for_token(
+    gamma_client: State<'_, GammaClient>,
+    token_id: String,
+) -> Result<Market, AppError> {
+    gamma_client.get_market_by_token_id(&token_id).await.map_err(AppError::from)
+}
+
+/// Fetch resolved/archived markets for research and backtesting
+#[tauri::command]
+#[instrument(skip(gamma_client))]
+pub async fn get_historical_markets(
+    gamma_client: State<'_, GammaClient>,
+    limit: Option<u32>,
+) -> Result<Vec<Market>, AppError> {
+    let filter = MarketFilter { limit, ..Default::default() }
+        .include_archived(true)
+        .include_closed(true);
+    gamma_client.get_markets_filtered(&filter).await.map_err(AppError::from)
+}
+
+/// Active markets ending within the next `hours`, optionally only those accepting orders
+#[tauri::command]
+#[instrument(skip(gamma_client))]
+pub async fn get_markets_resolving_soon(
+    gamma_client: State<'_, GammaClient>,
+    hours: u32,
+    active_trading_only: bool,
+    limit: Option<u32>,
+) -> Result<Vec<Market>, AppError> {
+    gamma_client
+        .get_markets_resolving_within(hours, limit, active_trading_only)
+        .await
+        .map_err(AppError::from)
+}
+
+/// Fetch markets resolved by a specific oracle ("uma", "chainlink", "manual", or any custom name)
+#[tauri::command]
+#[instrument(skip(gamma_client))]
+pub async fn get_markets_by_resolution_source(
+    gamma_client: State<'_, GammaClient>,
+    source: String,
+) -> Result<Vec<Market>, AppError> {
+    gamma_client
+        .get_markets_by_resolution_source(ResolutionSource::from(source.as_str()))
         .await
         .map_err(AppError::from)
 }
@@ -47,6 +131,106 @@ pub async fn get_events(
     gamma_client.get_events(limit).await.map_err(AppError::from)
 }
 
+/// List available market categories (cached for an hour by GammaClient)
+#[tauri::command]
+#[instrument(skip(gamma_client))]
+pub async fn get_market_categories(
+    gamma_client: State<'_, GammaClient>,
+) -> Result<Vec<MarketCategory>, AppError> {
+    gamma_client.get_market_categories().await.map_err(AppError::from)
+}
+
+/// Fetch a single event with nested markets, by slug or numeric ID
+#[tauri::command]
+#[instrument(skip(gamma_client))]
+pub async fn get_event(
+    gamma_client: State<'_, GammaClient>,
+    slug: String,
+) -> Result<Event, AppError> {
+    gamma_client.get_event(&slug).await.map_err(AppError::from)
+}
+
+/// Markets of one event, without fetching the event itself
+#[tauri::command]
+#[instrument(skip(gamma_client))]
+pub async fn get_event_markets(
+    gamma_client: State<'_, GammaClient>,
+    event_id: String,
+) -> Result<Vec<Market>, AppError> {
+    gamma_client.get_event_markets(&event_id).await.map_err(AppError::from)
+}
+
+/// Poll `condition_ids` every `interval_ms` and emit market_resolved as each one settles
+/// AIDEV-NOTE: Replaces the previous watch list - pass every market to watch (e.g. the
+/// positions' condition IDs). Stops by itself once all of them have resolved.
+#[tauri::command]
+pub async fn start_resolution_watch(
+    gamma_client: State<'_, GammaClient>,
+    ws_state: State<'_, WebSocketState>,
+    condition_ids: Vec<String>,
+    interval_ms: u64,
+) -> Result<(), AppError> {
+    if interval_ms == 0 {
+        return Err(AppError::Internal("Invalid interval: interval_ms must be greater than zero".to_string()));
+    }
+
+    let emitter = ws_state.manager.emitter().clone();
+    ws_state.resolution_watcher.start(
+        gamma_client.inner().clone(),
+        condition_ids,
+        emitter,
+        Duration::from_millis(interval_ms),
+    );
+    Ok(())
+}
+
+/// Stop watching markets for resolution
+#[tauri::command]
+pub fn stop_resolution_watch(ws_state: State<'_, WebSocketState>) {
+    ws_state.resolution_watcher.stop();
+}
+
+/// Recent public trades in a market (backfill for the live trades panel)
+#[tauri::command]
+#[instrument(skip(auth_state))]
+pub async fn get_market_activity(
+    auth_state: State<'_, AuthState>,
+    condition_id: String,
+    limit: Option<u32>,
+) -> Result<Vec<ClobTrade>, AppError> {
+    let clob_client = auth_state.clob_client.read().clone();
+    clob_client
+        .get_market_activity(&condition_id, limit.unwrap_or(50))
+        .await
+        .map_err(AppError::from)
+}
+
+/// Top holders of an outcome token
+#[tauri::command]
+#[instrument(skip(auth_state))]
+pub async fn get_holders(
+    auth_state: State<'_, AuthState>,
+    condition_id: String,
+    token_id: String,
+) -> Result<Vec<Holder>, AppError> {
+    let clob_client = auth_state.clob_client.read().clone();
+    clob_client
+        .get_holders(&condition_id, &token_id)
+        .await
+        .map_err(AppError::from)
+}
+
+/// Best bid/ask, spread and midpoint for a token (top of the REST order book)
+#[tauri::command]
+#[instrument(skip(auth_state))]
+pub async fn get_book_spread(
+    auth_state: State<'_, AuthState>,
+    token_id: String,
+) -> Result<BookSpread, AppError> {
+    let clob_client = auth_state.clob_client.read().clone();
+    clob_client.get_book_spread(&token_id).await.map_err(AppError::from)
+}
+
 /// Search markets by text query
 #[tauri::command]
 #[instrument(skip(gamma_client))]
@@ -57,6 +241,160 @@ pub async fn search_markets(
     gamma_client.search_markets(&query).await.map_err(AppError::from)
 }
 
+// ========== Dashboard ==========
+
+/// Maximum concurrent token quote fetches for refresh_dashboard
+const DASHBOARD_CONCURRENCY: usize = 8;
+
+/// Batched quotes for a dashboard refresh
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DashboardSnapshot {
+    /// Quotes keyed by token ID
+    pub quotes: HashMap<String, TokenQuote>,
+    /// Unix timestamp (seconds) when the snapshot was taken
+    pub fetched_at: i64,
+}
+
+/// Refresh midpoint/last prices for many tokens in one call
+/// AIDEV-NOTE: Replaces per-token get_market/get_price_history round trips from the dashboard
+#[tauri::command]
+#[instrument(skip(auth_state, token_ids), fields(tokens = token_ids.len()))]
+pub async fn refresh_dashboard(
+    auth_state: State<'_, AuthState>,
+    token_ids: Vec<String>,
+) -> Result<DashboardSnapshot, AppError> {
+    // Clone client to avoid holding lock across await
+    let clob_client = auth_state.clob_client.read().clone();
+    let quotes = clob_client.get_token_quotes(&token_ids, DASHBOARD_CONCURRENCY).await;
+
+    debug!("Refreshed {} dashboard quotes", quotes.len());
+
+    Ok(DashboardSnapshot {
+        quotes,
+        fetched_at: chrono::Utc::now().timestamp(),
+    })
+}
+
+// ========== Offline Market Cache ==========
+
+/// Markets cached more recently than this are served without refetching
+const MARKETS_CACHE_TTL_SECS: i64 = 15 * 60;
+
+/// Fetch markets from Gamma into the local cache (unless it is still fresh) and return them
+/// AIDEV-NOTE: `force_refresh` refetches even within the TTL; on fetch failure the stale cache is
+/// returned if there is one, so the UI keeps working offline
+#[tauri::command]
+#[instrument(skip(gamma_client, auth_state))]
+pub async fn cache_markets(
+    gamma_client: State<'_, GammaClient>,
+    auth_state: State<'_, AuthState>,
+    limit: Option<u32>,
+    force_refresh: Option<bool>,
+) -> Result<Vec<Market>, AppError> {
+    let db = &auth_state.database;
+    let now = chrono::Utc::now().timestamp();
+
+    let fresh = db
+        .markets_cached_at()?
+        .is_some_and(|cached_at| now - cached_at < MARKETS_CACHE_TTL_SECS);
+    if fresh && !force_refresh.unwrap_or(false) {
+        debug!("Market cache is fresh, skipping fetch");
+        return db.get_cached_markets(limit);
+    }
+
+    let filter = MarketFilter { limit, ..Default::default() };
+    match gamma_client.get_markets_filtered(&filter).await {
+        Ok(markets) => {
+            db.store_cached_markets(&markets, now)?;
+            Ok(markets)
+        }
+        Err(e) => {
+            let cached = db.get_cached_markets(limit)?;
+            if cached.is_empty() {
+                return Err(e.into());
+            }
+            tracing::warn!("Market fetch failed, serving {} cached markets: {}", cached.len(), e);
+            Ok(cached)
+        }
+    }
+}
+
+/// Read markets from the local cache without touching the network
+#[tauri::command]
+pub async fn get_cached_markets(
+    auth_state: State<'_, AuthState>,
+    limit: Option<u32>,
+) -> Result<Vec<Market>, AppError> {
+    auth_state.database.get_cached_markets(limit)
+}
+
+// ========== Chart Settings ==========
+
+/// Settings namespace for chart preferences (editable via save_settings)
+const CHART_SETTINGS_PREFIX: &str = "chart.";
+
+/// Effective chart preferences: stored "chart.*" settings over the built-in defaults
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ChartSettings {
+    /// Interval used when a request doesn't name one ("chart.default_interval")
+    pub default_interval: String,
+    /// Fixed resolution in minutes, None = recommended for the interval ("chart.fidelity")
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub fidelity: Option<u32>,
+    /// Refetch once the newest cached point is older than this ("chart.cache_max_age_secs")
+    pub cache_max_age_secs: i64,
+}
+
+impl Default for ChartSettings {
+    fn default() -> Self {
+        Self {
+            default_interval: "max".to_string(),
+            fidelity: None,
+            cache_max_age_secs: 300,
+        }
+    }
+}
+
+impl ChartSettings {
+    /// Load from the settings table
+    /// Never fails - TypedSettings falls back to the defaults for bad or unreadable values
+    pub fn load(db: &Database) -> Self {
+        let defaults = Self::default();
+        let settings = db.settings();
+        let key = |name: &str| format!("{}{}", CHART_SETTINGS_PREFIX, name);
+
+        let default_interval = settings
+            .get_string(&key("default_interval"))
+            .map(|interval| interval.trim().to_string())
+            .filter(|interval| !interval.is_empty())
+            .unwrap_or(defaults.default_interval);
+        // 0 (or unset) = recommended for the interval
+        let fidelity = u32::try_from(settings.get_u64(&key("fidelity"), 0)).ok().filter(|f| *f > 0);
+        let cache_max_age_secs = settings.get_u64(&key("cache_max_age_secs"), defaults.cache_max_age_secs as u64);
+
+        Self {
+            default_interval,
+            fidelity,
+            cache_max_age_secs: i64::try_from(cache_max_age_secs).unwrap_or(defaults.cache_max_age_secs),
+        }
+    }
+}
+
+/// Current chart preferences (defaults filled in for anything not set)
+#[tauri::command]
+pub async fn get_chart_settings(auth_state: State<'_, AuthState>) -> Result<ChartSettings, AppError> {
+    Ok(ChartSettings::load(&auth_state.database))
+}
+
+/// Drop all stored chart preferences, returning to the defaults
+#[tauri::command]
+pub async fn reset_chart_settings(auth_state: State<'_, AuthState>) -> Result<ChartSettings, AppError> {
+    auth_state.database.delete_settings_with_prefix(CHART_SETTINGS_PREFIX)?;
+    Ok(ChartSettings::default())
+}
+
 // ========== Price History ==========
 
 /// Price history request parameters
@@ -65,10 +403,10 @@ pub async fn search_markets(
 pub struct PriceHistoryParams {
     /// Token ID (CLOB token ID, long numeric string)
     pub token_id: String,
-    /// Time interval: "1h", "6h", "1d", "1w", "max" (default: "max")
+    /// Time interval: "1h", "6h", "1d", "1w", "max" (default: chart.default_interval)
     #[serde(default)]
     pub interval: Option<String>,
-    /// Resolution in minutes (e.g., 60 for hourly)
+    /// Resolution in minutes (e.g., 60 for hourly; default: chart.fidelity, else recommended)
     #[serde(default)]
     pub fidelity: Option<u32>,
 }
@@ -95,6 +433,8 @@ pub async fn get_price_history(
 ) -> Result<PriceHistoryResult, AppError> {
     let token_id = &params.token_id;
     let db = &auth_state.database;
+    let chart = ChartSettings::load(db);
+    let interval = params.interval.as_deref().unwrap_or(&chart.default_interval);
 
     // 1. Check cached data
     let cached = db.get_price_history(token_id, None, None)?;
@@ -105,10 +445,10 @@ pub async fn get_price_history(
     let latest_cached_ts = db.get_latest_price_timestamp(token_id)?;
     let now = chrono::Utc::now().timestamp();
 
-    // Fetch if no cache or cache is older than 5 minutes
+    // Fetch if no cache or cache is older than chart.cache_max_age_secs (5 minutes by default)
     let should_fetch = match latest_cached_ts {
         None => true,
-        Some(ts) => (now - ts) > 300, // 5 minutes
+        Some(ts) => (now - ts) > chart.cache_max_age_secs,
     };
 
     let mut fetched_count = 0;
@@ -121,11 +461,17 @@ pub async fn get_price_history(
         // Use startTs if we have cached data to get incremental updates
         let start_ts = latest_cached_ts.map(|ts| ts + 1);
 
+        // AIDEV-NOTE: Without an explicit fidelity, "max" charts would return minute bars
+        let fidelity = params
+            .fidelity
+            .or(chart.fidelity)
+            .unwrap_or_else(|| recommended_fidelity(interval));
+
         let api_result = clob_client
             .get_price_history(
                 token_id,
-                params.interval.as_deref(),
-                params.fidelity,
+                Some(interval),
+                Some(fidelity),
                 start_ts,
                 None,
             )
@@ -169,3 +515,42 @@ pub async fn get_price_history(
         fetched_count,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chart_settings_defaults_when_unset() {
+        let db = Database::open_in_memory();
+
+        let chart = ChartSettings::load(&db);
+
+        assert_eq!(chart.default_interval, "max");
+        assert_eq!(chart.fidelity, None);
+        assert_eq!(chart.cache_max_age_secs, 300);
+    }
+
+    #[test]
+    fn test_chart_settings_reads_stored_values_and_falls_back() {
+        let db = Database::open_in_memory();
+        db.store_settings_batch(&[
+            ("chart.default_interval", " 1w "),
+            ("chart.fidelity", "60"),
+            ("chart.cache_max_age_secs", "-5"),
+        ])
+        .unwrap();
+
+        let chart = ChartSettings::load(&db);
+
+        assert_eq!(chart.default_interval, "1w");
+        assert_eq!(chart.fidelity, Some(60));
+        // Negative age doesn't parse - default
+        assert_eq!(chart.cache_max_age_secs, 300);
+
+        db.store_settings_batch(&[("chart.default_interval", ""), ("chart.fidelity", "hourly")]).unwrap();
+        let chart = ChartSettings::load(&db);
+        assert_eq!(chart.default_interval, "max");
+        assert_eq!(chart.fidelity, None);
+    }
+}
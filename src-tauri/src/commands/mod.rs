@@ -1,9 +1,13 @@
+pub mod alerts;
 pub mod auth;
 pub mod markets;
+pub mod settings;
 pub mod trading;
 pub mod websocket;
 
+pub use alerts::*;
 pub use auth::*;
 pub use markets::*;
+pub use settings::*;
 pub use trading::*;
 pub use websocket::*;
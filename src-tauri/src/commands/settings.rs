@@ -0,0 +1,34 @@
+// AIDEV-NOTE: Tauri commands for user settings - persisted in the SQLite settings table
+
+use std::collections::HashMap;
+
+use tauri::State;
+
+use crate::error::AppError;
+use crate::AuthState;
+
+/// Save multiple settings atomically
+#[tauri::command]
+pub async fn save_settings(
+    settings: HashMap<String, String>,
+    state: State<'_, AuthState>,
+) -> Result<(), AppError> {
+    tracing::debug!("Saving {} settings", settings.len());
+
+    let pairs: Vec<(&str, &str)> = settings
+        .iter()
+        .map(|(k, v)| (k.as_str(), v.as_str()))
+        .collect();
+
+    state.database.store_settings_batch(&pairs)
+}
+
+/// Load settings by key (missing keys are omitted from the result)
+#[tauri::command]
+pub async fn get_settings(
+    keys: Vec<String>,
+    state: State<'_, AuthState>,
+) -> Result<HashMap<String, String>, AppError> {
+    let keys: Vec<&str> = keys.iter().map(String::as_str).collect();
+    state.database.get_settings_batch(&keys)
+}
@@ -1,14 +1,20 @@
 // AIDEV-NOTE: Tauri commands for trading - place/cancel orders
 // Orders require EIP-712 signing with CTF Exchange domain
 
-use tauri::State;
+use std::time::Duration;
+
+use tauri::{AppHandle, Emitter, State};
+use tokio::time::Instant;
+use zeroize::Zeroizing;
 
 use polymarket_rs::api::order::{
-    CancelResponse, OrderParams, OrderSide, PlaceOrderResponse,
-    SignatureType, UnsignedOrder,
+    CancelResponse, FeeRates, OrderParams, OrderSide, OrderType, PlaceOrderRequest,
+    PlaceOrderResponse, SignatureType, SignedOrder, UnsignedOrder,
 };
-use polymarket_rs::OrderSigner;
+use polymarket_rs::api::MAX_BATCH_ORDERS;
+use polymarket_rs::{Backoff, ClobClient, Order, OrderFill, OrderSigner, ReconnectConfig};
 use crate::error::AppError;
+use crate::events::OrderStatusChanged;
 use crate::AuthState;
 
 /// Place a new order
@@ -19,8 +25,106 @@ pub async fn place_order(
     private_key: String,
     state: State<'_, AuthState>,
 ) -> Result<PlaceOrderResponse, AppError> {
+    // Wiped when the command returns, whatever the outcome
+    let private_key = Zeroizing::new(private_key);
     tracing::info!("Placing order: side={:?}, price={}, size={}", params.side, params.price, params.size);
 
+    let signer = OrderSigner::from_private_key(&private_key)?;
+    sign_and_place(&params, &signer, &state).await
+}
+
+/// Place a new order, signed with the key saved by `store_private_key`
+/// AIDEV-NOTE: The key is read from the OS keyring here, so it never crosses IPC per order
+#[tauri::command]
+pub async fn place_order_keyring(
+    params: OrderParams,
+    state: State<'_, AuthState>,
+) -> Result<PlaceOrderResponse, AppError> {
+    tracing::info!("Placing order (keyring): side={:?}, price={}, size={}", params.side, params.price, params.size);
+
+    let signer = {
+        let private_key = crate::commands::private_key_store(&state)?.load()?;
+        OrderSigner::from_private_key(&private_key)?
+    };
+    sign_and_place(&params, &signer, &state).await
+}
+
+/// Sign one order and place it via the API
+async fn sign_and_place(
+    params: &OrderParams,
+    signer: &OrderSigner,
+    state: &AuthState,
+) -> Result<PlaceOrderResponse, AppError> {
+    let owner = authenticated_owner(state)?;
+    let signed_order = prepare_signed_order(params, signer, state).await?;
+
+    // Place via API
+    let client = state.clob_client.read().clone();
+    let result = client.place_order(signed_order, &owner, params.order_type).await?;
+
+    if result.success {
+        tracing::info!("Order placed successfully: {:?}", result.order_id);
+    } else {
+        tracing::warn!("Order placement failed: {:?}", result.error_msg);
+    }
+
+    Ok(result)
+}
+
+/// Place several orders in one request (e.g. both sides of a market)
+/// Results are in the same order as `orders`; each may succeed or fail independently
+#[tauri::command]
+pub async fn place_orders(
+    orders: Vec<OrderParams>,
+    private_key: String,
+    state: State<'_, AuthState>,
+) -> Result<Vec<PlaceOrderResponse>, AppError> {
+    let private_key = Zeroizing::new(private_key);
+    tracing::info!("Placing batch of {} orders", orders.len());
+
+    if orders.len() > MAX_BATCH_ORDERS {
+        return Err(AppError::Internal(format!(
+            "Too many orders: {} (max {} per batch)", orders.len(), MAX_BATCH_ORDERS
+        )));
+    }
+
+    let owner = authenticated_owner(&state)?;
+    let signer = OrderSigner::from_private_key(&private_key)?;
+
+    // AIDEV-NOTE: Sign everything before sending anything - a bad leg fails the whole batch
+    let mut requests = Vec::with_capacity(orders.len());
+    for params in &orders {
+        let signed_order = prepare_signed_order(params, &signer, &state).await?;
+        requests.push(PlaceOrderRequest {
+            order: signed_order,
+            owner: owner.clone(),
+            order_type: params.order_type,
+        });
+    }
+
+    let client = state.clob_client.read().clone();
+    let results = client.place_orders_batch(requests).await?;
+
+    let placed = results.iter().filter(|r| r.success).count();
+    tracing::info!("Batch placed {}/{} orders", placed, results.len());
+
+    Ok(results)
+}
+
+/// API key owner address, or an error if not logged in
+fn authenticated_owner(state: &AuthState) -> Result<String, AppError> {
+    let credentials = state.credentials.read();
+    credentials.as_ref()
+        .map(|c| c.address.clone())
+        .ok_or_else(|| AppError::Internal("Not authenticated".to_string()))
+}
+
+/// Validate params, run the FOK price-impact check, then build and sign the order
+async fn prepare_signed_order(
+    params: &OrderParams,
+    signer: &OrderSigner,
+    state: &AuthState,
+) -> Result<SignedOrder, AppError> {
     // Validate params
     if params.price <= 0.0 || params.price >= 1.0 {
         return Err(AppError::Internal(format!(
@@ -31,22 +135,28 @@ pub async fn place_order(
         return Err(AppError::Internal("Invalid size: must be positive".to_string()));
     }
 
-    // Get owner address from credentials
-    let owner = {
-        let credentials = state.credentials.read();
-        credentials.as_ref()
-            .map(|c| c.address.clone())
-            .ok_or_else(|| AppError::Internal("Not authenticated".to_string()))?
-    };
+    let owner = authenticated_owner(state)?;
+    let funder = state.polymarket_address.read().clone();
+    let stored_type = crate::commands::stored_signature_type(&state.database);
+
+    // AIDEV-NOTE: FOK fills immediately against the book - when the caller set a limit,
+    // refuse before signing if the walk-the-book VWAP is too far from the requested price
+    if let (OrderType::Fok, Some(max_slippage_pct)) = (params.order_type, params.max_slippage_pct) {
+        let client = state.clob_client.read().clone();
+        check_price_impact(&client, params, max_slippage_pct).await?;
+    }
 
-    // Create order signer
-    let signer = OrderSigner::from_private_key(&private_key)?;
     let signer_address = signer.address_string();
 
-    tracing::debug!("Signer address: {}, Owner address: {}", signer_address, owner);
+    let (maker, signature_type) = order_account(stored_type, &owner, &signer_address, funder.as_deref());
+
+    tracing::debug!(
+        "Signer address: {}, Maker: {}, Signature type: {:?}",
+        signer_address, maker, signature_type
+    );
 
     // Build unsigned order from params
-    let unsigned_order = build_order_from_params(&params, &owner, &signer_address)?;
+    let unsigned_order = build_order_from_params(params, maker, &signer_address, signature_type)?;
 
     tracing::debug!("Built order: salt={}, maker_amount={}, taker_amount={}",
         unsigned_order.salt, unsigned_order.maker_amount, unsigned_order.taker_amount);
@@ -56,17 +166,85 @@ pub async fn place_order(
 
     tracing::debug!("Order signed: {}", signed_order.signature);
 
-    // Place via API
+    Ok(signed_order)
+}
+
+/// Get fills for an order (also cached in the local trades table)
+#[tauri::command]
+pub async fn get_order_fills(
+    order_id: String,
+    state: State<'_, AuthState>,
+) -> Result<Vec<OrderFill>, AppError> {
     let client = state.clob_client.read().clone();
-    let result = client.place_order(signed_order, &owner, params.order_type).await?;
+    let fills = client.get_trades_for_order(&order_id).await?;
 
-    if result.success {
-        tracing::info!("Order placed successfully: {:?}", result.order_id);
-    } else {
-        tracing::warn!("Order placement failed: {:?}", result.error_msg);
+    for fill in &fills {
+        if let Err(e) = state.database.store_trade(fill) {
+            tracing::warn!("Failed to cache fill {}: {}", fill.trade_id, e);
+        }
     }
 
-    Ok(result)
+    Ok(fills)
+}
+
+/// Poll an order until its status is `target_status` (case-insensitive) or `timeout_secs` pass
+/// Returns the matching order, or the last one seen on timeout. Emits "order_status_changed"
+/// whenever a poll sees a different status than the previous one.
+/// AIDEV-NOTE: For orders placed with status "delayed" - backoff starts at 500ms, capped at 5s
+#[tauri::command]
+pub async fn await_order_status(
+    order_id: String,
+    target_status: String,
+    timeout_secs: u32,
+    app: AppHandle,
+    state: State<'_, AuthState>,
+) -> Result<Order, AppError> {
+    let client = state.clob_client.read().clone();
+    let deadline = Instant::now() + Duration::from_secs(u64::from(timeout_secs));
+    let mut backoff = Backoff::new(ReconnectConfig {
+        initial_delay: Duration::from_millis(500),
+        max_delay: Duration::from_secs(5),
+        ..Default::default()
+    });
+    let mut last: Option<Order> = None;
+
+    loop {
+        match client.get_order(&order_id).await {
+            Ok(order) => {
+                if let Some(previous) = last.as_ref().filter(|prev| prev.status != order.status) {
+                    tracing::info!("Order {} status {} -> {}", order_id, previous.status, order.status);
+                    let payload = OrderStatusChanged { order: order.clone(), previous_status: previous.status.clone() };
+                    if let Err(e) = app.emit("order_status_changed", &payload) {
+                        tracing::error!("Failed to emit order_status_changed: {}", e);
+                    }
+                }
+                if order.status.eq_ignore_ascii_case(&target_status) {
+                    return Ok(order);
+                }
+                last = Some(order);
+            }
+            // Transient failures (order not visible yet, network) just wait for the next poll
+            Err(e) if Instant::now() < deadline => tracing::warn!("Order {} poll failed: {}", order_id, e),
+            Err(e) => return last.ok_or_else(|| AppError::from(e)),
+        }
+
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        if remaining.is_zero() {
+            break;
+        }
+        let delay = backoff.next().unwrap_or(remaining).min(remaining);
+        tokio::time::sleep(delay).await;
+    }
+
+    tracing::info!("Timed out waiting for order {} to reach {}", order_id, target_status);
+    last.ok_or_else(|| AppError::Internal(format!("Order {} not found before timeout", order_id)))
+}
+
+/// Get current maker/taker fee rates (no auth required)
+#[tauri::command]
+pub async fn get_fee_rates(state: State<'_, AuthState>) -> Result<FeeRates, AppError> {
+    let client = state.clob_client.read().clone();
+    client.get_fee_rates().await.map_err(AppError::from)
 }
 
 /// Cancel a specific order by ID
@@ -81,6 +259,18 @@ pub async fn cancel_order(
     client.cancel_order(&order_id).await.map_err(AppError::from)
 }
 
+/// Cancel a specific set of orders in one request
+#[tauri::command]
+pub async fn cancel_orders(
+    order_ids: Vec<String>,
+    state: State<'_, AuthState>,
+) -> Result<CancelResponse, AppError> {
+    tracing::info!("Cancelling {} orders", order_ids.len());
+
+    let client = state.clob_client.read().clone();
+    client.cancel_orders(&order_ids).await.map_err(AppError::from)
+}
+
 /// Cancel all open orders
 #[tauri::command]
 pub async fn cancel_all_orders(
@@ -92,6 +282,35 @@ pub async fn cancel_all_orders(
     client.cancel_all_orders().await.map_err(AppError::from)
 }
 
+/// Cancel all open orders and return the orders still open afterwards
+/// AIDEV-NOTE: One IPC round trip instead of cancel + get_orders. The cancel outcome of
+/// every order goes to the order_log table; logging failures don't fail the command.
+#[tauri::command]
+pub async fn cancel_all_orders_and_refresh(
+    state: State<'_, AuthState>,
+) -> Result<Vec<Order>, AppError> {
+    tracing::info!("Cancelling all orders (with refresh)");
+
+    let client = state.clob_client.read().clone();
+    let (cancelled, remaining) = client.cancel_all_orders_and_refresh().await?;
+
+    let outcomes: Vec<(&str, &str)> = cancelled
+        .canceled
+        .iter()
+        .map(|id| (id.as_str(), "canceled"))
+        .chain(cancelled.not_canceled.iter().map(|(id, reason)| (id.as_str(), reason.as_str())))
+        .collect();
+    if let Err(e) = state.database.log_order_outcomes("cancel_all", &outcomes) {
+        tracing::warn!("Failed to log cancel-all outcome: {}", e);
+    }
+
+    tracing::info!(
+        "Cancelled {} orders, {} not cancelled, {} still open",
+        cancelled.canceled.len(), cancelled.not_canceled.len(), remaining.len()
+    );
+    Ok(remaining)
+}
+
 /// Cancel all orders for a specific market
 #[tauri::command]
 pub async fn cancel_market_orders(
@@ -104,12 +323,88 @@ pub async fn cancel_market_orders(
     client.cancel_market_orders(&market_id).await.map_err(AppError::from)
 }
 
+/// Cancel all open orders for a single outcome token
+#[tauri::command]
+pub async fn cancel_token_orders(
+    token_id: String,
+    state: State<'_, AuthState>,
+) -> Result<CancelResponse, AppError> {
+    tracing::info!("Cancelling orders for token: {}", token_id);
+
+    let client = state.clob_client.read().clone();
+    client.cancel_token_orders(&token_id).await.map_err(AppError::from)
+}
+
+/// Estimate the fill price of a FOK order and reject it if slippage exceeds `max_slippage_pct`
+async fn check_price_impact(
+    client: &ClobClient,
+    params: &OrderParams,
+    max_slippage_pct: f64,
+) -> Result<(), AppError> {
+    if params.price <= 0.0 {
+        return Err(AppError::Internal(format!(
+            "Invalid price: must be positive, got {}", params.price
+        )));
+    }
+    let book = client.get_orderbook(&params.token_id).await?;
+
+    let Some(estimated) = book.estimate_fill_price(params.side, params.size) else {
+        return Err(AppError::UserVisible {
+            title: "Not enough liquidity".to_string(),
+            detail: format!("Order book for {} cannot fill {} shares", params.token_id, params.size),
+            code: "insufficient_liquidity".to_string(),
+        });
+    };
+
+    // Only adverse moves count: paying more on a buy, receiving less on a sell
+    let adverse = match params.side {
+        OrderSide::Buy => estimated - params.price,
+        OrderSide::Sell => params.price - estimated,
+    };
+    let slippage_pct = adverse / params.price * 100.0;
+
+    tracing::debug!(
+        "FOK price impact: requested={}, estimated={:.4}, slippage={:.2}%",
+        params.price, estimated, slippage_pct
+    );
+
+    if slippage_pct > max_slippage_pct {
+        return Err(AppError::UserVisible {
+            title: "Price impact too high".to_string(),
+            detail: format!(
+                "Estimated fill {:.4} vs requested {:.4} ({:.2}% > {:.2}% limit)",
+                estimated, params.price, slippage_pct, max_slippage_pct
+            ),
+            code: "price_impact".to_string(),
+        });
+    }
+
+    Ok(())
+}
+
+/// Maker address and signature type for an order
+/// AIDEV-NOTE: Without an account type stored by `set_signature_type`, orders are signed the
+/// way they always were - Proxy, with the API key owner as maker. Only an explicit type moves
+/// the maker to the funder wallet (polymarket_address), signed by the EOA.
+fn order_account<'a>(
+    stored_type: Option<SignatureType>,
+    owner: &'a str,
+    signer_address: &'a str,
+    funder: Option<&'a str>,
+) -> (&'a str, SignatureType) {
+    match stored_type {
+        Some(signature_type) => (signature_type.maker_address(signer_address, funder), signature_type),
+        None => (owner, SignatureType::Proxy),
+    }
+}
+
 /// Build an unsigned order from user-friendly parameters
 /// AIDEV-NOTE: Converts price/size to makerAmount/takerAmount based on side
 fn build_order_from_params(
     params: &OrderParams,
-    owner: &str,
+    maker: &str,
     signer_address: &str,
+    signature_type: SignatureType,
 ) -> Result<UnsignedOrder, AppError> {
     use rand::Rng;
 
@@ -141,6 +436,11 @@ fn build_order_from_params(
         }
     };
 
+    // AIDEV-NOTE: GTD orders must say when they expire - never fall back to the default
+    if params.order_type == OrderType::Gtd && params.expiration_secs.unwrap_or(0) == 0 {
+        return Err(AppError::Internal("GTD orders require an explicit expiration_secs".to_string()));
+    }
+
     // Expiration: default 30 days from now
     let expiration_secs = params.expiration_secs.unwrap_or(30 * 24 * 60 * 60);
     let now = std::time::SystemTime::now()
@@ -157,7 +457,7 @@ fn build_order_from_params(
 
     Ok(UnsignedOrder {
         salt: salt.to_string(),
-        maker: owner.to_string(),
+        maker: maker.to_string(),
         signer: signer_address.to_string(),
         // Open order: any taker can fill
         taker: "0x0000000000000000000000000000000000000000".to_string(),
@@ -169,7 +469,54 @@ fn build_order_from_params(
         // AIDEV-NOTE: Fee rate defaults to 0, Polymarket may add their own
         fee_rate_bps: "0".to_string(),
         side: params.side,
-        // AIDEV-NOTE: Using Proxy signature type for Polymarket proxy wallets
-        signature_type: SignatureType::Proxy,
+        // AIDEV-NOTE: From order_account - Proxy unless an account type was stored
+        signature_type,
     })
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const OWNER: &str = "0x1111111111111111111111111111111111111111";
+    const SIGNER: &str = "0x2222222222222222222222222222222222222222";
+    const FUNDER: &str = "0x3333333333333333333333333333333333333333";
+
+    fn params() -> OrderParams {
+        OrderParams {
+            token_id: "12345".to_string(),
+            side: OrderSide::Buy,
+            price: 0.65,
+            size: 10.0,
+            order_type: OrderType::Gtc,
+            expiration_secs: None,
+            max_slippage_pct: None,
+        }
+    }
+
+    #[test]
+    fn test_default_order_is_proxy_signed_by_owner() {
+        // No account type stored: same order as before account types existed
+        let (maker, signature_type) = order_account(None, OWNER, SIGNER, Some(FUNDER));
+        let order = build_order_from_params(&params(), maker, SIGNER, signature_type).unwrap();
+
+        assert_eq!(order.maker, OWNER);
+        assert_eq!(order.signer, SIGNER);
+        assert_eq!(order.signature_type, SignatureType::Proxy);
+        assert_eq!(order.maker_amount, "6500000");
+        assert_eq!(order.taker_amount, "10000000");
+    }
+
+    #[test]
+    fn test_stored_account_type_sets_maker() {
+        assert_eq!(
+            order_account(Some(SignatureType::GnosisSafe), OWNER, SIGNER, Some(FUNDER)),
+            (FUNDER, SignatureType::GnosisSafe)
+        );
+        assert_eq!(
+            order_account(Some(SignatureType::Proxy), OWNER, SIGNER, Some(FUNDER)),
+            (FUNDER, SignatureType::Proxy)
+        );
+        assert_eq!(order_account(Some(SignatureType::Eoa), OWNER, SIGNER, Some(FUNDER)), (SIGNER, SignatureType::Eoa));
+    }
+}
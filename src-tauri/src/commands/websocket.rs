@@ -1,12 +1,21 @@
 // AIDEV-NOTE: Tauri commands for WebSocket connection management
 
-use tauri::State;
+use std::time::Duration;
+
+use tauri::{AppHandle, Manager, State};
 use serde::Serialize;
+use tracing::{info, warn};
 
-use polymarket_rs::{ConnectionState, ClobWebSocket, RtdsClient};
-use crate::WebSocketState;
+use polymarket_rs::{AggregatedOrderBook, ConnectionState, ClobWebSocket, RtdsClient};
+use crate::db::Database;
+use crate::error::AppError;
+use crate::{AuthState, WebSocketState};
 use crate::events::TauriEventEmitter;
 
+/// `ws_subscriptions.connection_type` values
+const CLOB: &str = "clob";
+const RTDS: &str = "rtds";
+
 /// Response for connection status
 #[derive(Debug, Serialize)]
 pub struct ConnectionStatusResponse {
@@ -18,6 +27,7 @@ pub struct ConnectionStatusResponse {
 #[tauri::command]
 pub async fn connect_rtds(
     ws_state: State<'_, WebSocketState>,
+    auth_state: State<'_, AuthState>,
     markets: Vec<String>,
 ) -> Result<(), String> {
     // Take out any existing client and disconnect it (outside await)
@@ -32,7 +42,8 @@ pub async fn connect_rtds(
 
     // Create and start new connection
     let mut client = RtdsClient::<TauriEventEmitter>::new(ws_state.manager.clone());
-    client.connect(markets).await;
+    client.connect(markets.clone()).await.map_err(|e| e.to_string())?;
+    persist_subscriptions(&auth_state.database, RTDS, &markets);
 
     // Store the new client
     {
@@ -45,12 +56,16 @@ pub async fn connect_rtds(
 
 /// Disconnect from RTDS WebSocket
 #[tauri::command]
-pub fn disconnect_rtds(ws_state: State<'_, WebSocketState>) -> Result<(), String> {
+pub fn disconnect_rtds(
+    ws_state: State<'_, WebSocketState>,
+    auth_state: State<'_, AuthState>,
+) -> Result<(), String> {
     let mut rtds_guard = ws_state.rtds.write();
 
     if let Some(mut client) = rtds_guard.take() {
         client.disconnect();
     }
+    persist_subscriptions(&auth_state.database, RTDS, &[]);
 
     Ok(())
 }
@@ -59,6 +74,7 @@ pub fn disconnect_rtds(ws_state: State<'_, WebSocketState>) -> Result<(), String
 #[tauri::command]
 pub async fn connect_clob(
     ws_state: State<'_, WebSocketState>,
+    auth_state: State<'_, AuthState>,
     token_ids: Vec<String>,
 ) -> Result<(), String> {
     // Take out any existing client and disconnect it (outside await)
@@ -73,7 +89,8 @@ pub async fn connect_clob(
 
     // Create and start new connection
     let mut client = ClobWebSocket::<TauriEventEmitter>::new(ws_state.manager.clone());
-    client.connect(token_ids).await;
+    client.connect(token_ids).await.map_err(|e| e.to_string())?;
+    persist_subscriptions(&auth_state.database, CLOB, &client.subscribed_tokens());
 
     // Store the new client
     {
@@ -84,18 +101,117 @@ pub async fn connect_clob(
     Ok(())
 }
 
+/// Add order book subscriptions to the active CLOB connection
+/// AIDEV-NOTE: Added tokens are kept on the client and restored after reconnects
+#[tauri::command]
+pub fn subscribe_clob_tokens(
+    ws_state: State<'_, WebSocketState>,
+    auth_state: State<'_, AuthState>,
+    token_ids: Vec<String>,
+) -> Result<Vec<String>, String> {
+    let guard = ws_state.clob.read();
+    let client = guard.as_ref().ok_or("CLOB WebSocket is not connected")?;
+    let added = client.subscribe_tokens(token_ids).map_err(|e| e.to_string())?;
+    for token_id in &added {
+        if let Err(e) = auth_state.database.add_ws_subscription(token_id, CLOB) {
+            warn!("Failed to persist CLOB subscription {}: {}", token_id, e);
+        }
+    }
+    Ok(added)
+}
+
 /// Disconnect from CLOB WebSocket
 #[tauri::command]
-pub fn disconnect_clob(ws_state: State<'_, WebSocketState>) -> Result<(), String> {
+pub fn disconnect_clob(
+    ws_state: State<'_, WebSocketState>,
+    auth_state: State<'_, AuthState>,
+) -> Result<(), String> {
     let mut clob_guard = ws_state.clob.write();
 
     if let Some(mut client) = clob_guard.take() {
         client.disconnect();
     }
+    persist_subscriptions(&auth_state.database, CLOB, &[]);
 
     Ok(())
 }
 
+/// Reconnect the feeds that were subscribed when the app last exited (or crashed)
+/// AIDEV-NOTE: Called once from setup. Runs on the async runtime because connect spawns
+/// the socket task; a connection the user opens in the meantime is left alone.
+pub fn restore_subscriptions(app: &AppHandle) {
+    let database = app.state::<AuthState>().database.clone();
+    let stored = |connection_type| {
+        database.get_ws_subscriptions(connection_type).unwrap_or_else(|e| {
+            warn!("Failed to load {} subscriptions: {}", connection_type, e);
+            Vec::new()
+        })
+    };
+    let clob_tokens = stored(CLOB);
+    let rtds_markets = stored(RTDS);
+    if clob_tokens.is_empty() && rtds_markets.is_empty() {
+        return;
+    }
+
+    let app = app.clone();
+    tauri::async_runtime::spawn(async move {
+        let ws_state = app.state::<WebSocketState>();
+
+        if !clob_tokens.is_empty() && ws_state.clob.read().is_none() {
+            let mut client = ClobWebSocket::<TauriEventEmitter>::new(ws_state.manager.clone());
+            match client.connect(clob_tokens).await {
+                Ok(()) => {
+                    info!("Restored CLOB subscriptions for {} tokens", client.subscribed_tokens().len());
+                    let mut guard = ws_state.clob.write();
+                    if guard.is_none() {
+                        *guard = Some(client);
+                    } else {
+                        client.disconnect();
+                    }
+                }
+                Err(e) => warn!("Failed to restore CLOB subscriptions: {}", e),
+            }
+        }
+
+        if !rtds_markets.is_empty() && ws_state.rtds.read().is_none() {
+            let mut client = RtdsClient::<TauriEventEmitter>::new(ws_state.manager.clone());
+            let count = rtds_markets.len();
+            match client.connect(rtds_markets).await {
+                Ok(()) => {
+                    info!("Restored RTDS subscriptions for {} markets", count);
+                    let mut guard = ws_state.rtds.write();
+                    if guard.is_none() {
+                        *guard = Some(client);
+                    } else {
+                        client.disconnect();
+                    }
+                }
+                Err(e) => warn!("Failed to restore RTDS subscriptions: {}", e),
+            }
+        }
+    });
+}
+
+/// Make the stored subscriptions of `connection_type` exactly `ids`
+/// Failures are logged, never returned - the live connection matters more
+fn persist_subscriptions(database: &Database, connection_type: &str, ids: &[String]) {
+    fn sync(database: &Database, connection_type: &str, ids: &[String]) -> Result<(), AppError> {
+        for stored in database.get_ws_subscriptions(connection_type)? {
+            if !ids.contains(&stored) {
+                database.remove_ws_subscription(&stored, connection_type)?;
+            }
+        }
+        for id in ids {
+            database.add_ws_subscription(id, connection_type)?;
+        }
+        Ok(())
+    }
+
+    if let Err(e) = sync(database, connection_type, ids) {
+        warn!("Failed to persist {} subscriptions: {}", connection_type, e);
+    }
+}
+
 /// Get current connection status for both WebSockets
 #[tauri::command]
 pub fn get_connection_status(ws_state: State<'_, WebSocketState>) -> ConnectionStatusResponse {
@@ -104,3 +220,65 @@ pub fn get_connection_status(ws_state: State<'_, WebSocketState>) -> ConnectionS
         clob: ws_state.manager.clob_state(),
     }
 }
+
+/// Start pushing connection_status events every `interval_ms`
+/// AIDEV-NOTE: Async so the task is spawned inside the Tokio runtime
+#[tauri::command]
+pub async fn start_stats_emitter(
+    ws_state: State<'_, WebSocketState>,
+    interval_ms: u64,
+) -> Result<(), String> {
+    if interval_ms == 0 {
+        return Err("interval_ms must be greater than zero".to_string());
+    }
+    ws_state.manager.start_stats_emitter(Duration::from_millis(interval_ms));
+    Ok(())
+}
+
+/// Stop the periodic connection_status push
+#[tauri::command]
+pub fn stop_stats_emitter(ws_state: State<'_, WebSocketState>) {
+    ws_state.manager.stop_stats_emitter();
+}
+
+/// Get aggregated order book depth for a token from the active CLOB connection
+/// AIDEV-NOTE: Returns None until a snapshot for the token has been received
+#[tauri::command]
+pub fn get_aggregated_orderbook(
+    ws_state: State<'_, WebSocketState>,
+    asset_id: String,
+    tick_size: f64,
+    levels: usize,
+) -> Option<AggregatedOrderBook> {
+    ws_state
+        .clob
+        .read()
+        .as_ref()
+        .and_then(|client| client.aggregated_snapshot(&asset_id, tick_size, levels))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    #[test]
+    fn test_persist_subscriptions_syncs_to_exact_set() {
+        let database = Database::open_in_memory();
+        persist_subscriptions(&database, CLOB, &ids(&["111", "222"]));
+        persist_subscriptions(&database, RTDS, &ids(&["m1"]));
+
+        // Drops 111, keeps 222 in place, appends 333
+        persist_subscriptions(&database, CLOB, &ids(&["222", "333"]));
+
+        assert_eq!(database.get_ws_subscriptions(CLOB).unwrap(), ["222", "333"]);
+        assert_eq!(database.get_ws_subscriptions(RTDS).unwrap(), ["m1"]);
+
+        persist_subscriptions(&database, CLOB, &[]);
+        assert!(database.get_ws_subscriptions(CLOB).unwrap().is_empty());
+        assert_eq!(database.get_ws_subscriptions(RTDS).unwrap(), ["m1"]);
+    }
+}
@@ -2,11 +2,14 @@
 // In dev mode, stores in local-db/plgui.db; in prod uses app data directory
 
 use rusqlite::Connection;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::PathBuf;
+use std::str::FromStr;
 use std::sync::Mutex;
-use tracing::{debug, info};
+use tracing::{debug, info, warn};
 
-use polymarket_rs::ApiCredentials;
+use polymarket_rs::{ApiCredentials, Market, OrderFill};
 use crate::error::AppError;
 
 /// Database manager for SQLite persistence
@@ -32,6 +35,11 @@ impl Database {
         let conn = Connection::open(&db_path)
             .map_err(|e| AppError::Internal(format!("Failed to open database: {}", e)))?;
 
+        Self::with_connection(conn)
+    }
+
+    /// Wrap an open connection and bring its schema up to date
+    fn with_connection(conn: Connection) -> Result<Self, AppError> {
         let db = Self {
             conn: Mutex::new(conn),
         };
@@ -42,6 +50,12 @@ impl Database {
         Ok(db)
     }
 
+    /// Fresh in-memory database with the full schema (tests)
+    #[cfg(test)]
+    pub(crate) fn open_in_memory() -> Self {
+        Self::with_connection(Connection::open_in_memory().unwrap()).unwrap()
+    }
+
     /// Get the database path based on environment
     fn get_db_path() -> Result<PathBuf, AppError> {
         // Check if we're in dev mode (local-db directory exists or we're in src-tauri)
@@ -65,7 +79,7 @@ impl Database {
         {
             std::fs::create_dir_all(&local_db)
                 .map_err(|e| AppError::Internal(format!("Failed to create local-db: {}", e)))?;
-            return Ok(local_db.join("plgui.db"));
+            Ok(local_db.join("plgui.db"))
         }
 
         // Production: use app data directory
@@ -82,49 +96,15 @@ impl Database {
         }
     }
 
-    /// Initialize database schema
+    /// Initialize database schema by applying any pending migrations
     fn init_schema(&self) -> Result<(), AppError> {
         let conn = self.conn.lock().unwrap();
+        let runner = MigrationRunner::new();
 
-        conn.execute_batch(
-            r#"
-            -- User credentials table
-            CREATE TABLE IF NOT EXISTS credentials (
-                id INTEGER PRIMARY KEY CHECK (id = 1),
-                api_key TEXT NOT NULL,
-                api_secret TEXT NOT NULL,
-                api_passphrase TEXT NOT NULL,
-                address TEXT NOT NULL,
-                polymarket_address TEXT,
-                created_at TEXT DEFAULT CURRENT_TIMESTAMP,
-                updated_at TEXT DEFAULT CURRENT_TIMESTAMP
-            );
-
-            -- User settings table
-            CREATE TABLE IF NOT EXISTS settings (
-                key TEXT PRIMARY KEY,
-                value TEXT NOT NULL,
-                updated_at TEXT DEFAULT CURRENT_TIMESTAMP
-            );
-
-            -- AIDEV-NOTE: Price history cache - stores historical price data per token
-            -- token_id is the CLOB token ID (long numeric string)
-            -- timestamp is Unix epoch seconds, price is 0.0-1.0
-            CREATE TABLE IF NOT EXISTS price_history (
-                id INTEGER PRIMARY KEY AUTOINCREMENT,
-                token_id TEXT NOT NULL,
-                timestamp INTEGER NOT NULL,
-                price REAL NOT NULL,
-                fetched_at TEXT DEFAULT CURRENT_TIMESTAMP,
-                UNIQUE(token_id, timestamp)
-            );
-
-            -- Index for efficient queries by token
-            CREATE INDEX IF NOT EXISTS idx_price_history_token_time
-                ON price_history(token_id, timestamp DESC);
-            "#,
-        )
-        .map_err(|e| AppError::Internal(format!("Failed to init schema: {}", e)))?;
+        let applied = runner.run(&conn)?;
+        if applied > 0 {
+            info!("Applied {} database migration(s), schema now at v{}", applied, runner.latest_version());
+        }
 
         debug!("Database schema initialized");
         Ok(())
@@ -198,6 +178,30 @@ impl Database {
         Ok(())
     }
 
+    /// Replace the stored API credentials, keeping the Polymarket address
+    pub fn update_credentials(&self, creds: &ApiCredentials) -> Result<(), AppError> {
+        let conn = self.conn.lock().unwrap();
+
+        let updated = conn
+            .execute(
+                r#"
+                UPDATE credentials
+                SET api_key = ?1, api_secret = ?2, api_passphrase = ?3, address = ?4,
+                    updated_at = CURRENT_TIMESTAMP
+                WHERE id = 1
+                "#,
+                (&creds.api_key, &creds.api_secret, &creds.api_passphrase, &creds.address),
+            )
+            .map_err(|e| AppError::Internal(format!("Failed to update credentials: {}", e)))?;
+
+        if updated == 0 {
+            return Err(AppError::Internal("No stored credentials to update".to_string()));
+        }
+
+        info!("Credentials updated in database");
+        Ok(())
+    }
+
     /// Update Polymarket address
     pub fn update_polymarket_address(&self, address: &str) -> Result<(), AppError> {
         let conn = self.conn.lock().unwrap();
@@ -242,24 +246,371 @@ impl Database {
         Ok(())
     }
 
+    /// Typed accessor over the settings table
+    pub fn settings(&self) -> TypedSettings<'_> {
+        TypedSettings { db: self }
+    }
+
+    /// Store multiple settings atomically in a single transaction
+    pub fn store_settings_batch(&self, pairs: &[(&str, &str)]) -> Result<(), AppError> {
+        let mut conn = self.conn.lock().unwrap();
+
+        let tx = conn
+            .transaction()
+            .map_err(|e| AppError::Internal(format!("Failed to begin settings transaction: {}", e)))?;
+
+        {
+            let mut stmt = tx
+                .prepare("INSERT OR REPLACE INTO settings (key, value, updated_at) VALUES (?1, ?2, CURRENT_TIMESTAMP)")
+                .map_err(|e| AppError::Internal(format!("Failed to prepare settings insert: {}", e)))?;
+
+            for (key, value) in pairs {
+                stmt.execute([key, value])
+                    .map_err(|e| AppError::Internal(format!("Failed to set setting '{}': {}", key, e)))?;
+            }
+        }
+
+        // AIDEV-NOTE: Dropping an uncommitted Transaction rolls back, so early returns above are safe
+        tx.commit()
+            .map_err(|e| AppError::Internal(format!("Failed to commit settings: {}", e)))?;
+
+        debug!("Stored {} settings", pairs.len());
+        Ok(())
+    }
+
+    /// Get multiple settings at once (missing keys are omitted from the map)
+    pub fn get_settings_batch(&self, keys: &[&str]) -> Result<HashMap<String, String>, AppError> {
+        let conn = self.conn.lock().unwrap();
+
+        let mut stmt = conn
+            .prepare("SELECT value FROM settings WHERE key = ?1")
+            .map_err(|e| AppError::Internal(format!("Failed to prepare settings query: {}", e)))?;
+
+        let mut result = HashMap::with_capacity(keys.len());
+        for key in keys {
+            match stmt.query_row([key], |row| row.get::<_, String>(0)) {
+                Ok(value) => {
+                    result.insert(key.to_string(), value);
+                }
+                Err(rusqlite::Error::QueryReturnedNoRows) => {}
+                Err(e) => return Err(AppError::Internal(format!("Failed to get setting '{}': {}", key, e))),
+            }
+        }
+
+        Ok(result)
+    }
+
+    /// All settings whose key starts with `prefix` (e.g. "chart." for chart preferences)
+    pub fn get_settings_with_prefix(&self, prefix: &str) -> Result<HashMap<String, String>, AppError> {
+        let conn = self.conn.lock().unwrap();
+
+        let mut stmt = conn
+            .prepare("SELECT key, value FROM settings WHERE key LIKE ?1 ESCAPE '\\'")
+            .map_err(|e| AppError::Internal(format!("Failed to prepare settings query: {}", e)))?;
+
+        let rows = stmt
+            .query_map([like_prefix(prefix)], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))
+            .map_err(|e| AppError::Internal(format!("Failed to query settings: {}", e)))?;
+
+        rows.collect::<Result<HashMap<_, _>, _>>()
+            .map_err(|e| AppError::Internal(format!("Failed to read setting: {}", e)))
+    }
+
+    /// Delete every setting whose key starts with `prefix`; returns how many were removed
+    pub fn delete_settings_with_prefix(&self, prefix: &str) -> Result<usize, AppError> {
+        let conn = self.conn.lock().unwrap();
+
+        let deleted = conn
+            .execute("DELETE FROM settings WHERE key LIKE ?1 ESCAPE '\\'", [like_prefix(prefix)])
+            .map_err(|e| AppError::Internal(format!("Failed to delete settings: {}", e)))?;
+
+        debug!("Deleted {} settings with prefix '{}'", deleted, prefix);
+        Ok(deleted)
+    }
+
+    // ========== Trade Methods ==========
+
+    /// Store a fill (no-op if the trade_id is already stored)
+    /// Returns true if the fill was new
+    pub fn store_trade(&self, fill: &OrderFill) -> Result<bool, AppError> {
+        let conn = self.conn.lock().unwrap();
+
+        let inserted = conn
+            .execute(
+                "INSERT OR IGNORE INTO trades (trade_id, order_id, size, price, timestamp, fee) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                (&fill.trade_id, &fill.order_id, fill.size, fill.price, &fill.timestamp, fill.fee),
+            )
+            .map_err(|e| AppError::Internal(format!("Failed to store trade: {}", e)))?;
+
+        Ok(inserted > 0)
+    }
+
+    // ========== Price Alert Methods ==========
+
+    /// Create a price alert and return it
+    pub fn add_price_alert(
+        &self,
+        token_id: &str,
+        threshold: f64,
+        direction: AlertDirection,
+    ) -> Result<PriceAlert, AppError> {
+        let conn = self.conn.lock().unwrap();
+
+        conn.query_row(
+            r#"
+            INSERT INTO price_alerts (token_id, threshold, direction)
+            VALUES (?1, ?2, ?3)
+            RETURNING id, token_id, threshold, direction, triggered, created_at
+            "#,
+            (token_id, threshold, direction.as_str()),
+            PriceAlert::from_row,
+        )
+        .map_err(|e| AppError::Internal(format!("Failed to add price alert: {}", e)))
+    }
+
+    /// Untriggered alerts, optionally limited to one token, oldest first
+    pub fn get_active_alerts(&self, token_id: Option<&str>) -> Result<Vec<PriceAlert>, AppError> {
+        let conn = self.conn.lock().unwrap();
+
+        let mut stmt = conn
+            .prepare(
+                r#"
+                SELECT id, token_id, threshold, direction, triggered, created_at
+                FROM price_alerts
+                WHERE triggered = 0 AND (?1 IS NULL OR token_id = ?1)
+                ORDER BY id ASC
+                "#,
+            )
+            .map_err(|e| AppError::Internal(format!("Failed to prepare alerts query: {}", e)))?;
+
+        let rows = stmt
+            .query_map([token_id], PriceAlert::from_row)
+            .map_err(|e| AppError::Internal(format!("Failed to query price alerts: {}", e)))?;
+
+        rows.collect::<Result<Vec<_>, _>>()
+            .map_err(|e| AppError::Internal(format!("Failed to read price alert: {}", e)))
+    }
+
+    /// Mark an alert as triggered
+    /// Returns true only for the call that flipped it, so concurrent updates fire it once
+    pub fn mark_alert_triggered(&self, id: i64) -> Result<bool, AppError> {
+        let conn = self.conn.lock().unwrap();
+
+        let updated = conn
+            .execute(
+                "UPDATE price_alerts SET triggered = 1 WHERE id = ?1 AND triggered = 0",
+                [id],
+            )
+            .map_err(|e| AppError::Internal(format!("Failed to mark alert triggered: {}", e)))?;
+
+        Ok(updated > 0)
+    }
+
+    /// Delete an alert (triggered or not)
+    /// Returns true if it existed
+    pub fn delete_price_alert(&self, id: i64) -> Result<bool, AppError> {
+        let conn = self.conn.lock().unwrap();
+
+        let deleted = conn
+            .execute("DELETE FROM price_alerts WHERE id = ?1", [id])
+            .map_err(|e| AppError::Internal(format!("Failed to delete price alert: {}", e)))?;
+
+        Ok(deleted > 0)
+    }
+
+    // ========== WebSocket Subscription Methods ==========
+
+    /// Remember a subscription (`connection_type` is "clob" or "rtds")
+    /// Returns true if it wasn't stored already
+    pub fn add_ws_subscription(&self, token_id: &str, connection_type: &str) -> Result<bool, AppError> {
+        let conn = self.conn.lock().unwrap();
+
+        let inserted = conn
+            .execute(
+                "INSERT OR IGNORE INTO ws_subscriptions (token_id, connection_type) VALUES (?1, ?2)",
+                (token_id, connection_type),
+            )
+            .map_err(|e| AppError::Internal(format!("Failed to store ws subscription: {}", e)))?;
+
+        Ok(inserted > 0)
+    }
+
+    /// Forget a subscription. Returns true if it was stored.
+    pub fn remove_ws_subscription(&self, token_id: &str, connection_type: &str) -> Result<bool, AppError> {
+        let conn = self.conn.lock().unwrap();
+
+        let deleted = conn
+            .execute(
+                "DELETE FROM ws_subscriptions WHERE token_id = ?1 AND connection_type = ?2",
+                (token_id, connection_type),
+            )
+            .map_err(|e| AppError::Internal(format!("Failed to remove ws subscription: {}", e)))?;
+
+        Ok(deleted > 0)
+    }
+
+    /// Stored subscriptions of `connection_type`, oldest first
+    pub fn get_ws_subscriptions(&self, connection_type: &str) -> Result<Vec<String>, AppError> {
+        let conn = self.conn.lock().unwrap();
+
+        let mut stmt = conn
+            .prepare(
+                "SELECT token_id FROM ws_subscriptions WHERE connection_type = ?1 ORDER BY subscribed_at ASC, rowid ASC",
+            )
+            .map_err(|e| AppError::Internal(format!("Failed to prepare ws subscriptions query: {}", e)))?;
+
+        let rows = stmt
+            .query_map([connection_type], |row| row.get(0))
+            .map_err(|e| AppError::Internal(format!("Failed to query ws subscriptions: {}", e)))?;
+
+        rows.collect::<Result<Vec<String>, _>>()
+            .map_err(|e| AppError::Internal(format!("Failed to read ws subscription: {}", e)))
+    }
+
+    // ========== Order Log Methods ==========
+
+    /// Append one row per order to the order log: `action` (e.g. "cancel_all") and what
+    /// came of it for that order ("canceled" or the CLOB's refusal reason)
+    /// Returns the number of rows written
+    pub fn log_order_outcomes(&self, action: &str, outcomes: &[(&str, &str)]) -> Result<usize, AppError> {
+        let mut conn = self.conn.lock().unwrap();
+
+        let tx = conn
+            .transaction()
+            .map_err(|e| AppError::Internal(format!("Failed to begin order log transaction: {}", e)))?;
+
+        {
+            let mut stmt = tx
+                .prepare("INSERT INTO order_log (action, order_id, outcome) VALUES (?1, ?2, ?3)")
+                .map_err(|e| AppError::Internal(format!("Failed to prepare order log insert: {}", e)))?;
+
+            for (order_id, outcome) in outcomes {
+                stmt.execute((action, order_id, outcome))
+                    .map_err(|e| AppError::Internal(format!("Failed to log order {}: {}", order_id, e)))?;
+            }
+        }
+
+        tx.commit()
+            .map_err(|e| AppError::Internal(format!("Failed to commit order log: {}", e)))?;
+
+        debug!("Logged {} order outcomes for {}", outcomes.len(), action);
+        Ok(outcomes.len())
+    }
+
+    // ========== Market Cache Methods ==========
+
+    /// Upsert markets into the offline cache, stamped with `cached_at` (Unix seconds)
+    /// Returns the number of markets stored
+    pub fn store_cached_markets(&self, markets: &[Market], cached_at: i64) -> Result<usize, AppError> {
+        let mut conn = self.conn.lock().unwrap();
+
+        let tx = conn
+            .transaction()
+            .map_err(|e| AppError::Internal(format!("Failed to begin market cache transaction: {}", e)))?;
+
+        {
+            let mut stmt = tx
+                .prepare(
+                    r#"
+                    INSERT INTO markets_cache (condition_id, question, slug, data, rank, cached_at)
+                    VALUES (?1, ?2, ?3, ?4, ?5, ?6)
+                    ON CONFLICT(condition_id) DO UPDATE SET
+                        question = excluded.question,
+                        slug = excluded.slug,
+                        data = excluded.data,
+                        rank = excluded.rank,
+                        cached_at = excluded.cached_at
+                    "#,
+                )
+                .map_err(|e| AppError::Internal(format!("Failed to prepare market cache upsert: {}", e)))?;
+
+            for (rank, market) in markets.iter().enumerate() {
+                let data = serde_json::to_string(market)?;
+                stmt.execute((
+                    &market.condition_id,
+                    &market.question,
+                    &market.market_slug,
+                    data,
+                    rank as i64,
+                    cached_at,
+                ))
+                .map_err(|e| AppError::Internal(format!("Failed to cache market {}: {}", market.condition_id, e)))?;
+            }
+        }
+
+        tx.commit()
+            .map_err(|e| AppError::Internal(format!("Failed to commit market cache: {}", e)))?;
+
+        debug!("Cached {} markets", markets.len());
+        Ok(markets.len())
+    }
+
+    /// Cached markets, newest fetch first and in fetch order within it
+    /// Rows whose JSON no longer parses (schema drift) are skipped
+    pub fn get_cached_markets(&self, limit: Option<u32>) -> Result<Vec<Market>, AppError> {
+        let conn = self.conn.lock().unwrap();
+
+        let mut stmt = conn
+            .prepare("SELECT condition_id, data FROM markets_cache ORDER BY cached_at DESC, rank ASC LIMIT ?1")
+            .map_err(|e| AppError::Internal(format!("Failed to prepare market cache query: {}", e)))?;
+
+        // LIMIT -1 means no limit in SQLite
+        let limit = limit.map(i64::from).unwrap_or(-1);
+        let rows = stmt
+            .query_map([limit], |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)))
+            .map_err(|e| AppError::Internal(format!("Failed to query market cache: {}", e)))?;
+
+        let mut markets = Vec::new();
+        for row in rows {
+            let (condition_id, data) =
+                row.map_err(|e| AppError::Internal(format!("Failed to read cached market: {}", e)))?;
+            match serde_json::from_str(&data) {
+                Ok(market) => markets.push(market),
+                Err(e) => warn!("Skipping unreadable cached market {}: {}", condition_id, e),
+            }
+        }
+
+        Ok(markets)
+    }
+
+    /// When the cache was last written (Unix seconds), None if empty
+    pub fn markets_cached_at(&self) -> Result<Option<i64>, AppError> {
+        let conn = self.conn.lock().unwrap();
+
+        conn.query_row("SELECT MAX(cached_at) FROM markets_cache", [], |row| row.get(0))
+            .map_err(|e| AppError::Internal(format!("Failed to read market cache age: {}", e)))
+    }
+
     // ========== Price History Methods ==========
 
     /// Store price history points for a token (upserts to avoid duplicates)
+    /// AIDEV-NOTE: One transaction + one prepared statement for the whole batch -
+    /// autocommit per row made first fetches (thousands of points) very slow
     pub fn store_price_history(&self, token_id: &str, points: &[(i64, f64)]) -> Result<usize, AppError> {
-        let conn = self.conn.lock().unwrap();
+        let mut conn = self.conn.lock().unwrap();
+
+        let tx = conn
+            .transaction()
+            .map_err(|e| AppError::Internal(format!("Failed to begin price history transaction: {}", e)))?;
 
         let mut inserted = 0;
-        for (timestamp, price) in points {
-            let result = conn.execute(
-                "INSERT OR IGNORE INTO price_history (token_id, timestamp, price) VALUES (?1, ?2, ?3)",
-                (token_id, timestamp, price),
-            );
+        {
+            let mut stmt = tx
+                .prepare("INSERT OR IGNORE INTO price_history (token_id, timestamp, price) VALUES (?1, ?2, ?3)")
+                .map_err(|e| AppError::Internal(format!("Failed to prepare price history insert: {}", e)))?;
 
-            if let Ok(count) = result {
-                inserted += count;
+            for (timestamp, price) in points {
+                // UNIQUE(token_id, timestamp) makes duplicates a no-op (count 0)
+                match stmt.execute((token_id, timestamp, price)) {
+                    Ok(count) => inserted += count,
+                    Err(e) => debug!("Skipping price point {} for {}: {}", timestamp, token_id, e),
+                }
             }
         }
 
+        tx.commit()
+            .map_err(|e| AppError::Internal(format!("Failed to commit price history: {}", e)))?;
+
         debug!("Stored {} new price history points for {}", inserted, token_id);
         Ok(inserted)
     }
@@ -302,12 +653,7 @@ impl Database {
             })
             .map_err(|e| AppError::Internal(format!("Failed to query price history: {}", e)))?;
 
-        let mut result = Vec::new();
-        for row in rows {
-            if let Ok(point) = row {
-                result.push(point);
-            }
-        }
+        let result: Vec<(i64, f64)> = rows.flatten().collect();
 
         debug!("Retrieved {} price history points for {}", result.len(), token_id);
         Ok(result)
@@ -348,3 +694,554 @@ impl Database {
         Ok(deleted)
     }
 }
+
+/// Which way the price must move through the threshold for an alert to fire
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum AlertDirection {
+    Above,
+    Below,
+}
+
+impl AlertDirection {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AlertDirection::Above => "above",
+            AlertDirection::Below => "below",
+        }
+    }
+
+    /// Whether `price` has reached `threshold` in this direction (inclusive)
+    pub fn is_crossed(&self, price: f64, threshold: f64) -> bool {
+        match self {
+            AlertDirection::Above => price >= threshold,
+            AlertDirection::Below => price <= threshold,
+        }
+    }
+}
+
+impl FromStr for AlertDirection {
+    type Err = AppError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "above" => Ok(AlertDirection::Above),
+            "below" => Ok(AlertDirection::Below),
+            other => Err(AppError::Internal(format!("Unknown alert direction: {}", other))),
+        }
+    }
+}
+
+/// A row of the price_alerts table
+#[derive(Debug, Clone, Serialize)]
+pub struct PriceAlert {
+    pub id: i64,
+    pub token_id: String,
+    pub threshold: f64,
+    pub direction: AlertDirection,
+    pub triggered: bool,
+    pub created_at: String,
+}
+
+impl PriceAlert {
+    /// Map `id, token_id, threshold, direction, triggered, created_at`
+    fn from_row(row: &rusqlite::Row<'_>) -> rusqlite::Result<Self> {
+        let direction: String = row.get(3)?;
+        Ok(Self {
+            id: row.get(0)?,
+            token_id: row.get(1)?,
+            threshold: row.get(2)?,
+            direction: direction.parse().map_err(|e: AppError| {
+                rusqlite::Error::FromSqlConversionFailure(3, rusqlite::types::Type::Text, e.into())
+            })?,
+            triggered: row.get(4)?,
+            created_at: row.get(5)?,
+        })
+    }
+}
+
+// ========== Migrations ==========
+
+/// A schema change applied exactly once, in version order
+/// AIDEV-NOTE: The applied version lives in `PRAGMA user_version`. Never edit a shipped
+/// migration - append a new one with the next version instead.
+struct Migration {
+    version: u32,
+    sql: &'static str,
+}
+
+/// Baseline schema. Uses IF NOT EXISTS so databases created before versioning
+/// (user_version 0, tables already present) upgrade cleanly.
+const SCHEMA_V1: &str = r#"
+    -- User credentials table
+    CREATE TABLE IF NOT EXISTS credentials (
+        id INTEGER PRIMARY KEY CHECK (id = 1),
+        api_key TEXT NOT NULL,
+        api_secret TEXT NOT NULL,
+        api_passphrase TEXT NOT NULL,
+        address TEXT NOT NULL,
+        polymarket_address TEXT,
+        created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+        updated_at TEXT DEFAULT CURRENT_TIMESTAMP
+    );
+
+    -- User settings table
+    CREATE TABLE IF NOT EXISTS settings (
+        key TEXT PRIMARY KEY,
+        value TEXT NOT NULL,
+        updated_at TEXT DEFAULT CURRENT_TIMESTAMP
+    );
+
+    -- AIDEV-NOTE: Price history cache - stores historical price data per token
+    -- token_id is the CLOB token ID (long numeric string)
+    -- timestamp is Unix epoch seconds, price is 0.0-1.0
+    CREATE TABLE IF NOT EXISTS price_history (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        token_id TEXT NOT NULL,
+        timestamp INTEGER NOT NULL,
+        price REAL NOT NULL,
+        fetched_at TEXT DEFAULT CURRENT_TIMESTAMP,
+        UNIQUE(token_id, timestamp)
+    );
+
+    -- Index for efficient queries by token
+    CREATE INDEX IF NOT EXISTS idx_price_history_token_time
+        ON price_history(token_id, timestamp DESC);
+
+    -- AIDEV-NOTE: Fills of the user's orders, deduplicated by trade_id
+    CREATE TABLE IF NOT EXISTS trades (
+        trade_id TEXT PRIMARY KEY,
+        order_id TEXT NOT NULL,
+        size REAL NOT NULL,
+        price REAL NOT NULL,
+        timestamp TEXT NOT NULL,
+        fee REAL NOT NULL DEFAULT 0,
+        stored_at TEXT DEFAULT CURRENT_TIMESTAMP
+    );
+
+    CREATE INDEX IF NOT EXISTS idx_trades_order ON trades(order_id);
+
+    -- AIDEV-NOTE: User price alerts - checked against every price_update for the token
+    -- direction is 'above' or 'below'; triggered flips to 1 once and the alert stops firing
+    CREATE TABLE IF NOT EXISTS price_alerts (
+        id INTEGER PRIMARY KEY,
+        token_id TEXT NOT NULL,
+        threshold REAL NOT NULL,
+        direction TEXT NOT NULL CHECK (direction IN ('above', 'below')),
+        triggered INTEGER NOT NULL DEFAULT 0,
+        created_at TEXT DEFAULT CURRENT_TIMESTAMP
+    );
+
+    CREATE INDEX IF NOT EXISTS idx_price_alerts_active
+        ON price_alerts(token_id) WHERE triggered = 0;
+
+    -- AIDEV-NOTE: Offline market cache - data is the full Market as JSON
+    -- rank is the position in the fetch that stored it; cached_at is Unix epoch seconds
+    CREATE TABLE IF NOT EXISTS markets_cache (
+        condition_id TEXT PRIMARY KEY,
+        question TEXT NOT NULL,
+        slug TEXT NOT NULL,
+        data TEXT NOT NULL,
+        rank INTEGER NOT NULL,
+        cached_at INTEGER NOT NULL
+    );
+
+    CREATE INDEX IF NOT EXISTS idx_markets_cache_order
+        ON markets_cache(cached_at DESC, rank ASC);
+"#;
+
+/// Live feed subscriptions, restored on startup
+const SCHEMA_V2: &str = r#"
+    -- AIDEV-NOTE: Tokens (clob) / markets (rtds) the user had subscribed when the app
+    -- last ran - written by the connect/subscribe commands, read back in setup
+    CREATE TABLE IF NOT EXISTS ws_subscriptions (
+        token_id TEXT NOT NULL,
+        connection_type TEXT NOT NULL CHECK (connection_type IN ('clob', 'rtds')),
+        subscribed_at TEXT DEFAULT CURRENT_TIMESTAMP,
+        PRIMARY KEY (token_id, connection_type)
+    );
+"#;
+
+/// Audit trail of order actions taken from the app
+const SCHEMA_V3: &str = r#"
+    CREATE TABLE IF NOT EXISTS order_log (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        action TEXT NOT NULL,
+        order_id TEXT NOT NULL,
+        outcome TEXT NOT NULL,
+        logged_at TEXT DEFAULT CURRENT_TIMESTAMP
+    );
+
+    CREATE INDEX IF NOT EXISTS idx_order_log_order ON order_log(order_id);
+"#;
+
+const MIGRATIONS: &[Migration] = &[
+    Migration { version: 1, sql: SCHEMA_V1 },
+    Migration { version: 2, sql: SCHEMA_V2 },
+    Migration { version: 3, sql: SCHEMA_V3 },
+];
+
+/// Applies `MIGRATIONS` newer than the database's `user_version`
+pub struct MigrationRunner {
+    migrations: &'static [Migration],
+}
+
+impl MigrationRunner {
+    pub fn new() -> Self {
+        Self { migrations: MIGRATIONS }
+    }
+
+    /// Schema version once every migration has run
+    pub fn latest_version(&self) -> u32 {
+        self.migrations.last().map_or(0, |m| m.version)
+    }
+
+    /// SQL of the migrations `run` would apply, in order, without executing anything
+    /// Empty when the database is up to date.
+    /// AIDEV-NOTE: Only the tests call this - it checks for pending migrations without
+    /// touching the database
+    #[allow(dead_code)]
+    pub fn dry_run(&self, conn: &Connection) -> Result<Vec<String>, AppError> {
+        Ok(self.pending(conn)?.iter().map(|m| m.sql.to_string()).collect())
+    }
+
+    /// Apply pending migrations, each in its own transaction. Returns how many ran.
+    pub fn run(&self, conn: &Connection) -> Result<usize, AppError> {
+        let pending = self.pending(conn)?;
+        for migration in pending {
+            // user_version can't be bound as a parameter; version is a trusted u32
+            let sql = format!(
+                "BEGIN;\n{}\nPRAGMA user_version = {};\nCOMMIT;",
+                migration.sql, migration.version
+            );
+            if let Err(e) = conn.execute_batch(&sql) {
+                let _ = conn.execute_batch("ROLLBACK;");
+                return Err(AppError::Internal(format!(
+                    "Failed to apply migration v{}: {}",
+                    migration.version, e
+                )));
+            }
+        }
+        Ok(pending.len())
+    }
+
+    fn pending(&self, conn: &Connection) -> Result<&'static [Migration], AppError> {
+        let current: u32 = conn
+            .query_row("PRAGMA user_version", [], |row| row.get(0))
+            .map_err(|e| AppError::Internal(format!("Failed to read schema version: {}", e)))?;
+        let applied = self.migrations.partition_point(|m| m.version <= current);
+        Ok(&self.migrations[applied..])
+    }
+}
+
+/// LIKE pattern matching keys that start with `prefix` (for use with a backslash ESCAPE)
+/// AIDEV-NOTE: '_' is common in setting keys and a LIKE wildcard, so it must be escaped.
+/// SQLite LIKE is ASCII case-insensitive - keep setting keys lowercase.
+fn like_prefix(prefix: &str) -> String {
+    let escaped = prefix.replace('\\', "\\\\").replace('%', "\\%").replace('_', "\\_");
+    format!("{}%", escaped)
+}
+
+/// Type-safe view over the settings table
+/// AIDEV-NOTE: Reads never fail - missing, unparseable or unreadable values fall back to the default
+pub struct TypedSettings<'a> {
+    db: &'a Database,
+}
+
+impl TypedSettings<'_> {
+    /// Get an unsigned integer setting
+    pub fn get_u64(&self, key: &str, default: u64) -> u64 {
+        self.get_parsed(key, default)
+    }
+
+    /// Get a raw string setting
+    pub fn get_string(&self, key: &str) -> Option<String> {
+        match self.db.get_setting(key) {
+            Ok(value) => value,
+            Err(e) => {
+                warn!("Failed to read setting '{}': {}", key, e);
+                None
+            }
+        }
+    }
+
+    /// Store an unsigned integer setting
+    pub fn set_u64(&self, key: &str, value: u64) -> Result<(), AppError> {
+        self.db.set_setting(key, &value.to_string())
+    }
+
+    fn get_parsed<T: FromStr>(&self, key: &str, default: T) -> T {
+        let Some(raw) = self.get_string(key) else {
+            return default;
+        };
+
+        raw.trim().parse().unwrap_or_else(|_| {
+            warn!("Setting '{}' has unparseable value '{}', using default", key, raw);
+            default
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn credentials(api_key: &str) -> ApiCredentials {
+        ApiCredentials {
+            api_key: api_key.to_string(),
+            api_secret: "c2VjcmV0".to_string(),
+            api_passphrase: "pass".to_string(),
+            address: "0xsigner".to_string(),
+        }
+    }
+
+    // ========== Credentials ==========
+
+    #[test]
+    fn test_update_credentials_keeps_polymarket_address() {
+        let db = Database::open_in_memory();
+        db.store_credentials(&credentials("old-key"), Some("0xfunder")).unwrap();
+
+        db.update_credentials(&credentials("new-key")).unwrap();
+
+        let (stored, polymarket_address) = db.load_credentials().unwrap().unwrap();
+        assert_eq!(stored.api_key, "new-key");
+        assert_eq!(stored.api_passphrase, "pass");
+        assert_eq!(polymarket_address.as_deref(), Some("0xfunder"));
+    }
+
+    #[test]
+    fn test_update_credentials_requires_stored_row() {
+        let db = Database::open_in_memory();
+
+        assert!(db.update_credentials(&credentials("new-key")).is_err());
+        assert!(db.load_credentials().unwrap().is_none());
+    }
+
+    // ========== Settings ==========
+
+    #[test]
+    fn test_typed_settings_round_trip() {
+        let db = Database::open_in_memory();
+        let settings = db.settings();
+
+        settings.set_u64("poll_secs", 42).unwrap();
+
+        assert_eq!(settings.get_u64("poll_secs", 7), 42);
+        assert_eq!(settings.get_string("poll_secs").as_deref(), Some("42"));
+        assert_eq!(db.get_setting("poll_secs").unwrap().as_deref(), Some("42"));
+    }
+
+    #[test]
+    fn test_typed_settings_parse_fallbacks() {
+        let db = Database::open_in_memory();
+        let settings = db.settings();
+
+        // Missing
+        assert_eq!(settings.get_u64("missing", 7), 7);
+        assert_eq!(settings.get_string("missing"), None);
+
+        // Surrounding whitespace is ignored
+        db.set_setting("padded", " 12 ").unwrap();
+        assert_eq!(settings.get_u64("padded", 7), 12);
+
+        // Unparseable or out of range
+        for bad in ["abc", "-1", "1.5", "", "18446744073709551616"] {
+            db.set_setting("bad", bad).unwrap();
+            assert_eq!(settings.get_u64("bad", 7), 7, "value {:?}", bad);
+        }
+    }
+
+    #[test]
+    fn test_settings_prefix_escapes_like_wildcards() {
+        let db = Database::open_in_memory();
+        db.store_settings_batch(&[
+            ("chart_a.interval", "1d"),
+            ("chartXa.interval", "1w"),
+            ("chart%a.interval", "max"),
+            ("other", "x"),
+        ])
+        .unwrap();
+
+        // '_' and '%' match only themselves, not any character
+        let stored = db.get_settings_with_prefix("chart_a.").unwrap();
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored["chart_a.interval"], "1d");
+        assert_eq!(db.get_settings_with_prefix("chart%").unwrap().len(), 1);
+
+        assert_eq!(db.delete_settings_with_prefix("chart_").unwrap(), 1);
+        assert_eq!(db.get_setting("chartXa.interval").unwrap().as_deref(), Some("1w"));
+        assert!(db.get_setting("chart_a.interval").unwrap().is_none());
+    }
+
+    // ========== Price Alerts ==========
+
+    #[test]
+    fn test_alert_direction_is_crossed_inclusive() {
+        assert!(AlertDirection::Above.is_crossed(0.61, 0.6));
+        assert!(AlertDirection::Above.is_crossed(0.6, 0.6));
+        assert!(!AlertDirection::Above.is_crossed(0.59, 0.6));
+
+        assert!(AlertDirection::Below.is_crossed(0.39, 0.4));
+        assert!(AlertDirection::Below.is_crossed(0.4, 0.4));
+        assert!(!AlertDirection::Below.is_crossed(0.41, 0.4));
+    }
+
+    #[test]
+    fn test_price_alert_lifecycle() {
+        let db = Database::open_in_memory();
+        let first = db.add_price_alert("t1", 0.6, AlertDirection::Above).unwrap();
+        let second = db.add_price_alert("t2", 0.4, AlertDirection::Below).unwrap();
+        let third = db.add_price_alert("t1", 0.3, AlertDirection::Below).unwrap();
+        assert_eq!(first.token_id, "t1");
+        assert_eq!(first.direction, AlertDirection::Above);
+        assert!(!first.triggered);
+
+        let ids = |alerts: Vec<PriceAlert>| alerts.into_iter().map(|a| a.id).collect::<Vec<_>>();
+        assert_eq!(ids(db.get_active_alerts(None).unwrap()), [first.id, second.id, third.id]);
+        assert_eq!(ids(db.get_active_alerts(Some("t1")).unwrap()), [first.id, third.id]);
+
+        // Triggering flips once and drops the alert from the active list
+        assert!(db.mark_alert_triggered(first.id).unwrap());
+        assert!(!db.mark_alert_triggered(first.id).unwrap());
+        assert_eq!(ids(db.get_active_alerts(Some("t1")).unwrap()), [third.id]);
+
+        assert!(db.delete_price_alert(second.id).unwrap());
+        assert!(!db.delete_price_alert(second.id).unwrap());
+        assert_eq!(ids(db.get_active_alerts(None).unwrap()), [third.id]);
+    }
+
+    // ========== WebSocket Subscriptions ==========
+
+    #[test]
+    fn test_ws_subscriptions_insert_or_ignore_and_order() {
+        let db = Database::open_in_memory();
+
+        assert!(db.add_ws_subscription("333", "clob").unwrap());
+        assert!(db.add_ws_subscription("111", "clob").unwrap());
+        assert!(!db.add_ws_subscription("333", "clob").unwrap(), "duplicate is ignored");
+        assert!(db.add_ws_subscription("333", "rtds").unwrap(), "other connection type is separate");
+
+        // Oldest subscription first, not sorted by ID
+        assert_eq!(db.get_ws_subscriptions("clob").unwrap(), ["333", "111"]);
+        assert_eq!(db.get_ws_subscriptions("rtds").unwrap(), ["333"]);
+        // OR IGNORE also swallows the connection_type CHECK
+        assert!(!db.add_ws_subscription("444", "other").unwrap());
+        assert!(db.get_ws_subscriptions("other").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_remove_ws_subscription() {
+        let db = Database::open_in_memory();
+        db.add_ws_subscription("111", "clob").unwrap();
+        db.add_ws_subscription("111", "rtds").unwrap();
+
+        assert!(db.remove_ws_subscription("111", "clob").unwrap());
+        assert!(!db.remove_ws_subscription("111", "clob").unwrap());
+
+        assert!(db.get_ws_subscriptions("clob").unwrap().is_empty());
+        assert_eq!(db.get_ws_subscriptions("rtds").unwrap(), ["111"]);
+    }
+
+    // ========== Market Cache ==========
+
+    fn market(condition_id: &str, question: &str) -> Market {
+        let raw: polymarket_rs::RawMarket = serde_json::from_value(serde_json::json!({
+            "id": condition_id,
+            "conditionId": condition_id,
+            "question": question,
+            "slug": question.to_lowercase().replace(' ', "-"),
+        }))
+        .unwrap();
+        Market::from(raw)
+    }
+
+    fn cached_ids(db: &Database, limit: Option<u32>) -> Vec<String> {
+        db.get_cached_markets(limit).unwrap().into_iter().map(|m| m.condition_id).collect()
+    }
+
+    #[test]
+    fn test_store_cached_markets_upserts_by_condition_id() {
+        let db = Database::open_in_memory();
+        db.store_cached_markets(&[market("0xa", "Old question"), market("0xb", "B")], 100).unwrap();
+
+        db.store_cached_markets(&[market("0xa", "New question")], 200).unwrap();
+
+        let markets = db.get_cached_markets(None).unwrap();
+        assert_eq!(markets.len(), 2);
+        assert_eq!(markets[0].condition_id, "0xa");
+        assert_eq!(markets[0].question, "New question");
+        assert_eq!(db.markets_cached_at().unwrap(), Some(200));
+    }
+
+    #[test]
+    fn test_get_cached_markets_newest_fetch_first_in_rank_order() {
+        let db = Database::open_in_memory();
+        assert_eq!(db.markets_cached_at().unwrap(), None);
+
+        db.store_cached_markets(&[market("0xa", "A"), market("0xb", "B")], 100).unwrap();
+        db.store_cached_markets(&[market("0xd", "D"), market("0xc", "C")], 200).unwrap();
+
+        assert_eq!(cached_ids(&db, None), ["0xd", "0xc", "0xa", "0xb"]);
+        assert_eq!(cached_ids(&db, Some(3)), ["0xd", "0xc", "0xa"]);
+    }
+
+    #[test]
+    fn test_get_cached_markets_skips_unreadable_rows() {
+        let db = Database::open_in_memory();
+        db.store_cached_markets(&[market("0xa", "A"), market("0xb", "B")], 100).unwrap();
+        db.conn.lock().unwrap()
+            .execute("UPDATE markets_cache SET data = '{}' WHERE condition_id = '0xa'", [])
+            .unwrap();
+
+        assert_eq!(cached_ids(&db, None), ["0xb"]);
+    }
+
+    // ========== Price History ==========
+
+    #[test]
+    fn test_store_price_history_skips_duplicate_timestamps() {
+        let db = Database::open_in_memory();
+
+        // Duplicate within the batch: the first point wins
+        let inserted = db.store_price_history("t1", &[(100, 0.5), (200, 0.6), (200, 0.9)]).unwrap();
+        assert_eq!(inserted, 2);
+
+        // Overlapping refetch only adds the new point
+        let inserted = db.store_price_history("t1", &[(200, 0.7), (300, 0.8)]).unwrap();
+        assert_eq!(inserted, 1);
+
+        assert_eq!(db.get_price_history("t1", None, None).unwrap(), [(100, 0.5), (200, 0.6), (300, 0.8)]);
+        // Same timestamp under another token is not a duplicate
+        assert_eq!(db.store_price_history("t2", &[(200, 0.4)]).unwrap(), 1);
+        assert_eq!(db.get_latest_price_timestamp("t1").unwrap(), Some(300));
+    }
+
+    // ========== Migrations ==========
+
+    #[test]
+    fn test_dry_run_lists_every_migration_on_new_database() {
+        let conn = Connection::open_in_memory().unwrap();
+        let runner = MigrationRunner::new();
+
+        let pending = runner.dry_run(&conn).unwrap();
+
+        assert_eq!(pending, [SCHEMA_V1, SCHEMA_V2, SCHEMA_V3]);
+        // Nothing was executed
+        let version: u32 = conn.query_row("PRAGMA user_version", [], |row| row.get(0)).unwrap();
+        assert_eq!(version, 0);
+    }
+
+    #[test]
+    fn test_dry_run_empty_after_run() {
+        let conn = Connection::open_in_memory().unwrap();
+        let runner = MigrationRunner::new();
+
+        assert_eq!(runner.run(&conn).unwrap(), MIGRATIONS.len());
+
+        assert!(runner.dry_run(&conn).unwrap().is_empty());
+        assert_eq!(runner.run(&conn).unwrap(), 0);
+        let version: u32 = conn.query_row("PRAGMA user_version", [], |row| row.get(0)).unwrap();
+        assert_eq!(version, runner.latest_version());
+    }
+}
@@ -1,4 +1,5 @@
 // AIDEV-NOTE: Tauri-specific error type that wraps polymarket_rs::ApiError
+use serde::ser::SerializeStruct;
 use serde::Serialize;
 use thiserror::Error;
 
@@ -24,15 +25,82 @@ pub enum AppError {
 
     #[error("Internal error: {0}")]
     Internal(String),
+
+    /// Error the frontend can show as-is: friendly `title`, technical `detail` for logs,
+    /// and a stable `code` for branching
+    #[error("{title}: {detail}")]
+    UserVisible {
+        title: String,
+        detail: String,
+        code: String,
+    },
+}
+
+/// Known CLOB rejections: (lowercase body substring, code, title)
+/// AIDEV-NOTE: Matched against the raw response body - first match wins
+const KNOWN_API_ERRORS: &[(&str, &str, &str)] = &[
+    ("not enough balance", "insufficient_funds", "Insufficient funds"),
+    ("insufficient balance", "insufficient_funds", "Insufficient funds"),
+    ("duplicated", "duplicate_order", "Duplicate order"),
+    ("duplicate order", "duplicate_order", "Duplicate order"),
+    ("invalid price", "invalid_price", "Invalid price"),
+    ("min_tick_size", "invalid_price", "Invalid price"),
+];
+
+impl AppError {
+    /// Stable `type` tag sent to the frontend for branching
+    pub fn kind(&self) -> &'static str {
+        match self {
+            AppError::Http(_) => "network_error",
+            AppError::Json(_) | AppError::Api(_) => "api_error",
+            AppError::MarketNotFound(_) => "market_not_found",
+            AppError::Auth(_) => "auth_error",
+            AppError::Database(_) | AppError::Internal(_) => "internal_error",
+            AppError::UserVisible { .. } => "user_visible",
+        }
+    }
+
+    /// Build an error from an API message, promoting known CLOB errors to UserVisible
+    pub fn from_api_message(msg: String) -> Self {
+        let lower = msg.to_lowercase();
+        match KNOWN_API_ERRORS.iter().find(|(needle, _, _)| lower.contains(needle)) {
+            Some((_, code, title)) => AppError::UserVisible {
+                title: title.to_string(),
+                detail: msg,
+                code: code.to_string(),
+            },
+            None => AppError::Api(msg),
+        }
+    }
 }
 
 // AIDEV-NOTE: Tauri requires errors to be serializable
+// Wire format (mirrored by `AppError` in src/lib/types.ts):
+//   { type: "auth_error" | "api_error" | "network_error" | "market_not_found" | "internal_error",
+//     message: string }
+// | { type: "user_visible", title: string, detail: string, code: string }
 impl Serialize for AppError {
     fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
     where
         S: serde::Serializer,
     {
-        serializer.serialize_str(&self.to_string())
+        match self {
+            // AIDEV-NOTE: Structured so the frontend can display title and log detail
+            AppError::UserVisible { title, detail, code } => {
+                let mut state = serializer.serialize_struct("AppError", 4)?;
+                state.serialize_field("type", self.kind())?;
+                state.serialize_field("title", title)?;
+                state.serialize_field("detail", detail)?;
+                state.serialize_field("code", code)?;
+                state.end()
+            }
+            _ => {
+                let mut state = serializer.serialize_struct("AppError", 2)?;
+                state.serialize_field("type", self.kind())?;
+                state.serialize_field("message", &self.to_string())?;
+                state.end()
+            }
+        }
     }
 }
 
@@ -47,7 +115,76 @@ impl From<polymarket_rs::ApiError> for AppError {
             ApiError::Auth(msg) => AppError::Auth(msg),
             ApiError::Signing(msg) => AppError::Auth(msg),
             ApiError::WebSocket(msg) => AppError::Api(msg),
-            ApiError::Api(msg) => AppError::Api(msg),
+            ApiError::Api(msg) => AppError::from_api_message(msg),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn to_json(error: AppError) -> serde_json::Value {
+        serde_json::to_value(&error).unwrap()
+    }
+
+    #[test]
+    fn test_message_variants_serialize_with_type() {
+        let json_error = serde_json::from_str::<serde_json::Value>("{").unwrap_err();
+        let json_message = format!("JSON parsing failed: {}", json_error);
+        let cases = [
+            (AppError::Json(json_error), "api_error", json_message),
+            (AppError::MarketNotFound("123".to_string()), "market_not_found", "Market not found: 123".to_string()),
+            (AppError::Api("bad request".to_string()), "api_error", "API error: bad request".to_string()),
+            (AppError::Auth("expired".to_string()), "auth_error", "Auth error: expired".to_string()),
+            (AppError::Database("locked".to_string()), "internal_error", "Database error: locked".to_string()),
+            (AppError::Internal("oops".to_string()), "internal_error", "Internal error: oops".to_string()),
+        ];
+
+        for (error, kind, message) in cases {
+            assert_eq!(to_json(error), json!({ "type": kind, "message": message }));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_http_error_serializes_as_network_error() {
+        let error = reqwest::Client::new().get("http://127.0.0.1:1").send().await.unwrap_err();
+        let value = to_json(AppError::Http(error));
+
+        assert_eq!(value["type"], "network_error");
+        assert!(value["message"].as_str().unwrap().starts_with("HTTP request failed: "));
+        assert_eq!(value.as_object().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_user_visible_serializes_fields() {
+        let error = AppError::UserVisible {
+            title: "Price impact too high".to_string(),
+            detail: "Estimated fill 0.7000 vs requested 0.6000".to_string(),
+            code: "price_impact".to_string(),
+        };
+
+        assert_eq!(
+            to_json(error),
+            json!({
+                "type": "user_visible",
+                "title": "Price impact too high",
+                "detail": "Estimated fill 0.7000 vs requested 0.6000",
+                "code": "price_impact",
+            })
+        );
+    }
+
+    #[test]
+    fn test_known_api_errors_become_user_visible() {
+        let value = to_json(AppError::from(polymarket_rs::ApiError::Api(
+            "not enough balance / allowance".to_string(),
+        )));
+        assert_eq!(value["type"], "user_visible");
+        assert_eq!(value["code"], "insufficient_funds");
+
+        let value = to_json(AppError::from(polymarket_rs::ApiError::Api("order crossed".to_string())));
+        assert_eq!(value, json!({ "type": "api_error", "message": "API error: order crossed" }));
+    }
+}
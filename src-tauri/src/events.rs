@@ -1,19 +1,109 @@
 // AIDEV-NOTE: TauriEventEmitter - implements polymarket_rs::EventEmitter for Tauri
 use polymarket_rs::{
-    ws::RtdsTrade, ClobTrade, ConnectionStatus, EventEmitter, OrderBookSnapshot, PriceUpdate,
+    ws::RtdsTrade, Balance, ClobTrade, ConnectionStatus, EventEmitter, Order, OrderBookSnapshot, OrderEvent,
+    OrderFillEvent, PriceUpdate, TickSizeChange, Token,
 };
-use tauri::{AppHandle, Emitter};
-use tracing::error;
+use serde::Serialize;
+use std::time::Duration;
+use tauri::{AppHandle, Emitter, Manager};
+use tracing::{error, info};
+
+use crate::db::PriceAlert;
+use crate::AuthState;
+
+/// Payload of the "price_alert_triggered" event
+#[derive(Debug, Clone, Serialize)]
+pub struct PriceAlertTriggered {
+    pub alert: PriceAlert,
+    /// Price that crossed the threshold
+    pub price: f64,
+}
+
+/// Payload of the "order_status_changed" event (see `await_order_status`)
+#[derive(Debug, Clone, Serialize)]
+pub struct OrderStatusChanged {
+    pub order: Order,
+    pub previous_status: String,
+}
+
+/// Payload of the "ws_reconnecting" event
+#[derive(Debug, Clone, Serialize)]
+pub struct Reconnecting<'a> {
+    /// "clob" or "rtds"
+    pub channel: &'a str,
+    pub attempt: u32,
+    pub next_delay_ms: u64,
+}
+
+/// Payload of the "market_resolved" event (see `start_resolution_watch`)
+#[derive(Debug, Clone, Serialize)]
+pub struct MarketResolved<'a> {
+    pub condition_id: &'a str,
+    pub winning_token: &'a Token,
+}
 
 /// Tauri implementation of EventEmitter
 /// Bridges WebSocket events to Tauri frontend
 pub struct TauriEventEmitter(pub AppHandle);
 
+impl TauriEventEmitter {
+    /// Fire any active alerts on this asset whose threshold the new price has crossed
+    /// AIDEV-NOTE: Alerts live in AuthState, which is managed after the emitter is built -
+    /// looked up per update and skipped if not yet available. Only a crossing touches SQLite.
+    fn check_price_alerts(&self, update: &PriceUpdate) {
+        let Some(state) = self.0.try_state::<AuthState>() else {
+            return;
+        };
+
+        for mut alert in state.price_alerts.take_crossed(&update.asset_id, update.price) {
+            match state.database.mark_alert_triggered(alert.id) {
+                Ok(true) => {}
+                Ok(false) => continue,
+                Err(e) => {
+                    error!("Failed to mark alert {} triggered: {}", alert.id, e);
+                    continue;
+                }
+            }
+
+            info!(
+                "Price alert {} triggered: {} {} {} (price {})",
+                alert.id,
+                alert.token_id,
+                alert.direction.as_str(),
+                alert.threshold,
+                update.price
+            );
+
+            alert.triggered = true;
+            let payload = PriceAlertTriggered { alert, price: update.price };
+            if let Err(e) = self.0.emit("price_alert_triggered", &payload) {
+                error!("Failed to emit price_alert_triggered: {}", e);
+            }
+        }
+    }
+
+    /// Reprice any tracked position in this asset and emit "position_updated"
+    fn update_positions(&self, update: &PriceUpdate) {
+        let Some(state) = self.0.try_state::<AuthState>() else {
+            return;
+        };
+
+        if let Some(position) = state.position_tracker.apply_price(&update.asset_id, update.price) {
+            if let Err(e) = self.0.emit("position_updated", &position) {
+                error!("Failed to emit position_updated: {}", e);
+            }
+        }
+    }
+}
+
 impl EventEmitter for TauriEventEmitter {
     fn emit_price_update(&self, update: &PriceUpdate) {
         if let Err(e) = self.0.emit("price_update", update) {
             error!("Failed to emit price_update: {}", e);
         }
+
+        self.check_price_alerts(update);
+        self.update_positions(update);
     }
 
     fn emit_orderbook_snapshot(&self, snapshot: &OrderBookSnapshot) {
@@ -39,4 +129,42 @@ impl EventEmitter for TauriEventEmitter {
             error!("Failed to emit connection_status: {}", e);
         }
     }
+
+    fn emit_tick_size_change(&self, change: &TickSizeChange) {
+        if let Err(e) = self.0.emit("tick_size_change", change) {
+            error!("Failed to emit tick_size_change: {}", e);
+        }
+    }
+
+    fn emit_order_update(&self, event: &OrderEvent) {
+        if let Err(e) = self.0.emit("order_update", event) {
+            error!("Failed to emit order_update: {}", e);
+        }
+    }
+
+    fn emit_fill(&self, fill: &OrderFillEvent) {
+        if let Err(e) = self.0.emit("order_fill", fill) {
+            error!("Failed to emit order_fill: {}", e);
+        }
+    }
+
+    fn emit_balance_update(&self, balance: &Balance) {
+        if let Err(e) = self.0.emit("balance_update", balance) {
+            error!("Failed to emit balance_update: {}", e);
+        }
+    }
+
+    fn emit_market_resolved(&self, condition_id: &str, winning_token: &Token) {
+        let payload = MarketResolved { condition_id, winning_token };
+        if let Err(e) = self.0.emit("market_resolved", &payload) {
+            error!("Failed to emit market_resolved: {}", e);
+        }
+    }
+
+    fn emit_reconnecting(&self, channel: &str, attempt: u32, next_delay: Duration) {
+        let payload = Reconnecting { channel, attempt, next_delay_ms: next_delay.as_millis() as u64 };
+        if let Err(e) = self.0.emit("ws_reconnecting", &payload) {
+            error!("Failed to emit ws_reconnecting: {}", e);
+        }
+    }
 }
@@ -1,21 +1,26 @@
 // AIDEV-NOTE: Tauri app entry point - uses polymarket_rs for API/WebSocket logic
 
+mod alerts;
 mod auth;
 mod commands;
 mod db;
 mod error;
 mod events;
+mod positions;
 
 use std::sync::Arc;
+use alerts::AlertCache;
 use db::Database;
 use events::TauriEventEmitter;
+use positions::PositionTracker;
 use parking_lot::RwLock;
 use tauri::Manager;
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 // Import from polymarket-rs
 use polymarket_rs::{
-    ApiCredentials, ClobClient, ClobWebSocket, GammaClient, RtdsClient, WebSocketManager,
+    ApiCredentials, BalanceWatcher, ClobClient, ClobWebSocket, GammaClient, OrderWatcher, ResolutionWatcher,
+    RtdsClient, WebSocketManager,
 };
 
 /// Shared state for WebSocket connections
@@ -24,6 +29,8 @@ pub struct WebSocketState {
     pub manager: Arc<WebSocketManager<TauriEventEmitter>>,
     pub rtds: RwLock<Option<RtdsClient<TauriEventEmitter>>>,
     pub clob: RwLock<Option<ClobWebSocket<TauriEventEmitter>>>,
+    /// Reports watched markets as they resolve (see start_resolution_watch)
+    pub resolution_watcher: ResolutionWatcher,
 }
 
 /// Shared state for authentication
@@ -32,6 +39,14 @@ pub struct AuthState {
     pub clob_client: RwLock<ClobClient>,
     pub database: Arc<Database>,
     pub polymarket_address: RwLock<Option<String>>,
+    pub balance_watcher: BalanceWatcher,
+    pub order_watcher: OrderWatcher,
+    /// Positions repriced live from price updates (see events.rs)
+    pub position_tracker: PositionTracker,
+    /// Active price alerts, checked on every price update (see events.rs)
+    pub price_alerts: AlertCache,
+    /// OS keyring entry for the signing key (None if the platform has no OS keyring)
+    pub private_key_store: Option<auth::PrivateKeyStore>,
 }
 
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
@@ -59,6 +74,7 @@ pub fn run() {
                 manager: ws_manager.clone(),
                 rtds: RwLock::new(None),
                 clob: RwLock::new(None),
+                resolution_watcher: ResolutionWatcher::new(),
             };
             app.manage(ws_state);
 
@@ -67,11 +83,16 @@ pub fn run() {
                 .expect("Failed to initialize database"));
 
             let (credentials, clob_client, polymarket_address) = match database.load_credentials() {
-                Ok(Some((creds, poly_addr))) => {
-                    tracing::info!("Found existing credentials for {}", creds.address);
-                    let client = ClobClient::with_credentials(&creds);
-                    (Some(creds), client, poly_addr)
-                }
+                Ok(Some((creds, poly_addr))) => match ClobClient::with_credentials(&creds) {
+                    Ok(client) => {
+                        tracing::info!("Found existing credentials for {}", creds.address);
+                        (Some(creds), client, poly_addr)
+                    }
+                    Err(e) => {
+                        tracing::warn!("Ignoring stored credentials: {}", e);
+                        (None, ClobClient::new(), None)
+                    }
+                },
                 Ok(None) => {
                     tracing::debug!("No stored credentials found");
                     (None, ClobClient::new(), None)
@@ -82,42 +103,116 @@ pub fn run() {
                 }
             };
 
+            let clob_client = clob_client.with_signature_type(commands::balance_signature_type(&database));
+
+            let private_key_store = match auth::PrivateKeyStore::new() {
+                Ok(store) => Some(store),
+                Err(e) => {
+                    tracing::warn!("Private key storage unavailable: {}", e);
+                    None
+                }
+            };
+
+            let price_alerts = AlertCache::new();
+            if let Err(e) = price_alerts.reload(&database) {
+                tracing::warn!("Failed to load price alerts: {}", e);
+            }
+
             let auth_state = AuthState {
                 credentials: RwLock::new(credentials),
                 clob_client: RwLock::new(clob_client),
                 database,
                 polymarket_address: RwLock::new(polymarket_address),
+                balance_watcher: BalanceWatcher::new(),
+                order_watcher: OrderWatcher::new(),
+                position_tracker: PositionTracker::new(),
+                price_alerts,
+                private_key_store,
             };
             app.manage(auth_state);
 
+            // Bring back the live feeds from the previous session
+            commands::restore_subscriptions(app.handle());
+
             Ok(())
         })
         .invoke_handler(tauri::generate_handler![
             // Market commands
             commands::get_markets,
+            commands::get_markets_lite,
+            commands::get_market_summaries,
+            commands::get_market_for_token,
+            commands::cache_markets,
+            commands::get_cached_markets,
+            commands::get_historical_markets,
+            commands::get_markets_resolving_soon,
+            commands::get_markets_by_resolution_source,
             commands::get_market,
             commands::get_events,
+            commands::get_event,
+            commands::get_event_markets,
+            commands::start_resolution_watch,
+            commands::stop_resolution_watch,
+            commands::get_market_categories,
+            commands::get_market_activity,
+            commands::get_holders,
+            commands::get_book_spread,
             commands::search_markets,
             commands::get_price_history,
+            commands::get_chart_settings,
+            commands::reset_chart_settings,
+            commands::refresh_dashboard,
             // WebSocket commands
             commands::connect_rtds,
             commands::disconnect_rtds,
             commands::connect_clob,
+            commands::subscribe_clob_tokens,
+            commands::start_stats_emitter,
+            commands::stop_stats_emitter,
             commands::disconnect_clob,
             commands::get_connection_status,
+            commands::get_aggregated_orderbook,
             // Auth commands
             commands::get_auth_status,
             commands::login,
             commands::logout,
+            commands::rotate_credentials,
+            commands::store_private_key,
+            commands::clear_private_key,
+            commands::has_stored_private_key,
             commands::set_polymarket_address,
+            commands::set_signature_type,
             commands::get_balance,
+            commands::start_balance_polling,
+            commands::stop_balance_polling,
+            commands::start_order_polling,
+            commands::stop_order_polling,
             commands::get_positions,
+            commands::get_portfolio_summary,
+            commands::get_pnl_summary,
+            commands::get_leaderboard,
+            commands::get_user_rank,
             commands::get_orders,
             // Trading commands
             commands::place_order,
+            commands::place_orders,
+            commands::place_order_keyring,
+            commands::get_order_fills,
+            commands::get_fee_rates,
             commands::cancel_order,
+            commands::cancel_orders,
             commands::cancel_all_orders,
+            commands::cancel_all_orders_and_refresh,
             commands::cancel_market_orders,
+            commands::cancel_token_orders,
+            commands::await_order_status,
+            // Settings commands
+            commands::save_settings,
+            commands::get_settings,
+            // Price alert commands
+            commands::add_price_alert,
+            commands::get_price_alerts,
+            commands::delete_price_alert,
         ])
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
@@ -0,0 +1,114 @@
+// AIDEV-NOTE: Live P&L for the positions last returned by get_positions - repriced from
+// WebSocket price updates so the portfolio view doesn't have to refetch
+
+use std::collections::HashMap;
+
+use parking_lot::RwLock;
+use polymarket_rs::Position;
+
+/// Positions keyed by asset (outcome token ID)
+#[derive(Default)]
+pub struct PositionTracker {
+    positions: RwLock<HashMap<String, Position>>,
+}
+
+impl PositionTracker {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Track exactly these positions (the latest get_positions snapshot)
+    pub fn replace(&self, positions: &[Position]) {
+        *self.positions.write() = positions
+            .iter()
+            .map(|position| (position.asset.clone(), position.clone()))
+            .collect();
+    }
+
+    /// Stop tracking everything (logout)
+    pub fn clear(&self) {
+        self.positions.write().clear();
+    }
+
+    /// Reprice the position holding `asset_id`, if any
+    /// Returns the updated position, or None if untracked or the price didn't change
+    pub fn apply_price(&self, asset_id: &str, price: f64) -> Option<Position> {
+        let mut positions = self.positions.write();
+        let position = positions.get_mut(asset_id)?;
+        if position.cur_price == price {
+            return None;
+        }
+
+        position.cur_price = price;
+        position.current_value = position.size * price;
+        position.cash_pnl = position.current_value - position.initial_value;
+        if position.initial_value > 0.0 {
+            position.percent_pnl = position.cash_pnl / position.initial_value * 100.0;
+        }
+        Some(position.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn position(asset: &str, size: f64, initial_value: f64, cur_price: f64) -> Position {
+        serde_json::from_value(serde_json::json!({
+            "asset": asset,
+            "conditionId": "0xc",
+            "size": size,
+            "avgPrice": if size > 0.0 { initial_value / size } else { 0.0 },
+            "initialValue": initial_value,
+            "currentValue": size * cur_price,
+            "cashPnl": size * cur_price - initial_value,
+            "percentPnl": 0.0,
+            "curPrice": cur_price,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn test_apply_price_reprices_position() {
+        let tracker = PositionTracker::new();
+        tracker.replace(&[position("t1", 100.0, 40.0, 0.4)]);
+
+        let updated = tracker.apply_price("t1", 0.5).unwrap();
+
+        assert_eq!(updated.cur_price, 0.5);
+        assert!((updated.current_value - 50.0).abs() < 1e-9);
+        assert!((updated.cash_pnl - 10.0).abs() < 1e-9);
+        assert!((updated.percent_pnl - 25.0).abs() < 1e-9);
+
+        // Same price again, or an untracked asset: nothing to emit
+        assert!(tracker.apply_price("t1", 0.5).is_none());
+        assert!(tracker.apply_price("t2", 0.5).is_none());
+    }
+
+    #[test]
+    fn test_apply_price_with_zero_initial_value_keeps_percent() {
+        let tracker = PositionTracker::new();
+        let mut free = position("t1", 10.0, 0.0, 0.1);
+        free.percent_pnl = 12.5;
+        tracker.replace(&[free]);
+
+        let updated = tracker.apply_price("t1", 0.3).unwrap();
+
+        assert!((updated.cash_pnl - 3.0).abs() < 1e-9);
+        assert_eq!(updated.percent_pnl, 12.5);
+        assert!(updated.percent_pnl.is_finite());
+    }
+
+    #[test]
+    fn test_replace_and_clear() {
+        let tracker = PositionTracker::new();
+        tracker.replace(&[position("t1", 1.0, 0.5, 0.5)]);
+        tracker.replace(&[position("t2", 1.0, 0.5, 0.5)]);
+
+        assert!(tracker.apply_price("t1", 0.9).is_none());
+        assert!(tracker.apply_price("t2", 0.9).is_some());
+
+        tracker.clear();
+        assert!(tracker.apply_price("t2", 0.1).is_none());
+    }
+}
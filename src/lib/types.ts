@@ -34,6 +34,44 @@ export interface Market {
   liquidity: string;
   liquidity_num: number;
   spread: number;
+  resolution_source?: string;
+}
+
+// Compact market summary for list views (get_markets_lite)
+export interface MarketQuote {
+  id: string;
+  condition_id: string;
+  question: string;
+  yes_price?: number;
+  no_price?: number;
+  volume: number;
+  spread: number;
+}
+
+// Lightweight market for large lists (get_market_summaries)
+export interface MarketSummary {
+  condition_id: string;
+  question: string;
+  market_slug: string;
+  end_date_iso: string;
+  best_price: number;
+  volume_num: number;
+  liquidity_num: number;
+  active: boolean;
+  token_count: number;
+}
+
+// Filter argument for get_market_summaries (all fields optional)
+export interface MarketFilter {
+  query?: string;
+  limit?: number;
+  offset?: number;
+  resolution_source?: string;
+  include_archived?: boolean;
+  include_closed?: boolean;
+  accepting_orders_only?: boolean;
+  end_date_min?: string;
+  end_date_max?: string;
 }
 
 export interface Token {
@@ -90,6 +128,7 @@ export interface OrderBookLevel {
   size: string;
 }
 
+// get_positions result; also the payload of the "position_updated" event (repriced live)
 export interface Position {
   asset: string;
   conditionId: string;
@@ -105,6 +144,9 @@ export interface Position {
   proxyWallet: string;
 }
 
+// get_positions sort_by option (largest first)
+export type PositionSortKey = "cash_pnl" | "percent_pnl" | "current_value" | "size";
+
 export interface Order {
   id: string;
   market: string;
@@ -118,6 +160,54 @@ export interface Order {
   createdAt: string;
 }
 
+// get_portfolio_summary result
+export interface PortfolioSummary {
+  positions: Position[];
+  openOrders: Order[];
+  totalValue: number;
+  totalCashPnl: number;
+  // Omitted when the P&L request failed
+  pnl?: PnlSummary;
+}
+
+// get_pnl_summary result (account-wide, from the Data API)
+export interface PnlSummary {
+  totalPnl: number;
+  realizedPnl: number;
+  unrealizedPnl: number;
+  totalVolume: number;
+}
+
+// Payload of the "market_resolved" event (start_resolution_watch)
+export interface MarketResolved {
+  condition_id: string;
+  winning_token: Token;
+}
+
+// Payload of the "order_fill" event (start_order_polling)
+export interface OrderFillEvent {
+  order_id: string;
+  fill_size: number;
+  fill_price: number;
+  remaining_size: number;
+  timestamp: number;
+}
+
+// Payload of the "order_update" event (start_order_polling)
+export type OrderEventKind = "filled" | "partially_filled" | "cancelled";
+
+export interface OrderEvent {
+  kind: OrderEventKind;
+  order: Order;
+  newly_matched: number;  // Shares matched since the previous poll
+}
+
+// Payload of the "order_status_changed" event (await_order_status)
+export interface OrderStatusChanged {
+  order: Order;
+  previous_status: string;
+}
+
 // Auth types
 export interface AuthStatus {
   isAuthenticated: boolean;
@@ -125,6 +215,11 @@ export interface AuthStatus {
   polymarketAddress?: string;
 }
 
+// Account type (set_signature_type) - must match how the Polymarket wallet was created
+// 0 = EOA, 1 = Polymarket proxy, 2 = Gnosis Safe
+export type SignatureType = 0 | 1 | 2;
+
+// Also the payload of the "balance_update" event (start_balance_polling)
 export interface Balance {
   balance: string;
   allowances: Record<string, string>;
@@ -142,6 +237,53 @@ export interface OrderParams {
   size: number;       // Number of shares
   orderType: OrderTimeInForce;
   expirationSecs?: number;
+  maxSlippagePct?: number; // FOK only - refuse if estimated fill deviates more than this (unset = no check)
+}
+
+// Fill of one of the user's orders (get_order_fills)
+export interface OrderFill {
+  tradeId: string;
+  orderId: string;
+  size: number;
+  price: number;
+  timestamp: string;
+  fee: number;
+}
+
+// Market category (get_market_categories)
+export interface MarketCategory {
+  id: string;
+  label: string;
+  market_count?: number;
+}
+
+// Top holder of an outcome token (get_holders)
+export interface Holder {
+  address: string;
+  amount: number;
+}
+
+// Leaderboard row (get_leaderboard / get_user_rank)
+export interface LeaderboardEntry {
+  address: string;
+  pnl: number;
+  volume: number;
+  rank: number;
+}
+
+// Top of book for one token (get_book_spread)
+export interface BookSpread {
+  token_id: string;
+  best_bid: number;
+  best_ask: number;
+  spread: number;
+  mid_price: number;
+}
+
+// Maker/taker fee rates in basis points (get_fee_rates)
+export interface FeeRates {
+  maker_fee_rate_bps: number;
+  taker_fee_rate_bps: number;
 }
 
 // Order placement result
@@ -153,6 +295,14 @@ export interface PlaceOrderResult {
   status?: string;
 }
 
+// Error returned by any backend command (rejected invoke() promise)
+export type AppError =
+  | {
+      type: "auth_error" | "api_error" | "network_error" | "market_not_found" | "internal_error";
+      message: string;
+    }
+  | { type: "user_visible"; title: string; detail: string; code: string };
+
 // Cancel result
 export interface CancelResult {
   canceled: string[];
@@ -166,6 +316,33 @@ export interface PriceUpdate {
   asset_id?: string;  // Token ID for matching specific outcomes
   price: number;
   timestamp?: number;
+  volume?: number;  // Size accompanying the price change
+}
+
+// Minimum tick size change for a token ("tick_size_change" event)
+export interface TickSizeChange {
+  asset_id: string;
+  old_tick_size: number;
+  new_tick_size: number;
+  timestamp?: number;
+}
+
+// Price alerts (add_price_alert / get_price_alerts)
+export type AlertDirection = "above" | "below";
+
+export interface PriceAlert {
+  id: number;
+  token_id: string;
+  threshold: number;
+  direction: AlertDirection;
+  triggered: boolean;
+  created_at: string;
+}
+
+// Payload of the "price_alert_triggered" event
+export interface PriceAlertTriggered {
+  alert: PriceAlert;
+  price: number;
 }
 
 export interface TradeUpdate {
@@ -216,6 +393,13 @@ export interface ConnectionStatus {
   rtds: ConnectionStateValue;
 }
 
+// Payload of the "ws_reconnecting" event - fired before each reconnect delay
+export interface Reconnecting {
+  channel: "clob" | "rtds";
+  attempt: number;
+  next_delay_ms: number;
+}
+
 // Price history types
 // AIDEV-NOTE: Matches PricePoint from Rust clob.rs
 export interface PricePoint {
@@ -223,6 +407,14 @@ export interface PricePoint {
   p: number;  // Price (0.0 - 1.0)
 }
 
+// Effective chart preferences (get_chart_settings / reset_chart_settings)
+// Edit via save_settings with keys "chart.default_interval", "chart.fidelity", "chart.cache_max_age_secs"
+export interface ChartSettings {
+  defaultInterval: string;
+  fidelity?: number;
+  cacheMaxAgeSecs: number;
+}
+
 export interface PriceHistoryParams {
   tokenId: string;
   interval?: "1h" | "6h" | "1d" | "1w" | "max";
@@ -1,5 +1,6 @@
 import { type ClassValue, clsx } from "clsx";
 import { twMerge } from "tailwind-merge";
+import type { AppError } from "@/lib/types";
 
 export function cn(...inputs: ClassValue[]) {
   return twMerge(clsx(inputs));
@@ -35,3 +36,20 @@ export function formatCompactUsd(amount: number): string {
 export function shortenAddress(address: string, chars = 4): string {
   return `${address.slice(0, chars + 2)}...${address.slice(-chars)}`;
 }
+
+// Message to show for a rejected backend call
+// AIDEV-NOTE: Tauri commands reject with an AppError object ({type, message} or the
+// user_visible {title, detail, code}), not an Error - show the title, not the detail
+export function errorMessage(err: unknown, fallback?: string): string {
+  if (err instanceof Error) {
+    return err.message;
+  }
+  if (typeof err === "object" && err !== null && "type" in err) {
+    const appError = err as AppError;
+    return appError.type === "user_visible" ? appError.title : appError.message;
+  }
+  if (typeof err === "string") {
+    return err;
+  }
+  return fallback ?? String(err);
+}
@@ -5,7 +5,7 @@ import { useMarketsStore } from "@/stores/markets";
 import { useAuthStore } from "@/stores/auth";
 import { getBackend } from "@/lib/backend";
 import { Briefcase, TrendingUp, Activity } from "lucide-react";
-import { cn } from "@/lib/utils";
+import { cn, errorMessage } from "@/lib/utils";
 
 export function Dashboard() {
   const { markets, isLoading, setMarkets, setLoading, setError } = useMarketsStore();
@@ -38,7 +38,7 @@ export function Dashboard() {
         const data = await backend.getMarkets(undefined, 6);
         setMarkets(data);
       } catch (err) {
-        setError(err instanceof Error ? err.message : "Failed to fetch markets");
+        setError(errorMessage(err, "Failed to fetch markets"));
       } finally {
         setLoading(false);
       }
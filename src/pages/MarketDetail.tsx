@@ -6,7 +6,7 @@ import { Badge } from "@/components/ui/badge";
 import { Separator } from "@/components/ui/separator";
 import { OrderBook } from "@/components/trading/OrderBook";
 import { PriceChart, type PriceDataPoint } from "@/components/trading/PriceChart";
-import { formatPrice, formatCompactUsd } from "@/lib/utils";
+import { formatPrice, formatCompactUsd, errorMessage } from "@/lib/utils";
 import { getBackend } from "@/lib/backend";
 import { useWebSocketStore } from "@/stores/websocket";
 import { useOrderBookStore } from "@/stores/orderbook";
@@ -77,7 +77,7 @@ export function MarketDetail() {
         }
       } catch (err) {
         console.error("[MarketDetail] Error fetching market:", err);
-        setError(err instanceof Error ? err.message : "Failed to fetch market");
+        setError(errorMessage(err, "Failed to fetch market"));
       } finally {
         setIsLoading(false);
       }
@@ -2,6 +2,7 @@ import { useEffect, useState, useCallback } from "react";
 import { MarketList, MarketSearch } from "@/components/markets";
 import { useMarketsStore } from "@/stores/markets";
 import { getBackend } from "@/lib/backend";
+import { errorMessage } from "@/lib/utils";
 import { Button } from "@/components/ui/button";
 import { RefreshCw } from "lucide-react";
 
@@ -27,7 +28,7 @@ export function Markets() {
         : await backend.getMarkets(undefined, 50);
       setMarkets(data);
     } catch (err) {
-      setError(err instanceof Error ? err.message : "Failed to fetch markets");
+      setError(errorMessage(err, "Failed to fetch markets"));
     } finally {
       setLoading(false);
     }
@@ -91,6 +91,35 @@ describe("useTradingStore", () => {
       expect(isPlacingOrder).toBe(false);
     });
 
+    it("shows the message of a backend AppError", async () => {
+      const mockBackend = createMockBackend({
+        shouldThrow: true,
+        error: { type: "auth_error", message: "Auth error: Not authenticated" },
+      });
+      _setBackendForTesting(mockBackend);
+
+      await useTradingStore.getState().placeOrder(orderParams, "key");
+
+      expect(useTradingStore.getState().orderError).toBe("Auth error: Not authenticated");
+    });
+
+    it("shows the title of a user-visible AppError", async () => {
+      const mockBackend = createMockBackend({
+        shouldThrow: true,
+        error: {
+          type: "user_visible",
+          title: "Insufficient funds",
+          detail: "not enough balance / allowance",
+          code: "insufficient_funds",
+        },
+      });
+      _setBackendForTesting(mockBackend);
+
+      await useTradingStore.getState().placeOrder(orderParams, "key");
+
+      expect(useTradingStore.getState().orderError).toBe("Insufficient funds");
+    });
+
     it("stores order result with orderId", async () => {
       const mockBackend = createMockBackend({
         placeOrderResult: {
@@ -3,6 +3,7 @@
 import { create } from "zustand";
 import type { AuthStatus, Balance, Position, Order } from "@/lib/types";
 import { getBackend } from "@/lib/backend";
+import { errorMessage } from "@/lib/utils";
 
 interface AuthState {
   // Auth status
@@ -74,8 +75,7 @@ export const useAuthStore = create<AuthState>((set, get) => ({
 
       return true;
     } catch (err) {
-      const errorMessage = err instanceof Error ? err.message : String(err);
-      set({ isLoading: false, error: errorMessage });
+      set({ isLoading: false, error: errorMessage(err) });
       return false;
     }
   },
@@ -95,8 +95,7 @@ export const useAuthStore = create<AuthState>((set, get) => ({
         orders: [],
       });
     } catch (err) {
-      const errorMessage = err instanceof Error ? err.message : String(err);
-      set({ isLoading: false, error: errorMessage });
+      set({ isLoading: false, error: errorMessage(err) });
     }
   },
 
@@ -3,6 +3,7 @@
 import { create } from "zustand";
 import type { OrderParams, PlaceOrderResult } from "@/lib/types";
 import { getBackend } from "@/lib/backend";
+import { errorMessage } from "@/lib/utils";
 
 interface TradingState {
   // Order form state
@@ -47,8 +48,7 @@ export const useTradingStore = create<TradingState>((set) => ({
 
       return true;
     } catch (err) {
-      const errorMessage = err instanceof Error ? err.message : String(err);
-      set({ isPlacingOrder: false, orderError: errorMessage });
+      set({ isPlacingOrder: false, orderError: errorMessage(err) });
       return false;
     }
   },
@@ -76,8 +76,7 @@ export const useTradingStore = create<TradingState>((set) => ({
 
       return true;
     } catch (err) {
-      const errorMessage = err instanceof Error ? err.message : String(err);
-      set({ isCancelling: false, cancelError: errorMessage });
+      set({ isCancelling: false, cancelError: errorMessage(err) });
       return false;
     }
   },
@@ -92,8 +91,7 @@ export const useTradingStore = create<TradingState>((set) => ({
       set({ isCancelling: false });
       return true;
     } catch (err) {
-      const errorMessage = err instanceof Error ? err.message : String(err);
-      set({ isCancelling: false, cancelError: errorMessage });
+      set({ isCancelling: false, cancelError: errorMessage(err) });
       return false;
     }
   },
@@ -108,8 +106,7 @@ export const useTradingStore = create<TradingState>((set) => ({
       set({ isCancelling: false });
       return true;
     } catch (err) {
-      const errorMessage = err instanceof Error ? err.message : String(err);
-      set({ isCancelling: false, cancelError: errorMessage });
+      set({ isCancelling: false, cancelError: errorMessage(err) });
       return false;
     }
   },
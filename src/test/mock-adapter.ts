@@ -148,12 +148,14 @@ export interface MockBackendConfig {
   // Error simulation
   shouldThrow?: boolean;
   errorMessage?: string;
+  // Rejection value instead of an Error (e.g. a serialized backend AppError)
+  error?: unknown;
 }
 
 export function createMockBackend(config: MockBackendConfig = {}): BackendAdapter {
   const throwIfConfigured = () => {
     if (config.shouldThrow) {
-      throw new Error(config.errorMessage || "Mock error");
+      throw config.error ?? new Error(config.errorMessage || "Mock error");
     }
   };
 